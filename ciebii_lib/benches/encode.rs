@@ -0,0 +1,99 @@
+//! Counts the allocations and time taken by the paths that encode chunks into bytes.
+//! Run with `cargo bench -p ciebii_lib`.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    hint::black_box,
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Instant,
+};
+
+use ciebii_lib::{chunk::Chunk, file::CIEBIIFILE, section::Section};
+
+/// The system allocator, counting every allocation it makes
+struct Counting;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+const SIZE: usize = 256;
+const RUNS: u32 = 20;
+
+fn chunks() -> Vec<Chunk> {
+    (0..SIZE * SIZE)
+        .map(|i| Chunk::new(i as u8, (i / SIZE) as u8, 0))
+        .collect()
+}
+
+/// Runs `f` `RUNS` times, printing the allocations and time of a single run.
+/// `setup` builds the input of every run and isn't counted.
+fn bench<T, R>(name: &str, setup: impl Fn() -> T, f: impl Fn(T) -> R) {
+    let mut allocations = 0;
+    let mut elapsed = 0.0;
+
+    for _ in 0..RUNS {
+        let input = setup();
+
+        let before = ALLOCATIONS.load(Ordering::Relaxed);
+        let start = Instant::now();
+        let output = black_box(f(black_box(input)));
+        elapsed += start.elapsed().as_secs_f64();
+        allocations += ALLOCATIONS.load(Ordering::Relaxed) - before;
+
+        drop(output);
+    }
+
+    println!(
+        "{name:<16} {:>8} allocations {:>10.3} ms",
+        allocations / RUNS as usize,
+        elapsed * 1000.0 / RUNS as f64
+    );
+}
+
+fn main() {
+    println!("{SIZE} x {SIZE} pixels, averaged over {RUNS} runs");
+
+    bench("try_from_chunks", chunks, |chunks| {
+        CIEBIIFILE::try_from_chunks(SIZE, SIZE, chunks).unwrap()
+    });
+
+    bench("push_chunk", chunks, |chunks| {
+        let mut file = CIEBIIFILE::new(SIZE, SIZE);
+        for chunk in chunks {
+            file.push_chunk(chunk);
+        }
+        file
+    });
+
+    let file = || {
+        let mut file = CIEBIIFILE::try_from_chunks(SIZE, SIZE, chunks()).unwrap();
+        file.push_frame(chunks(), 100).unwrap();
+        file.set_metadata(Section::EXIF, vec![0; 1024]);
+        file
+    };
+
+    bench("as_bytes", file, |file| file.as_bytes());
+
+    bench("modify", file, |mut file| {
+        file.modify(0, Chunk::new(1, 2, 3)).unwrap();
+        file
+    });
+}
@@ -0,0 +1,113 @@
+#ifndef CIEBII_H
+#define CIEBII_H
+
+/* Generated by cbindgen from src/ffi.rs, don't edit by hand. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/*
+ What every function returns, `CIEBII_STATUS_OK` when it succeeded
+ */
+typedef enum CiebiiStatus {
+  CIEBII_STATUS_OK = 0,
+  /*
+   A pointer was null, or the path wasn't valid UTF-8
+   */
+  CIEBII_STATUS_INVALID_ARGUMENT = 1,
+  /*
+   The file couldn't be opened or read
+   */
+  CIEBII_STATUS_IO = 2,
+  /*
+   The checksum of a chunk didn't match its color
+   */
+  CIEBII_STATUS_CHECKSUM_FAIL = 3,
+  /*
+   The data isn't a valid ciebii file
+   */
+  CIEBII_STATUS_CORRUPT = 4,
+  /*
+   The image has no frame at the given index
+   */
+  CIEBII_STATUS_NO_SUCH_FRAME = 5,
+  /*
+   The buffer can't hold every pixel of a frame
+   */
+  CIEBII_STATUS_BUFFER_TOO_SMALL = 6,
+} CiebiiStatus;
+
+/*
+ A decoded ciebii file, only ever handed out behind a pointer
+ */
+typedef struct CiebiiImage CiebiiImage;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/*
+ Reads and decodes the ciebii file at `path`. On success `*out` points to the image, which
+ is freed with `ciebii_free`, and is left alone otherwise.
+
+ # Safety
+ `path` must be null or a NUL-terminated string, and `out` null or valid for writes.
+ */
+CiebiiStatus ciebii_open(const char *path, CiebiiImage **out);
+
+/*
+ Decodes a ciebii file from the `len` bytes at `bytes`, like `ciebii_open`.
+
+ # Safety
+ `bytes` must be null or valid for reads of `len` bytes, and `out` null or valid for
+ writes.
+ */
+CiebiiStatus ciebii_decode(const uint8_t *bytes, uintptr_t len, CiebiiImage **out);
+
+/*
+ Writes the width and height of `image` in pixels to `width` and `height`.
+
+ # Safety
+ `image` must be null or come from `ciebii_open` or `ciebii_decode`, and `width` and
+ `height` null or valid for writes.
+ */
+CiebiiStatus ciebii_dimensions(const CiebiiImage *image, uintptr_t *width, uintptr_t *height);
+
+/*
+ Returns the number of frames of `image`, 1 for a still image and 0 for null.
+
+ # Safety
+ `image` must be null or come from `ciebii_open` or `ciebii_decode`.
+ */
+uintptr_t ciebii_frame_count(const CiebiiImage *image);
+
+/*
+ Copies the pixels of frame `frame` of `image` into `buffer` row by row, four bytes each
+ in RGBA order. `len` is the size of the buffer, which needs to hold `width * height * 4`
+ bytes.
+
+ # Safety
+ `image` must be null or come from `ciebii_open` or `ciebii_decode`, and `buffer` null or
+ valid for writes of `len` bytes.
+ */
+CiebiiStatus ciebii_copy_pixels(const CiebiiImage *image,
+                                uintptr_t frame,
+                                uint8_t *buffer,
+                                uintptr_t len);
+
+/*
+ Frees an image. Null is ignored.
+
+ # Safety
+ `image` must be null or come from `ciebii_open` or `ciebii_decode`, and not be used
+ again afterwards.
+ */
+void ciebii_free(CiebiiImage *image);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* CIEBII_H */
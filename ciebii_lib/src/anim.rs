@@ -0,0 +1,214 @@
+use super::{
+    chunk::{self, Chunk},
+    error::ChunkError,
+    header::Header,
+};
+
+/// An additional frame of an animated file, shown for `delay` milliseconds.
+/// ```
+/// use ciebii_lib::{anim::Frame, chunk::Chunk};
+/// let frame = Frame::new(vec![Chunk::new(255, 0, 0)], 100);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    chunks: Vec<Chunk>,
+    delay: u32,
+}
+
+impl Frame {
+    pub fn new(chunks: Vec<Chunk>, delay: u32) -> Self {
+        Self { chunks, delay }
+    }
+
+    /// Returns the chunks of this frame
+    pub fn chunks(&self) -> &Vec<Chunk> {
+        &self.chunks
+    }
+
+    /// Returns how long this frame is shown, in milliseconds
+    pub fn delay(&self) -> u32 {
+        self.delay
+    }
+
+    pub(crate) fn set_delay(&mut self, delay: u32) {
+        self.delay = delay;
+    }
+}
+
+// The payload of an animation section is laid out as
+// [FRAME COUNT (u32), DELAY (u32) * FRAME COUNT, CHUNKS OF FRAME 1.., CHUNKS OF FRAME 2.., ...]
+// The chunks of frame 0 are the regular pixel data of the file.
+
+/// Encodes the delay of the first frame along with every additional frame into an animation section payload.
+/// The chunks are stored the way `header` says those of the first frame are, with their alpha,
+/// checksum and as runs if it says so. Every frame starts a run of its own.
+pub(crate) fn encode(first_delay: u32, frames: &[Frame], header: &Header) -> Vec<u8> {
+    let count = frames.len() as u32 + 1;
+    let pixels: usize = frames.iter().map(|frame| frame.chunks.len()).sum();
+    let (alpha, checksum) = (header.has_alpha(), header.checksum_algorithm());
+
+    let mut bytes = Vec::with_capacity(4 * (count as usize + 1) + pixels * chunk::len(alpha));
+    bytes.extend_from_slice(&count.to_be_bytes());
+    bytes.extend_from_slice(&first_delay.to_be_bytes());
+    for frame in frames {
+        bytes.extend_from_slice(&frame.delay.to_be_bytes());
+    }
+    for frame in frames {
+        match header.is_compressed() {
+            true => chunk::encode_runs(&frame.chunks, alpha, checksum, &mut bytes),
+            false => chunk::encode_into(&frame.chunks, alpha, checksum, &mut bytes),
+        }
+    }
+
+    bytes
+}
+
+/// Decodes an animation section payload for frames of `pixels` chunks each, stored the way
+/// `header` says. Returns the delay of the first frame and the additional frames.
+pub(crate) fn decode(
+    payload: &[u8],
+    pixels: usize,
+    header: &Header,
+) -> Result<(u32, Vec<Frame>), ChunkError> {
+    let (alpha, checksum) = (header.has_alpha(), header.checksum_algorithm());
+
+    if payload.len() < 4 {
+        return Err(ChunkError::InvalidLen);
+    }
+
+    let count = u32::from_be_bytes(payload[0..4].try_into()?) as usize;
+
+    // An animation always has at least the first frame
+    if count == 0 {
+        return Err(ChunkError::InvalidLen);
+    }
+
+    // The delays, then the bytes of every chunk of every additional frame
+    let delays_end = count
+        .checked_mul(4)
+        .and_then(|len| len.checked_add(4))
+        .filter(|end| *end <= payload.len())
+        .ok_or(ChunkError::DimensionMismatch)?;
+
+    let delays = payload[4..delays_end]
+        .chunks(4)
+        .map(|delay| Ok(u32::from_be_bytes(delay.try_into()?)))
+        .collect::<Result<Vec<u32>, ChunkError>>()?;
+
+    // The length of runs is only known once they're decoded
+    if header.is_compressed() {
+        let mut frames = Vec::with_capacity(count - 1);
+        let mut offset = delays_end;
+
+        for delay in delays.iter().skip(1) {
+            let (chunks, len) = chunk::decode_runs(&payload[offset..], pixels, alpha, checksum)?;
+            frames.push(Frame::new(chunks, *delay));
+            offset += len;
+        }
+
+        if offset != payload.len() {
+            return Err(ChunkError::DimensionMismatch);
+        }
+
+        return Ok((delays[0], frames));
+    }
+
+    let expected_len = (count - 1)
+        .checked_mul(pixels)
+        .and_then(|chunks| chunks.checked_mul(chunk::len(alpha)))
+        .and_then(|len| len.checked_add(delays_end));
+
+    if expected_len != Some(payload.len()) {
+        return Err(ChunkError::DimensionMismatch);
+    }
+
+    let mut frames = Vec::with_capacity(count - 1);
+
+    // Frame data only exists for animations with more than one frame
+    if pixels > 0 {
+        for (bytes, delay) in payload[delays_end..]
+            .chunks(pixels * chunk::len(alpha))
+            .zip(delays.iter().skip(1))
+        {
+            let chunks = chunk::decode_all(bytes, alpha, checksum)?;
+
+            frames.push(Frame::new(chunks, *delay));
+        }
+    } else {
+        frames.extend(delays.iter().skip(1).map(|delay| Frame::new(Vec::new(), *delay)));
+    }
+
+    Ok((delays[0], frames))
+}
+
+#[cfg(test)]
+mod anim_tests {
+    use super::*;
+    use crate::checksum::ChecksumAlgorithm;
+
+    // The payloads below carry the legacy checksum of each chunk
+    fn legacy_header(x: usize, y: usize) -> Header {
+        Header::new(x, y).checksummed(ChecksumAlgorithm::Ciebii)
+    }
+
+    #[test]
+    fn encode_frames() {
+        let frames = vec![Frame::new(vec![Chunk::new(255, 0, 0)], 200)];
+
+        assert_eq!(
+            encode(100, &frames, &legacy_header(1, 1)),
+            [0, 0, 0, 2, 0, 0, 0, 100, 0, 0, 0, 200, 255, 0, 0, 0, 252]
+        );
+    }
+
+    #[test]
+    fn decode_frames() {
+        let payload = vec![0, 0, 0, 2, 0, 0, 0, 100, 0, 0, 0, 200, 255, 0, 0, 0, 252];
+
+        let (first_delay, frames) = decode(&payload, 1, &legacy_header(1, 1)).unwrap();
+
+        assert_eq!(first_delay, 100);
+        assert_eq!(frames, vec![Frame::new(vec![Chunk::new(255, 0, 0)], 200)]);
+    }
+
+    #[test]
+    fn decode_dimension_mismatch() {
+        let payload = vec![0, 0, 0, 2, 0, 0, 0, 100, 0, 0, 0, 200, 255, 0, 0, 0, 252];
+
+        let header = legacy_header(2, 1);
+
+        if let ChunkError::DimensionMismatch = decode(&payload, 2, &header).unwrap_err() {
+        } else {
+            panic!()
+        }
+    }
+
+    #[test]
+    fn decode_checksum_fail() {
+        let payload = vec![0, 0, 0, 2, 0, 0, 0, 100, 0, 0, 0, 200, 255, 0, 0, 0, 253];
+
+        if let ChunkError::ChecksumFail = decode(&payload, 1, &legacy_header(1, 1)).unwrap_err() {
+        } else {
+            panic!()
+        }
+    }
+
+    #[test]
+    fn compressed_frames() {
+        let header = Header::new(3, 1).compressed(true);
+        let (red, green) = (Chunk::new(255, 0, 0), Chunk::new(0, 255, 0));
+        let frames = vec![
+            Frame::new(vec![red; 3], 200),
+            Frame::new(vec![red, green, green], 300),
+        ];
+
+        let payload = encode(100, &frames, &header);
+        assert_eq!(payload.len(), 16 + 3 * (1 + Chunk::LEN));
+        assert_eq!(decode(&payload, 3, &header).unwrap(), (100, frames));
+
+        if let ChunkError::DimensionMismatch = decode(&payload[..16], 3, &header).unwrap_err() {
+        } else {
+            panic!()
+        }
+    }
+}
@@ -1,5 +1,35 @@
+/// How the chunks, sections and header of a file are checksummed. Every checksum takes 2
+/// bytes whichever is used, so the layout of a file doesn't depend on it.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ChecksumAlgorithm {
+    /// The 16 bit checksum of `checksum`, which every ciebii file used before CRC-32. Files
+    /// without the CRC-32 flag in their header are still read with it.
+    Ciebii,
 
-/// Creates a checksum given a stream of bytes
+    /// The low 16 bits of a CRC-32, which catches every change of a single byte and collides
+    /// far less on small ones. New files use it.
+    #[default]
+    Crc32,
+}
+
+impl ChecksumAlgorithm {
+    /// Checksums `data` into the 2 bytes a ciebii file stores
+    /// ```
+    /// use ciebii_lib::checksum::{checksum, ChecksumAlgorithm};
+    ///
+    /// assert_eq!(ChecksumAlgorithm::Ciebii.checksum(b"abc"), checksum(b"abc"));
+    /// assert_eq!(ChecksumAlgorithm::Crc32.checksum(b"abc"), 0x41c2);
+    /// ```
+    pub fn checksum(self, data: &[u8]) -> u16 {
+        match self {
+            ChecksumAlgorithm::Ciebii => checksum(data),
+            ChecksumAlgorithm::Crc32 => crc32fast::hash(data) as u16,
+        }
+    }
+}
+
+/// Creates a checksum given a stream of bytes. This is the legacy checksum of
+/// `ChecksumAlgorithm::Ciebii`, kept for reading and writing files that use it.
 pub fn checksum(data: &[u8]) -> u16 {
     let mut total: u16 = 0;
     let mut prev: u8 = 0xAB;
@@ -8,8 +38,8 @@ pub fn checksum(data: &[u8]) -> u16 {
         // XOR the byte with the previous modified byte
         let new_byte = b ^ prev;
 
-        // Add the new byte to the total
-        total += new_byte as u16;
+        // Add the new byte to the total, wrapping around on long inputs
+        total = total.wrapping_add(new_byte as u16);
 
         // Modify the previous byte
         prev = new_byte - (total << 8) as u8;
@@ -1,8 +1,11 @@
-use super::checksum::checksum;
+use super::checksum::{checksum, ChecksumAlgorithm};
 use super::error::*;
-use super::rgb::RGB;
+use super::rgb::{RGB, RGBA};
 
-/// A ciebii chunk consisting of an RGB code along with a checksum
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// A ciebii chunk consisting of an RGB code and its alpha along with a checksum
 /// ```
 /// use ciebii_lib::chunk::Chunk;
 /// let chunk = Chunk::new(255, 0, 0);
@@ -10,15 +13,34 @@ use super::rgb::RGB;
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub struct Chunk {
     rgb: RGB,
+    alpha: u8,
     checksum: u16,
 }
 
 #[allow(dead_code)]
 impl Chunk {
+
+    /// The length of a chunk in bytes
+    pub const LEN: usize = 5;
+
+    /// The length of a chunk in bytes in files with an alpha channel
+    pub const RGBA_LEN: usize = 6;
+
+    /// Creates a fully opaque chunk
     pub fn new(r: u8, g: u8, b: u8) -> Self {
+        Self::with_alpha(r, g, b, 255)
+    }
+
+    /// Creates a chunk with the given alpha, 255 being fully opaque
+    /// ```
+    /// use ciebii_lib::chunk::Chunk;
+    /// let chunk = Chunk::with_alpha(255, 0, 0, 128);
+    /// assert!(!chunk.is_opaque());
+    /// ```
+    pub fn with_alpha(r: u8, g: u8, b: u8, alpha: u8) -> Self {
         let rgb = RGB::new(r, g, b);
-        let checksum = checksum(&rgb.as_bytes());
-        Self { rgb, checksum }
+        let checksum = expected_checksum(rgb, alpha);
+        Self { rgb, alpha, checksum }
     }
 
     /// Returns the RGB code in a ciebii RGB struct.
@@ -26,34 +48,275 @@ impl Chunk {
         self.rgb
     }
 
-    /// Returns the u16 checksum of this chunk.
+    /// Returns the RGB code along with the alpha.
+    pub fn rgba(&self) -> RGBA {
+        let (r, g, b) = self.rgb.color();
+        RGBA::new(r, g, b, self.alpha)
+    }
+
+    /// Returns the alpha of this chunk, 255 being fully opaque.
+    pub fn alpha(&self) -> u8 {
+        self.alpha
+    }
+
+    /// Returns whether this chunk is fully opaque.
+    pub fn is_opaque(&self) -> bool {
+        self.alpha == 255
+    }
+
+    /// Returns the u16 checksum of this chunk. It covers the alpha too, unless the chunk is
+    /// fully opaque, so opaque chunks have the same checksum with or without an alpha channel.
+    /// This is the legacy ciebii checksum, which the bytes of a chunk carry unless their file
+    /// is checksummed with CRC-32, see `ChecksumAlgorithm`.
     pub fn checksum(&self) -> u16 {
         self.checksum
     }
 
+    /// Returns whether the stored checksum matches the RGB code and alpha.
+    pub fn is_valid(&self) -> bool {
+        self.checksum == expected_checksum(self.rgb, self.alpha)
+    }
+
     /// Returns this chunk as a vector of bytes.
     /// It returns it in the format \[RGB | CHECKSUM]
     pub fn as_bytes(&self) -> Vec<u8> {
+        self.to_array().to_vec()
+    }
+
+    /// Returns this chunk as bytes like `as_bytes`, without allocating.
+    /// There's no room for the alpha, so a translucent chunk is written as if it were opaque.
+    pub fn to_array(&self) -> [u8; Self::LEN] {
+        let (r, g, b) = self.rgb.color();
+        let checksum = match self.is_opaque() {
+            true => self.checksum,
+            false => checksum(&[r, g, b]),
+        };
+        let [high, low] = checksum.to_be_bytes();
+
+        [r, g, b, high, low]
+    }
+
+    /// Returns this chunk as bytes in the format \[RGB | ALPHA | CHECKSUM], the way files with
+    /// an alpha channel store it.
+    pub fn to_rgba_array(&self) -> [u8; Self::RGBA_LEN] {
+        let (r, g, b) = self.rgb.color();
+        let [high, low] = self.checksum.to_be_bytes();
+
+        [r, g, b, self.alpha, high, low]
+    }
+
+    /// Decodes a chunk stored with its alpha, see `to_rgba_array`.
+    pub fn try_from_rgba(bytes: &[u8]) -> Result<Self, ChunkError> {
+        let bytes: [u8; Self::RGBA_LEN] = bytes.try_into().map_err(|_| ChunkError::InvalidLen)?;
+        let [r, g, b, alpha, high, low] = bytes;
+        let chunk = Chunk::with_alpha(r, g, b, alpha);
+
+        if u16::from_be_bytes([high, low]) != chunk.checksum {
+            return Err(ChunkError::ChecksumFail);
+        }
+
+        Ok(chunk)
+    }
+}
+
+/// Returns the checksum a chunk of `rgb` and `alpha` should have
+fn expected_checksum(rgb: RGB, alpha: u8) -> u16 {
+    let (r, g, b) = rgb.color();
+
+    match alpha {
+        255 => checksum(&[r, g, b]),
+        _ => checksum(&[r, g, b, alpha]),
+    }
+}
+
+/// The longest run of equal chunks a single run can hold, its length is stored minus one in
+/// a byte
+pub(crate) const MAX_RUN: usize = 256;
+
+/// Returns the length of a chunk in bytes, with or without an alpha channel
+pub(crate) fn len(alpha: bool) -> usize {
+    match alpha {
+        true => Chunk::RGBA_LEN,
+        false => Chunk::LEN,
+    }
+}
+
+/// Writes the bytes of `chunk` to `target`, with its alpha if `alpha` is set. Files
+/// checksummed with CRC-32 store the checksum of the color bytes as they are in the file,
+/// rather than the one the chunk carries.
+pub(crate) fn encode_one(
+    chunk: &Chunk,
+    alpha: bool,
+    checksum: ChecksumAlgorithm,
+    target: &mut [u8],
+) {
+    match alpha {
+        true => target.copy_from_slice(&chunk.to_rgba_array()),
+        false => target.copy_from_slice(&chunk.to_array()),
+    }
 
-        // Merge the rgb and checksum
-        let bytes: Vec<u8> = self
-            .rgb
-            .as_bytes()
-            .iter()
-            .chain(self.checksum.to_be_bytes().iter())
-            .cloned()
-            .collect();
-        bytes
+    if checksum == ChecksumAlgorithm::Crc32 {
+        let (color, stored) = target.split_at_mut(len(alpha) - 2);
+        stored.copy_from_slice(&checksum.checksum(color).to_be_bytes());
     }
 }
 
+/// Appends the bytes of every chunk to `bytes`, with their alpha if `alpha` is set and
+/// checksummed with `checksum`, making room for all of them at once.
+#[cfg(not(feature = "parallel"))]
+pub(crate) fn encode_into(
+    chunks: &[Chunk],
+    alpha: bool,
+    checksum: ChecksumAlgorithm,
+    bytes: &mut Vec<u8>,
+) {
+    let start = bytes.len();
+    bytes.resize(start + chunks.len() * len(alpha), 0);
+
+    for (target, chunk) in bytes[start..].chunks_exact_mut(len(alpha)).zip(chunks) {
+        encode_one(chunk, alpha, checksum, target);
+    }
+}
+
+/// Every chunk has its own slot in `bytes`, so they can be filled in on any thread.
+#[cfg(feature = "parallel")]
+pub(crate) fn encode_into(
+    chunks: &[Chunk],
+    alpha: bool,
+    checksum: ChecksumAlgorithm,
+    bytes: &mut Vec<u8>,
+) {
+    let start = bytes.len();
+    bytes.resize(start + chunks.len() * len(alpha), 0);
+
+    bytes[start..]
+        .par_chunks_exact_mut(len(alpha))
+        .zip(chunks)
+        .for_each(|(target, chunk)| encode_one(chunk, alpha, checksum, target));
+}
+
+/// Creates an opaque chunk for every color in order. Working out the checksums is most of
+/// the work of decoding other formats, so with the `parallel` feature it's spread over
+/// every thread.
+#[cfg(not(feature = "parallel"))]
+pub(crate) fn from_colors(colors: &[[u8; 3]]) -> Vec<Chunk> {
+    colors.iter().map(|&[r, g, b]| Chunk::new(r, g, b)).collect()
+}
+
+#[cfg(feature = "parallel")]
+pub(crate) fn from_colors(colors: &[[u8; 3]]) -> Vec<Chunk> {
+    colors.par_iter().map(|&[r, g, b]| Chunk::new(r, g, b)).collect()
+}
+
+/// Decodes a single chunk, with its alpha if `alpha` is set and checksummed with `checksum`
+pub(crate) fn decode(
+    bytes: &[u8],
+    alpha: bool,
+    checksum: ChecksumAlgorithm,
+) -> Result<Chunk, ChunkError> {
+    match (checksum, alpha) {
+        (ChecksumAlgorithm::Ciebii, true) => Chunk::try_from_rgba(bytes),
+        (ChecksumAlgorithm::Ciebii, false) => Chunk::try_from(bytes),
+        (ChecksumAlgorithm::Crc32, _) => {
+            if bytes.len() != len(alpha) {
+                return Err(ChunkError::InvalidLen);
+            }
+
+            let (color, stored) = bytes.split_at(len(alpha) - 2);
+            if u16::from_be_bytes([stored[0], stored[1]]) != checksum.checksum(color) {
+                return Err(ChunkError::ChecksumFail);
+            }
+
+            let a = if alpha { color[3] } else { 255 };
+            Ok(Chunk::with_alpha(color[0], color[1], color[2], a))
+        }
+    }
+}
+
+/// Decodes chunks stored back to back in order, `Chunk::RGBA_LEN` bytes each if `alpha` is
+/// set and `Chunk::LEN` otherwise. A partial chunk at the end fails with `InvalidLen`.
+#[cfg(not(feature = "parallel"))]
+pub(crate) fn decode_all(
+    bytes: &[u8],
+    alpha: bool,
+    checksum: ChecksumAlgorithm,
+) -> Result<Vec<Chunk>, ChunkError> {
+    bytes.chunks(len(alpha)).map(|chunk| decode(chunk, alpha, checksum)).collect()
+}
+
+/// Any thread may be the first to fail, so a broken file is decoded again in order to
+/// report the same error as without the `parallel` feature.
+#[cfg(feature = "parallel")]
+pub(crate) fn decode_all(
+    bytes: &[u8],
+    alpha: bool,
+    checksum: ChecksumAlgorithm,
+) -> Result<Vec<Chunk>, ChunkError> {
+    let decode = |chunk| decode(chunk, alpha, checksum);
+
+    bytes
+        .par_chunks(len(alpha))
+        .map(decode)
+        .collect::<Result<_, _>>()
+        .or_else(|_| bytes.chunks(len(alpha)).map(decode).collect())
+}
+
+/// Appends `chunks` to `bytes` as runs of equal chunks, each run stored as its length minus
+/// one in a byte followed by the chunk. Runs longer than `MAX_RUN` are split up.
+pub(crate) fn encode_runs(
+    chunks: &[Chunk],
+    alpha: bool,
+    checksum: ChecksumAlgorithm,
+    bytes: &mut Vec<u8>,
+) {
+    for run in chunks.chunk_by(|a, b| a == b).flat_map(|run| run.chunks(MAX_RUN)) {
+        bytes.push((run.len() - 1) as u8);
+        encode_into(&run[..1], alpha, checksum, bytes);
+    }
+}
+
+/// Decodes the runs at the start of `bytes` until they add up to `count` chunks, see
+/// `encode_runs`. Returns the chunks along with how many bytes the runs took.
+///
+/// A run cut short fails with `InvalidLen`, and running out of runs or a run going past
+/// `count` with `DimensionMismatch`.
+pub(crate) fn decode_runs(
+    bytes: &[u8],
+    count: usize,
+    alpha: bool,
+    checksum: ChecksumAlgorithm,
+) -> Result<(Vec<Chunk>, usize), ChunkError> {
+    let run_len = 1 + len(alpha);
+
+    // `count` comes from the header, only reserve what the runs there can actually hold
+    let mut chunks = Vec::with_capacity(count.min(bytes.len() / run_len * MAX_RUN));
+    let mut offset = 0;
+
+    while chunks.len() < count {
+        let run = bytes.get(offset..).ok_or(ChunkError::DimensionMismatch)?;
+        let (&length, chunk) = run.split_first().ok_or(ChunkError::DimensionMismatch)?;
+        let chunk = chunk.get(..len(alpha)).ok_or(ChunkError::InvalidLen)?;
+        let chunk = decode(chunk, alpha, checksum)?;
+        let length = length as usize + 1;
+
+        if chunks.len() + length > count {
+            return Err(ChunkError::DimensionMismatch);
+        }
+
+        chunks.extend(std::iter::repeat_n(chunk, length));
+        offset += run_len;
+    }
+
+    Ok((chunks, offset))
+}
+
 impl TryFrom<&[u8]> for Chunk {
     type Error = ChunkError;
 
     fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
 
         // All chunks need to be 5 bytes
-        if bytes.len() != 5 {
+        if bytes.len() != Chunk::LEN {
             return Err(ChunkError::InvalidLen);
         }
 
@@ -65,6 +328,7 @@ impl TryFrom<&[u8]> for Chunk {
 
         // create an RGB struct
         let rgb = RGB::new(rgb[0], rgb[1], rgb[2]);
+        let alpha = 255;
 
         // Do some bit shifting to get the original checksum
         let original_checksum = ((check[0] as u16) << 8) | check[1] as u16;
@@ -76,6 +340,7 @@ impl TryFrom<&[u8]> for Chunk {
 
         Ok(Chunk {
             rgb,
+            alpha,
             checksum: new_checksum,
         })
     }
@@ -85,6 +350,9 @@ impl TryFrom<&[u8]> for Chunk {
 mod chunk_tests {
     use super::*;
 
+    const CIEBII: ChecksumAlgorithm = ChecksumAlgorithm::Ciebii;
+    const CRC32: ChecksumAlgorithm = ChecksumAlgorithm::Crc32;
+
     #[test]
     fn create_new_chunk() {
         let chunk = Chunk::new(255, 0, 0);
@@ -93,6 +361,15 @@ mod chunk_tests {
         assert_eq!(chunk.as_bytes(), [255, 0, 0, 0, 252]);
     }
 
+    #[test]
+    fn chunk_is_valid() {
+        let mut chunk = Chunk::new(0xAB, 0xCD, 0xEF);
+        assert!(chunk.is_valid());
+
+        chunk.checksum += 1;
+        assert!(!chunk.is_valid());
+    }
+
     #[test]
     fn try_from_invalid_len() {
         let data: Vec<u8> = vec![1, 2, 3];
@@ -133,4 +410,153 @@ mod chunk_tests {
 
         assert_eq!(chunk.rgb(), RGB::new(0xAB, 0xCD, 0xEF));
     }
+
+    #[test]
+    fn encode_decode_all() {
+        let chunks: Vec<Chunk> = (0..5000).map(|i| Chunk::with_alpha(i as u8, 7, 9, 200)).collect();
+
+        let mut bytes = vec![1, 2];
+        encode_into(&chunks, true, CIEBII, &mut bytes);
+
+        assert_eq!(bytes[..2], [1, 2]);
+        assert_eq!(bytes[2..8], chunks[0].to_rgba_array());
+        assert_eq!(decode_all(&bytes[2..], true, CIEBII).unwrap(), chunks);
+    }
+
+    #[test]
+    fn decode_all_first_error() {
+        let mut bytes = Vec::new();
+        encode_into(&vec![Chunk::new(1, 2, 3); 5000], false, CRC32, &mut bytes);
+        bytes[Chunk::LEN] ^= 1;
+        bytes.pop();
+
+        if let ChunkError::ChecksumFail = decode_all(&bytes, false, CRC32).unwrap_err() {
+        } else {
+            panic!()
+        }
+    }
+
+    #[test]
+    fn crc32_round_trip() {
+        let chunks = [Chunk::new(1, 2, 3), Chunk::with_alpha(4, 5, 6, 7)];
+
+        for alpha in [false, true] {
+            let mut bytes = Vec::new();
+            encode_into(&chunks[..1 + alpha as usize], alpha, CRC32, &mut bytes);
+
+            let stored = &bytes[len(alpha) - 2..len(alpha)];
+            let color = &bytes[..len(alpha) - 2];
+            assert_eq!(stored, (crc32fast::hash(color) as u16).to_be_bytes());
+            assert_eq!(decode_all(&bytes, alpha, CRC32).unwrap(), chunks[..1 + alpha as usize]);
+        }
+    }
+
+    #[test]
+    fn crc32_catches_legacy_collision() {
+        let mut legacy = Chunk::new(1, 2, 3).to_array();
+        let mut crc = [0; Chunk::LEN];
+        encode_one(&Chunk::new(1, 2, 3), false, CRC32, &mut crc);
+
+        // The legacy checksum of 1, 3, 3 is the same as that of 1, 2, 3
+        legacy[1] ^= 1;
+        crc[1] ^= 1;
+
+        assert_eq!(decode(&legacy, false, CIEBII).unwrap(), Chunk::new(1, 3, 3));
+        if let ChunkError::ChecksumFail = decode(&crc, false, CRC32).unwrap_err() {
+        } else {
+            panic!()
+        }
+    }
+
+    #[test]
+    fn rgba_round_trip() {
+        let chunk = Chunk::with_alpha(0xAB, 0xCD, 0xEF, 0x80);
+        let bytes = chunk.to_rgba_array();
+
+        assert_eq!(bytes[3], 0x80);
+        assert_eq!(Chunk::try_from_rgba(&bytes).unwrap(), chunk);
+        assert_eq!(chunk.rgba(), RGBA::new(0xAB, 0xCD, 0xEF, 0x80));
+    }
+
+    #[test]
+    fn rgba_checksum_covers_alpha() {
+        let mut bytes = Chunk::with_alpha(0xAB, 0xCD, 0xEF, 0x80).to_rgba_array();
+        bytes[3] = 0xFF;
+
+        if let ChunkError::ChecksumFail = Chunk::try_from_rgba(&bytes).unwrap_err() {
+        } else {
+            panic!()
+        }
+    }
+
+    #[test]
+    fn opaque_checksum_matches() {
+        let chunk = Chunk::new(0xAB, 0xCD, 0xEF);
+
+        assert_eq!(Chunk::with_alpha(0xAB, 0xCD, 0xEF, 255), chunk);
+        assert_eq!(chunk.to_rgba_array()[4..], chunk.to_array()[3..]);
+    }
+
+    #[test]
+    fn translucent_as_opaque() {
+        let bytes = Chunk::with_alpha(0xAB, 0xCD, 0xEF, 0).to_array();
+
+        assert_eq!(Chunk::try_from(&bytes[..]).unwrap(), Chunk::new(0xAB, 0xCD, 0xEF));
+    }
+
+    #[test]
+    fn runs_round_trip() {
+        let mut chunks = vec![Chunk::new(1, 2, 3); 300];
+        chunks.push(Chunk::new(4, 5, 6));
+        chunks.push(Chunk::new(1, 2, 3));
+
+        let mut bytes = Vec::new();
+        encode_runs(&chunks, false, CIEBII, &mut bytes);
+        bytes.push(0xFF);
+
+        // 256 + 44 of the first color, then two single chunks
+        assert_eq!(bytes.len(), 4 * (1 + Chunk::LEN) + 1);
+        assert_eq!((bytes[0], bytes[6]), (255, 43));
+        assert_eq!(
+            decode_runs(&bytes, chunks.len(), false, CIEBII).unwrap(),
+            (chunks, bytes.len() - 1)
+        );
+    }
+
+    #[test]
+    fn runs_with_alpha() {
+        let chunks = vec![Chunk::with_alpha(1, 2, 3, 4); 3];
+
+        let mut bytes = Vec::new();
+        encode_runs(&chunks, true, CRC32, &mut bytes);
+
+        assert_eq!(bytes.len(), 1 + Chunk::RGBA_LEN);
+        assert_eq!(decode_runs(&bytes, 3, true, CRC32).unwrap().0, chunks);
+    }
+
+    #[test]
+    fn runs_cut_short() {
+        let mut bytes = Vec::new();
+        encode_runs(&[Chunk::new(1, 2, 3); 4], false, CRC32, &mut bytes);
+
+        if let ChunkError::InvalidLen = decode_runs(&bytes[..4], 4, false, CRC32).unwrap_err() {
+        } else {
+            panic!()
+        }
+        if let ChunkError::DimensionMismatch = decode_runs(&bytes, 5, false, CRC32).unwrap_err() {
+        } else {
+            panic!()
+        }
+    }
+
+    #[test]
+    fn run_past_count() {
+        let mut bytes = Vec::new();
+        encode_runs(&[Chunk::new(1, 2, 3); 4], false, CRC32, &mut bytes);
+
+        if let ChunkError::DimensionMismatch = decode_runs(&bytes, 3, false, CRC32).unwrap_err() {
+        } else {
+            panic!()
+        }
+    }
 }
@@ -0,0 +1,154 @@
+use super::{
+    checksum::{checksum, ChecksumAlgorithm},
+    chunk::{self, Chunk},
+    error::ChunkError,
+    file::CIEBIIFILE,
+};
+
+// Magic bytes spelling "SHITFILE", used by the format before it was renamed
+pub const LEGACY_MAGIC_BYTES: [u8; 8] = *b"SHITFILE";
+
+/// The length of the legacy header: magic bytes, then x and y (u64 each), then a u32
+/// checksum of the dimensions
+pub const LEGACY_HEADER_LEN: usize = 28;
+
+/// The usual extension of legacy files
+pub const LEGACY_EXTENSION: &str = "shf";
+
+/// Encodes the first frame of `file` in the legacy SHITFILE format.
+/// Legacy files have no sections, so animation frames and metadata are dropped.
+/// ```
+/// use ciebii_lib::{chunk::Chunk, compat, file::CIEBIIFILE};
+/// let file = CIEBIIFILE::try_from_chunks(1, 1, vec![Chunk::new(255, 0, 0)]).unwrap();
+/// assert_eq!(compat::decode(&compat::encode(&file)).unwrap().chunks(), file.chunks());
+/// ```
+pub fn encode(file: &CIEBIIFILE) -> Vec<u8> {
+    let (x, y) = file.dimensions();
+
+    // The dimensions are checksummed the same way as in a ciebii header
+    let dimensions: Vec<u8> = (x as u64)
+        .to_be_bytes()
+        .iter()
+        .chain((y as u64).to_be_bytes().iter())
+        .cloned()
+        .collect();
+    let checksum = checksum(&dimensions) as u32;
+
+    LEGACY_MAGIC_BYTES
+        .iter()
+        .chain(dimensions.iter())
+        .chain(checksum.to_be_bytes().iter())
+        .cloned()
+        .chain(file.chunks().iter().flat_map(|chunk| chunk.as_bytes()))
+        .collect()
+}
+
+/// Decodes a legacy SHITFILE into a ciebii file. The pixel chunks are the same in both formats,
+/// as long as the ciebii file keeps the legacy checksum, which it does.
+pub fn decode(bytes: &[u8]) -> Result<CIEBIIFILE, ChunkError> {
+    let header = bytes.get(..LEGACY_HEADER_LEN).ok_or(ChunkError::InvalidLen)?;
+
+    // Make sure that the magic bytes match
+    if header[..8] != LEGACY_MAGIC_BYTES {
+        return Err(ChunkError::IllegalHeader);
+    }
+
+    let dimensions = &header[8..24];
+    let old_checksum = u32::from_be_bytes(header[24..28].try_into()?);
+
+    if old_checksum != checksum(dimensions) as u32 {
+        return Err(ChunkError::ChecksumFail);
+    }
+
+    let x = u64::from_be_bytes(dimensions[..8].try_into()?);
+    let y = u64::from_be_bytes(dimensions[8..].try_into()?);
+    let x = usize::try_from(x).map_err(|_| ChunkError::DimensionMismatch)?;
+    let y = usize::try_from(y).map_err(|_| ChunkError::DimensionMismatch)?;
+
+    // One chunk per pixel, nothing may follow
+    let pixels = x.checked_mul(y).ok_or(ChunkError::DimensionMismatch)?;
+    let body = &bytes[LEGACY_HEADER_LEN..];
+    if pixels.checked_mul(Chunk::LEN) != Some(body.len()) {
+        return Err(ChunkError::DimensionMismatch);
+    }
+
+    let chunks = chunk::decode_all(body, false, ChecksumAlgorithm::Ciebii)?;
+
+    let mut file = CIEBIIFILE::try_from_chunks(x, y, chunks)?;
+    file.set_checksum_algorithm(ChecksumAlgorithm::Ciebii);
+
+    Ok(file)
+}
+
+#[cfg(test)]
+mod compat_tests {
+    use super::*;
+
+    fn test_file() -> CIEBIIFILE {
+        let chunks = vec![
+            Chunk::new(0xAB, 0xCD, 0xEF),
+            Chunk::new(0x12, 0x34, 0x56),
+            Chunk::new(0x69, 0x42, 0x00),
+            Chunk::new(0xDE, 0xAD, 0xA5),
+        ];
+
+        let mut file = CIEBIIFILE::try_from_chunks(2, 2, chunks).unwrap();
+        file.set_checksum_algorithm(ChecksumAlgorithm::Ciebii);
+
+        file
+    }
+
+    #[test]
+    fn round_trip() {
+        let bytes = encode(&test_file());
+
+        assert!(bytes.starts_with(b"SHITFILE"));
+        assert_eq!(bytes.len(), LEGACY_HEADER_LEN + 4 * 5);
+        assert_eq!(decode(&bytes).unwrap(), test_file());
+    }
+
+    #[test]
+    fn same_chunks_as_ciebii() {
+        let legacy = encode(&test_file());
+        let ciebii = test_file().as_bytes();
+
+        assert_eq!(legacy[LEGACY_HEADER_LEN..], ciebii[30..]);
+    }
+
+    #[test]
+    fn decode_illegal_header() {
+        let mut bytes = encode(&test_file());
+        bytes[0] = b'X';
+
+        if let ChunkError::IllegalHeader = decode(&bytes).unwrap_err() {
+        } else {
+            panic!()
+        }
+    }
+
+    #[test]
+    fn decode_checksum_fail() {
+        let mut bytes = encode(&test_file());
+        bytes[15] ^= 1;
+
+        if let ChunkError::ChecksumFail = decode(&bytes).unwrap_err() {
+        } else {
+            panic!()
+        }
+    }
+
+    #[test]
+    fn decode_truncated() {
+        let bytes = encode(&test_file());
+
+        if let ChunkError::DimensionMismatch = decode(&bytes[..bytes.len() - 5]).unwrap_err() {
+        } else {
+            panic!()
+        }
+
+        if let ChunkError::InvalidLen = decode(&bytes[..10]).unwrap_err() {
+        } else {
+            panic!()
+        }
+    }
+}
@@ -0,0 +1,95 @@
+use super::{error::ChunkError, file::CIEBIIFILE};
+
+/// Cuts the `width` by `height` rectangle starting at `x`, `y` out of every frame of `file`.
+/// Frame delays and metadata are kept. Fails with `DimensionMismatch` if the rectangle
+/// doesn't fit inside the image or is empty.
+/// ```
+/// use ciebii_lib::{chunk::Chunk, crop::crop, file::CIEBIIFILE};
+/// let chunks = (0..4).map(|i| Chunk::new(i, 0, 0)).collect();
+/// let file = CIEBIIFILE::try_from_chunks(2, 2, chunks).unwrap();
+///
+/// let cropped = crop(&file, 1, 0, 1, 2).unwrap();
+/// assert_eq!(cropped.chunks(), &[Chunk::new(1, 0, 0), Chunk::new(3, 0, 0)]);
+/// ```
+pub fn crop(
+    file: &CIEBIIFILE,
+    x: usize,
+    y: usize,
+    width: usize,
+    height: usize,
+) -> Result<CIEBIIFILE, ChunkError> {
+    let (file_width, file_height) = file.dimensions();
+
+    let fits = |start: usize, len: usize, side: usize| {
+        len > 0 && start.checked_add(len).is_some_and(|end| end <= side)
+    };
+    if !fits(x, width, file_width) || !fits(y, height, file_height) {
+        return Err(ChunkError::DimensionMismatch);
+    }
+
+    file.map_frames(width, height, |chunks| {
+        chunks
+            .chunks(file_width)
+            .skip(y)
+            .take(height)
+            .flat_map(|row| row[x..x + width].iter().cloned())
+            .collect()
+    })
+}
+
+#[cfg(test)]
+mod crop_tests {
+    use super::*;
+    use crate::{chunk::Chunk, section::Section};
+
+    // A 3x3 file where the red channel is the index of the pixel
+    fn test_file() -> CIEBIIFILE {
+        let chunks = (0..9).map(|i| Chunk::new(i, 0, 0)).collect();
+
+        CIEBIIFILE::try_from_chunks(3, 3, chunks).unwrap()
+    }
+
+    fn reds(chunks: &[Chunk]) -> Vec<u8> {
+        chunks.iter().map(|chunk| chunk.rgb().color().0).collect()
+    }
+
+    #[test]
+    fn crop_center() {
+        let cropped = crop(&test_file(), 1, 1, 2, 2).unwrap();
+
+        assert_eq!(cropped.dimensions(), (2, 2));
+        assert_eq!(reds(cropped.chunks()), [4, 5, 7, 8]);
+    }
+
+    #[test]
+    fn crop_whole() {
+        assert_eq!(crop(&test_file(), 0, 0, 3, 3).unwrap(), test_file());
+    }
+
+    #[test]
+    fn crop_out_of_bounds() {
+        let regions = [(2, 0, 2, 1), (0, 3, 1, 1), (0, 0, 0, 1), (usize::MAX, 0, 2, 1)];
+
+        for (x, y, width, height) in regions {
+            let err = crop(&test_file(), x, y, width, height).unwrap_err();
+
+            if let ChunkError::DimensionMismatch = err {
+            } else {
+                panic!()
+            }
+        }
+    }
+
+    #[test]
+    fn crop_keeps_frames_and_metadata() {
+        let mut file = test_file();
+        file.push_frame(test_file().chunks().iter().rev().cloned().collect(), 40).unwrap();
+        file.set_metadata(Section::EXIF, vec![1, 2, 3]);
+
+        let cropped = crop(&file, 0, 0, 1, 2).unwrap();
+
+        assert_eq!(reds(cropped.frame(1).unwrap()), [8, 5]);
+        assert_eq!(cropped.frame_delay(1), Some(40));
+        assert_eq!(cropped.metadata(Section::EXIF), Some(&[1, 2, 3][..]));
+    }
+}
@@ -0,0 +1,201 @@
+use super::{
+    chunk::{self, Chunk},
+    error::ChunkError,
+    header::Header,
+    section::Section,
+};
+
+/// Returns the length of the record of a pixel in files with `header`: its chunk, along with
+/// the length of its run if the file is compressed
+pub(crate) fn record_len(header: &Header) -> usize {
+    header.chunk_len() + header.is_compressed() as usize
+}
+
+/// Reads the records of a ciebii file front to back, each by its own declared size:
+/// the header, then the pixel chunks, then any sections. Whole files are read from a slice,
+/// the streaming decoders hand it whatever part of the file they read last.
+pub(crate) struct Cursor<B: AsRef<[u8]>> {
+    bytes: B,
+    offset: usize,
+}
+
+impl<B: AsRef<[u8]>> Cursor<B> {
+    pub(crate) fn new(bytes: B) -> Self {
+        Self { bytes, offset: 0 }
+    }
+
+    /// Returns the bytes left to read
+    fn rest(&self) -> &[u8] {
+        &self.bytes.as_ref()[self.offset..]
+    }
+
+    /// Returns whether every byte has been read
+    pub(crate) fn is_empty(&self) -> bool {
+        self.rest().is_empty()
+    }
+
+    /// Returns how far into the bytes the cursor is
+    pub(crate) fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns whether the bytes left could start a section, rather than being garbage
+    pub(crate) fn at_section(&self) -> bool {
+        Section::is_start(self.rest())
+    }
+
+    /// Takes the next `len` bytes, if there are that many left
+    fn take(&mut self, len: usize) -> Option<&[u8]> {
+        let end = self.offset.checked_add(len)?;
+        let bytes = self.bytes.as_ref().get(self.offset..end)?;
+        self.offset = end;
+
+        Some(bytes)
+    }
+
+    /// Reads the header. The shorter headers of 32-bit builds are only tried when the bytes
+    /// don't start with a current one. Fails with `InvalidLen` if the bytes end inside it.
+    pub(crate) fn header(&mut self) -> Result<Header, ChunkError> {
+        let current = self
+            .rest()
+            .get(..Header::LEN)
+            .ok_or(ChunkError::InvalidLen)
+            .and_then(|bytes| Header::try_from(bytes.to_vec()));
+
+        let (header, len) = match current {
+            Ok(header) => (header, Header::LEN),
+            Err(err) => match self.rest().get(..Header::LEN_32BIT) {
+                Some(bytes) => match Header::try_from(bytes.to_vec()) {
+                    Ok(header) => (header, Header::LEN_32BIT),
+                    Err(_) => return Err(err),
+                },
+                None => return Err(err),
+            },
+        };
+        self.offset += len;
+
+        Ok(header)
+    }
+
+    /// Takes the bytes of `count` chunks of `chunk_len` bytes each without decoding them yet,
+    /// or whatever is left if the bytes end first.
+    pub(crate) fn chunk_bytes(&mut self, count: usize, chunk_len: usize) -> &[u8] {
+        let len = count.saturating_mul(chunk_len).min(self.rest().len());
+
+        self.take(len).unwrap_or_default()
+    }
+
+    /// Reads `count` chunks stored one after the other the way `header` says. Fails with
+    /// `DimensionMismatch` if the bytes end first.
+    pub(crate) fn chunks(
+        &mut self,
+        count: usize,
+        header: &Header,
+    ) -> Result<Vec<Chunk>, ChunkError> {
+        let len = count
+            .checked_mul(header.chunk_len())
+            .ok_or(ChunkError::DimensionMismatch)?;
+        let bytes = self.take(len).ok_or(ChunkError::DimensionMismatch)?;
+
+        chunk::decode_all(bytes, header.has_alpha(), header.checksum_algorithm())
+    }
+
+    /// Reads the record of the next pixel the way `header` says, see `record_len`. Returns its
+    /// chunk along with how many pixels it stands for, which is only ever more than one for
+    /// the runs of a compressed file. Fails with `DimensionMismatch` if the bytes end first.
+    pub(crate) fn record(&mut self, header: &Header) -> Result<(Chunk, usize), ChunkError> {
+        let (alpha, checksum) = (header.has_alpha(), header.checksum_algorithm());
+        let record = self
+            .take(record_len(header))
+            .ok_or(ChunkError::DimensionMismatch)?;
+
+        match header.is_compressed() {
+            true => Ok((chunk::decode(&record[1..], alpha, checksum)?, record[0] as usize + 1)),
+            false => Ok((chunk::decode(record, alpha, checksum)?, 1)),
+        }
+    }
+
+    /// Reads `count` chunks stored as runs the way `header` says, see `chunk::decode_runs`.
+    pub(crate) fn runs(&mut self, count: usize, header: &Header) -> Result<Vec<Chunk>, ChunkError> {
+        let (alpha, checksum) = (header.has_alpha(), header.checksum_algorithm());
+        let (chunks, len) = chunk::decode_runs(self.rest(), count, alpha, checksum)?;
+        self.offset += len;
+
+        Ok(chunks)
+    }
+
+    /// Reads the next section, whatever its tag, checksummed the way `header` says.
+    pub(crate) fn section(&mut self, header: &Header) -> Result<Section, ChunkError> {
+        let checksum = header.checksum_algorithm();
+        let (section, len) = Section::parse(self.rest(), checksum)?;
+        self.offset += len;
+
+        Ok(section)
+    }
+}
+
+#[cfg(test)]
+mod cursor_tests {
+    use super::*;
+    use crate::file::CIEBIIFILE;
+
+    #[test]
+    fn records_in_order() {
+        let mut file = CIEBIIFILE::try_from_chunks(2, 1, vec![Chunk::new(1, 2, 3); 2]).unwrap();
+        file.set_metadata(Section::EXIF, vec![1, 2, 3]);
+        let bytes = file.as_bytes();
+
+        let mut cursor = Cursor::new(&bytes);
+        let header = cursor.header().unwrap();
+        assert_eq!(header.dimensions(), (2, 1));
+        assert_eq!(cursor.chunk_bytes(2, Chunk::LEN), &bytes[30..40]);
+        assert_eq!(cursor.section(&header).unwrap().tag(), Section::EXIF);
+        assert!(cursor.is_empty());
+    }
+
+    #[test]
+    fn too_few_chunks() {
+        let bytes = CIEBIIFILE::try_from_chunks(1, 1, vec![Chunk::new(1, 2, 3)])
+            .unwrap()
+            .as_bytes();
+
+        let mut cursor = Cursor::new(&bytes);
+        cursor.header().unwrap();
+
+        assert_eq!(cursor.chunk_bytes(usize::MAX, Chunk::LEN), &bytes[30..]);
+        assert!(cursor.is_empty());
+    }
+
+    #[test]
+    fn records() {
+        let chunks = vec![Chunk::new(1, 2, 3), Chunk::new(1, 2, 3), Chunk::new(4, 5, 6)];
+        let mut file = CIEBIIFILE::try_from_chunks(3, 1, chunks).unwrap();
+        let bytes = file.as_bytes();
+
+        let mut cursor = Cursor::new(&bytes[..]);
+        let header = cursor.header().unwrap();
+        assert_eq!(cursor.record(&header).unwrap(), (Chunk::new(1, 2, 3), 1));
+        assert_eq!(cursor.chunks(2, &header).unwrap()[1], Chunk::new(4, 5, 6));
+
+        file.set_compressed(true);
+        let bytes = file.as_bytes();
+
+        let mut cursor = Cursor::new(bytes);
+        let header = cursor.header().unwrap();
+        assert_eq!(cursor.record(&header).unwrap(), (Chunk::new(1, 2, 3), 2));
+        assert_eq!(cursor.record(&header).unwrap(), (Chunk::new(4, 5, 6), 1));
+
+        if let ChunkError::DimensionMismatch = cursor.record(&header).unwrap_err() {
+        } else {
+            panic!()
+        }
+    }
+
+    #[test]
+    fn short_header() {
+        if let ChunkError::InvalidLen = Cursor::new(&[67, 73, 69]).header().unwrap_err() {
+        } else {
+            panic!()
+        }
+    }
+}
@@ -0,0 +1,338 @@
+use std::ops::Range;
+
+use miette::SourceSpan;
+
+use super::{checksum::ChecksumAlgorithm, chunk::Chunk, error::ChunkError, header::Header};
+
+/// How many bytes each line of a hex snippet shows
+const BYTES_PER_LINE: usize = 16;
+
+/// How many lines of bytes are shown before and after the offending ones
+const CONTEXT_LINES: usize = 1;
+
+/// How many offending bytes are shown at most, long runs of garbage are cut short
+const MAX_LABELED_BYTES: usize = 4 * BYTES_PER_LINE;
+
+/// The width of the offset in front of each line of a hex snippet, with its padding
+const OFFSET_WIDTH: usize = 10;
+
+/// A decode error along with where in the file it happened, rendered by miette as a hex
+/// snippet with the offending bytes labeled.
+/// ```
+/// use ciebii_lib::{chunk::Chunk, diagnostic::diagnose, file::CIEBIIFILE};
+///
+/// let file = CIEBIIFILE::try_from_chunks(2, 1, vec![Chunk::new(1, 2, 3); 2]).unwrap();
+/// let mut bytes = file.as_bytes();
+/// bytes[35] ^= 1;
+///
+/// let err = CIEBIIFILE::try_from(&bytes[..]).unwrap_err();
+/// let diagnostic = diagnose(&bytes, err);
+/// assert_eq!(diagnostic.chunk(), Some(1));
+/// ```
+#[derive(thiserror::Error, miette::Diagnostic, Debug)]
+#[error("Failed to decode the ciebii file.")]
+#[diagnostic(code(ciebii::decode))]
+pub struct DecodeDiagnostic {
+    #[source]
+    error: ChunkError,
+
+    #[source_code]
+    snippet: String,
+
+    #[label("{label}")]
+    span: Option<SourceSpan>,
+
+    label: String,
+
+    #[help]
+    help: Option<String>,
+
+    offset: Option<usize>,
+    chunk: Option<usize>,
+    expected_checksum: Option<u16>,
+}
+
+impl DecodeDiagnostic {
+    /// Returns the error that stopped decoding
+    pub fn error(&self) -> &ChunkError {
+        &self.error
+    }
+
+    /// Returns the offset of the first offending byte, if they could be found
+    pub fn offset(&self) -> Option<usize> {
+        self.offset
+    }
+
+    /// Returns the index of the chunk whose checksum failed
+    pub fn chunk(&self) -> Option<usize> {
+        self.chunk
+    }
+
+    /// Returns the checksum the failing chunk should have had
+    pub fn expected_checksum(&self) -> Option<u16> {
+        self.expected_checksum
+    }
+}
+
+/// Works out where in `bytes` decoding failed with `error`. Errors that can't be pinned to
+/// any bytes, like those inside sections or the runs of compressed files, come without a
+/// snippet.
+pub fn diagnose(bytes: &[u8], error: ChunkError) -> DecodeDiagnostic {
+    let mut diagnostic = DecodeDiagnostic {
+        error,
+        snippet: String::new(),
+        span: None,
+        label: String::new(),
+        help: None,
+        offset: None,
+        chunk: None,
+        expected_checksum: None,
+    };
+
+    let header = bytes
+        .get(..Header::LEN)
+        .and_then(|header| Header::try_from(header.to_vec()).ok());
+    let (width, height) = header.map_or((0, 0), |header| header.dimensions());
+    let pixels = width.saturating_mul(height);
+    let alpha = header.is_some_and(|header| header.has_alpha());
+    let len = header.map_or(Chunk::LEN, |header| header.chunk_len());
+    let compressed = header.is_some_and(|header| header.is_compressed());
+    let checksum = header.map_or(ChecksumAlgorithm::default(), |header| {
+        header.checksum_algorithm()
+    });
+
+    // Where the pixel chunks end, or would end if the file weren't cut short
+    let pixel_bytes = bytes.len().saturating_sub(Header::LEN).min(pixels * len);
+
+    let (range, label) = match &diagnostic.error {
+        ChunkError::ChecksumFail if !compressed => {
+            let chunks = bytes.get(Header::LEN..).unwrap_or_default();
+            let Some((index, expected)) = find_bad_chunk(chunks, pixels, alpha, checksum) else {
+                return diagnostic;
+            };
+
+            let start = Header::LEN + index * len;
+            let stored = u16::from_be_bytes([bytes[start + len - 2], bytes[start + len - 1]]);
+
+            diagnostic.chunk = Some(index);
+            diagnostic.expected_checksum = Some(expected);
+            diagnostic.help =
+                Some("The file was modified or corrupted after it was written.".to_owned());
+
+            (
+                start..start + len,
+                format!(
+                    "chunk {index}, the pixel at {},{}, stores the checksum {stored:#06x} \
+                     instead of {expected:#06x}",
+                    index % width,
+                    index / width
+                ),
+            )
+        }
+        ChunkError::InvalidLen if bytes.len() < Header::LEN => (
+            0..bytes.len(),
+            format!("the header takes {} bytes, the file ends after {}", Header::LEN, bytes.len()),
+        ),
+        ChunkError::IllegalHeader => {
+            diagnostic.help = Some(format!(
+                "Ciebii files start with the magic bytes 'CIEBIIFILE' followed by the width \
+                 and height, {} bytes in total.",
+                Header::LEN
+            ));
+
+            (0..Header::LEN, "this isn't a valid ciebii header".to_owned())
+        }
+        ChunkError::InvalidLen if !compressed && !pixel_bytes.is_multiple_of(len) => {
+            let start = Header::LEN + pixel_bytes - pixel_bytes % len;
+
+            (start..bytes.len(), format!("this chunk is cut short, chunks take {len} bytes"))
+        }
+        ChunkError::DimensionMismatch if header.is_some() && !compressed => (
+            bytes.len()..bytes.len(),
+            format!(
+                "the file ends after {} of the {width}x{height} image's {pixels} chunks",
+                pixel_bytes / len
+            ),
+        ),
+        ChunkError::TrailingData(offset) => {
+            diagnostic.help =
+                Some("Only sections may follow the pixel data of a ciebii file.".to_owned());
+
+            (
+                *offset..bytes.len(),
+                format!("{} unexpected bytes start here", bytes.len() - offset),
+            )
+        }
+        _ => return diagnostic,
+    };
+
+    let (snippet, span) = hex_snippet(bytes, range.clone());
+    diagnostic.snippet = snippet;
+    diagnostic.span = Some(span);
+    diagnostic.label = label;
+    diagnostic.offset = Some(range.start);
+
+    diagnostic
+}
+
+/// Finds the first of `count` chunks whose checksum doesn't match its color, along with the
+/// checksum it should have had. The chunks carry an alpha byte if `alpha` is set and are
+/// checksummed with `checksum`.
+fn find_bad_chunk(
+    bytes: &[u8],
+    count: usize,
+    alpha: bool,
+    checksum: ChecksumAlgorithm,
+) -> Option<(usize, u16)> {
+    let len = if alpha { Chunk::RGBA_LEN } else { Chunk::LEN };
+
+    bytes
+        .chunks_exact(len)
+        .take(count)
+        .enumerate()
+        .find_map(|(index, chunk)| {
+            let expected = match checksum {
+                ChecksumAlgorithm::Ciebii => {
+                    let a = if alpha { chunk[3] } else { 255 };
+                    Chunk::with_alpha(chunk[0], chunk[1], chunk[2], a).checksum()
+                }
+                ChecksumAlgorithm::Crc32 => checksum.checksum(&chunk[..len - 2]),
+            };
+            let stored = u16::from_be_bytes([chunk[len - 2], chunk[len - 1]]);
+
+            (stored != expected).then_some((index, expected))
+        })
+}
+
+/// Renders the lines of `bytes` around `range` as a hex dump, one line of
+/// `BYTES_PER_LINE` bytes each with its offset in front. Returns the dump along with where
+/// `range` ended up in it. An empty range points just past the byte before it.
+fn hex_snippet(bytes: &[u8], range: Range<usize>) -> (String, SourceSpan) {
+    if bytes.is_empty() {
+        return (String::new(), (0, 0).into());
+    }
+
+    let end = range.end.min(range.start + MAX_LABELED_BYTES);
+    let last = bytes.len() - 1;
+
+    let first_line = (range.start.min(last) / BYTES_PER_LINE).saturating_sub(CONTEXT_LINES);
+    let last_line = (end.saturating_sub(1).min(last) / BYTES_PER_LINE + CONTEXT_LINES)
+        .min(last / BYTES_PER_LINE);
+
+    let line_len = OFFSET_WIDTH + BYTES_PER_LINE * 3;
+    let mut snippet = String::with_capacity((last_line - first_line + 1) * line_len);
+
+    for line in first_line..=last_line {
+        let start = line * BYTES_PER_LINE;
+        let hex: Vec<String> = bytes[start..(start + BYTES_PER_LINE).min(bytes.len())]
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect();
+
+        snippet.push_str(&format!("{start:08x}  {}\n", hex.join(" ")));
+    }
+
+    // Where the byte at `offset` starts in the snippet
+    let position = |offset: usize| {
+        (offset / BYTES_PER_LINE - first_line) * line_len
+            + OFFSET_WIDTH
+            + offset % BYTES_PER_LINE * 3
+    };
+
+    let span = if range.start >= end {
+        (position(range.start.saturating_sub(1).min(last)) + 2, 0)
+    } else {
+        let start = position(range.start);
+        (start, position(end - 1) + 2 - start)
+    };
+
+    (snippet, span.into())
+}
+
+#[cfg(test)]
+mod diagnostic_tests {
+    use super::*;
+    use crate::{checksum::checksum, file::CIEBIIFILE};
+
+    const CRC32: ChecksumAlgorithm = ChecksumAlgorithm::Crc32;
+
+    fn test_bytes() -> Vec<u8> {
+        let chunks = (0..6).map(|i| Chunk::new(i, 0, 0)).collect();
+
+        CIEBIIFILE::try_from_chunks(3, 2, chunks).unwrap().as_bytes()
+    }
+
+    fn diagnose_bytes(bytes: &[u8]) -> DecodeDiagnostic {
+        diagnose(bytes, CIEBIIFILE::try_from(bytes).unwrap_err())
+    }
+
+    #[test]
+    fn checksum_fail() {
+        let mut bytes = test_bytes();
+        bytes[Header::LEN + 4 * Chunk::LEN] ^= 1;
+
+        let diagnostic = diagnose_bytes(&bytes);
+
+        assert_eq!(diagnostic.chunk(), Some(4));
+        assert_eq!(diagnostic.offset(), Some(Header::LEN + 4 * Chunk::LEN));
+        assert_eq!(diagnostic.expected_checksum(), Some(CRC32.checksum(&[5, 0, 0])));
+        assert!(diagnostic.label.contains("the pixel at 1,1"));
+    }
+
+    #[test]
+    fn checksum_fail_legacy() {
+        let chunks = (0..6).map(|i| Chunk::new(i, 0, 0)).collect();
+        let mut file = CIEBIIFILE::try_from_chunks(3, 2, chunks).unwrap();
+        file.set_checksum_algorithm(ChecksumAlgorithm::Ciebii);
+        let mut bytes = file.as_bytes();
+        bytes[Header::LEN + 2 * Chunk::LEN] ^= 1;
+
+        let diagnostic = diagnose_bytes(&bytes);
+
+        assert_eq!(diagnostic.chunk(), Some(2));
+        assert_eq!(diagnostic.expected_checksum(), Some(checksum(&[3, 0, 0])));
+    }
+
+    #[test]
+    fn checksum_fail_with_alpha() {
+        let chunks = (0..6).map(|i| Chunk::with_alpha(i, 0, 0, 128)).collect();
+        let mut bytes = CIEBIIFILE::try_from_chunks(3, 2, chunks).unwrap().as_bytes();
+        bytes[Header::LEN + 4 * Chunk::RGBA_LEN + 3] ^= 1;
+
+        let diagnostic = diagnose_bytes(&bytes);
+
+        assert_eq!(diagnostic.chunk(), Some(4));
+        assert_eq!(diagnostic.offset(), Some(Header::LEN + 4 * Chunk::RGBA_LEN));
+        assert_eq!(diagnostic.expected_checksum(), Some(CRC32.checksum(&[4, 0, 0, 129])));
+    }
+
+    #[test]
+    fn trailing_data() {
+        let mut bytes = test_bytes();
+        let end = bytes.len();
+        bytes.extend([0, 0, 0, 5, 1, 2]);
+
+        let diagnostic = diagnose_bytes(&bytes);
+
+        assert_eq!(diagnostic.offset(), Some(end));
+        assert_eq!(diagnostic.chunk(), None);
+    }
+
+    #[test]
+    fn truncated() {
+        let bytes = test_bytes();
+        let diagnostic = diagnose_bytes(&bytes[..bytes.len() - Chunk::LEN]);
+
+        assert_eq!(diagnostic.offset(), Some(bytes.len() - Chunk::LEN));
+        assert!(diagnostic.label.contains("5 of the 3x2 image's 6 chunks"));
+    }
+
+    #[test]
+    fn snippet_span() {
+        let bytes: Vec<u8> = (0..64).collect();
+        let (snippet, span) = hex_snippet(&bytes, 33..35);
+
+        assert_eq!(snippet.lines().count(), 3);
+        assert_eq!(&snippet[span.offset()..span.offset() + span.len()], "21 22");
+    }
+}
@@ -0,0 +1,138 @@
+use super::{chunk::Chunk, file::CIEBIIFILE, rgb::RGB};
+
+/// Fills a rectangle of `file` with a color. Parts outside of the image are clipped.
+/// ```
+/// use ciebii_lib::{draw::fill_rect, file::CIEBIIFILE, rgb::RGB};
+/// let mut file = CIEBIIFILE::new(0, 0);
+/// fill_rect(&mut file, 0, 0, 10, 10, RGB::new(0xFF, 0, 0));
+/// ```
+pub fn fill_rect(file: &mut CIEBIIFILE, x: usize, y: usize, width: usize, height: usize, color: RGB) {
+    let (image_width, image_height) = file.dimensions();
+
+    // Clip the rectangle to the image
+    let x_end = x.saturating_add(width).min(image_width);
+    let y_end = y.saturating_add(height).min(image_height);
+
+    let (r, g, b) = color.color();
+    let chunk = Chunk::new(r, g, b);
+
+    file.modify_chunks(|chunks| {
+        for row in y..y_end {
+            for column in x..x_end {
+                if let Some(target) = chunks.get_mut(row * image_width + column) {
+                    *target = chunk;
+                }
+            }
+        }
+    });
+}
+
+/// Sets every pixel of `file` for which `mask` returns true to a color, in one pass.
+/// Coordinates outside of the image are never passed to `mask`.
+pub fn fill_where(file: &mut CIEBIIFILE, color: RGB, mask: impl Fn(usize, usize) -> bool) {
+    let (width, _) = file.dimensions();
+
+    let (r, g, b) = color.color();
+    let chunk = Chunk::new(r, g, b);
+
+    file.modify_chunks(|chunks| {
+        for (i, target) in chunks.iter_mut().enumerate() {
+            if mask(i % width, i / width) {
+                *target = chunk;
+            }
+        }
+    });
+}
+
+/// Copies the first frame of `overlay` onto `file` with its top left corner at `x`, `y`.
+/// Parts outside of `file` are clipped.
+/// ```
+/// use ciebii_lib::{chunk::Chunk, draw::stamp, file::CIEBIIFILE};
+/// let mut file = CIEBIIFILE::try_from_chunks(2, 1, vec![Chunk::new(0, 0, 0); 2]).unwrap();
+/// let overlay = CIEBIIFILE::try_from_chunks(1, 1, vec![Chunk::new(255, 0, 0)]).unwrap();
+///
+/// stamp(&mut file, &overlay, 1, 0);
+/// assert_eq!(file.get_at_index(1), Some(&Chunk::new(255, 0, 0)));
+/// ```
+pub fn stamp(file: &mut CIEBIIFILE, overlay: &CIEBIIFILE, x: usize, y: usize) {
+    let (width, height) = file.dimensions();
+    let (overlay_width, overlay_height) = overlay.dimensions();
+
+    // Clip the overlay to the image
+    let columns = overlay_width.min(width.saturating_sub(x));
+    let rows = overlay_height.min(height.saturating_sub(y));
+
+    if columns == 0 || rows == 0 {
+        return;
+    }
+
+    file.modify_chunks(|chunks| {
+        for row in 0..rows {
+            let target = (y + row) * width + x;
+            let source = row * overlay_width;
+
+            chunks[target..target + columns]
+                .copy_from_slice(&overlay.chunks()[source..source + columns]);
+        }
+    });
+}
+
+#[cfg(test)]
+mod draw_tests {
+    use super::*;
+    use crate::checksum::ChecksumAlgorithm;
+
+    fn blank(width: usize, height: usize) -> CIEBIIFILE {
+        CIEBIIFILE::try_from_chunks(width, height, vec![Chunk::new(0, 0, 0); width * height]).unwrap()
+    }
+
+    #[test]
+    fn fill_rect_inside() {
+        let mut file = blank(3, 3);
+        fill_rect(&mut file, 1, 1, 1, 2, RGB::new(255, 0, 0));
+
+        let red: Vec<usize> = (0..9)
+            .filter(|i| file.get_at_index(*i).unwrap().rgb() == RGB::new(255, 0, 0))
+            .collect();
+        assert_eq!(red, [4, 7]);
+    }
+
+    #[test]
+    fn fill_rect_clips() {
+        let mut file = blank(2, 2);
+        fill_rect(&mut file, 1, 1, 100, 100, RGB::new(255, 0, 0));
+
+        assert_eq!(file.get_at_index(3).unwrap().rgb(), RGB::new(255, 0, 0));
+        assert_eq!(file.chunks().len(), 4);
+
+        // Bytes have to follow the chunks
+        let [high, low] = ChecksumAlgorithm::Crc32.checksum(&[255, 0, 0]).to_be_bytes();
+        assert_eq!(file.as_bytes()[30 + 15..30 + 20], [255, 0, 0, high, low]);
+    }
+
+    #[test]
+    fn fill_where_mask() {
+        let mut file = blank(2, 2);
+        fill_where(&mut file, RGB::new(255, 0, 0), |x, y| x == y);
+
+        assert_eq!(file.get_at_index(0).unwrap().rgb(), RGB::new(255, 0, 0));
+        assert_eq!(file.get_at_index(1).unwrap().rgb(), RGB::new(0, 0, 0));
+        assert_eq!(file.get_at_index(3).unwrap().rgb(), RGB::new(255, 0, 0));
+    }
+
+    #[test]
+    fn stamp_clips() {
+        let mut file = blank(3, 2);
+        let overlay = CIEBIIFILE::try_from_chunks(2, 2, vec![Chunk::new(255, 0, 0); 4]).unwrap();
+        stamp(&mut file, &overlay, 2, 1);
+
+        let red: Vec<usize> = (0..6)
+            .filter(|i| file.get_at_index(*i).unwrap().rgb() == RGB::new(255, 0, 0))
+            .collect();
+        assert_eq!(red, [5]);
+
+        // Completely outside, nothing happens
+        stamp(&mut file, &overlay, 10, 0);
+        assert_eq!(file.chunks().len(), 6);
+    }
+}
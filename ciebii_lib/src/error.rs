@@ -1,4 +1,7 @@
-use std::array::TryFromSliceError;
+use std::{array::TryFromSliceError, path::PathBuf};
+
+#[cfg(feature = "diagnostics")]
+use crate::diagnostic::DecodeDiagnostic;
 
 #[derive(thiserror::Error, Debug)]
 pub enum ChunkError {
@@ -15,6 +18,90 @@ pub enum ChunkError {
     #[error("The dimensions do not correspond to the amount of chunks in the file.")]
     DimensionMismatch,
 
+    /// Holds the offset the unexpected bytes start at, so tools can look at them
+    #[error("Unexpected data after the end of the file, starting at byte {0}.")]
+    TrailingData(usize),
+
+    #[error("'{0}' is not a valid color, expected a hex code like '#FF8800'.")]
+    InvalidColor(String),
+
+    #[error("{0} bytes don't fit into the image, it can hold {1} bytes.")]
+    PayloadTooLarge(usize, usize),
+
+    #[error("The image could not be read: {0}.")]
+    InvalidImage(String),
+
     #[error("Failed to parse bytes")]
     ByteParseFail(#[from] TryFromSliceError),
+
+    #[error("Failed to read the data: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+/// An error from reading or writing a ciebii file on disk, telling a file that couldn't be
+/// accessed apart from one that holds corrupt data.
+#[derive(thiserror::Error, Debug)]
+#[non_exhaustive]
+pub enum CiebiiError {
+    /// The file couldn't be opened, read or written. The source tells why, like
+    /// `std::io::ErrorKind::NotFound`.
+    #[error("Failed to access '{}'.", path.display())]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    /// The file was read, but isn't a valid ciebii file
+    #[error("'{}' is not a valid ciebii file.", path.display())]
+    Corrupt {
+        path: PathBuf,
+        #[source]
+        source: ChunkError,
+    },
+
+    /// Like `Corrupt`, along with where in the file decoding failed
+    #[cfg(feature = "diagnostics")]
+    #[error("'{}' is not a valid ciebii file.", path.display())]
+    Diagnostic {
+        path: PathBuf,
+        #[source]
+        source: Box<DecodeDiagnostic>,
+    },
+}
+
+impl CiebiiError {
+    /// Returns what was wrong with the data of a corrupt file
+    pub fn chunk_error(&self) -> Option<&ChunkError> {
+        match self {
+            CiebiiError::Io { .. } => None,
+            CiebiiError::Corrupt { source, .. } => Some(source),
+            #[cfg(feature = "diagnostics")]
+            CiebiiError::Diagnostic { source, .. } => Some(source.error()),
+        }
+    }
+
+    /// Returns where in the file decoding failed, for a corrupt file
+    #[cfg(feature = "diagnostics")]
+    pub fn diagnostic(&self) -> Option<&DecodeDiagnostic> {
+        match self {
+            CiebiiError::Diagnostic { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+
+    /// Attributes `err` to the file at `path`, where an IO error is about accessing it and
+    /// anything else about its data.
+    pub(crate) fn at(path: impl Into<PathBuf>, err: ChunkError) -> Self {
+        match err {
+            ChunkError::Io(source) => CiebiiError::Io {
+                path: path.into(),
+                source,
+            },
+            source => CiebiiError::Corrupt {
+                path: path.into(),
+                source,
+            },
+        }
+    }
 }
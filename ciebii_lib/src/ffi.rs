@@ -0,0 +1,328 @@
+use std::{
+    ffi::{c_char, CStr},
+    panic::{self, AssertUnwindSafe},
+    path::Path,
+    slice,
+};
+
+use super::{
+    error::{ChunkError, CiebiiError},
+    file::CIEBIIFILE,
+    io::read_file,
+};
+
+// C bindings for decoding ciebii files. `include/ciebii.h` declares them and is generated
+// from this module with `cbindgen --config cbindgen.toml --output include/ciebii.h`.
+
+/// A decoded ciebii file, only ever handed out behind a pointer
+pub struct CiebiiImage {
+    file: CIEBIIFILE,
+}
+
+/// What every function returns, `CIEBII_STATUS_OK` when it succeeded
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CiebiiStatus {
+    Ok = 0,
+
+    /// A pointer was null, or the path wasn't valid UTF-8
+    InvalidArgument = 1,
+
+    /// The file couldn't be opened or read
+    Io = 2,
+
+    /// The checksum of a chunk didn't match its color
+    ChecksumFail = 3,
+
+    /// The data isn't a valid ciebii file
+    Corrupt = 4,
+
+    /// The image has no frame at the given index
+    NoSuchFrame = 5,
+
+    /// The buffer can't hold every pixel of a frame
+    BufferTooSmall = 6,
+}
+
+impl From<&ChunkError> for CiebiiStatus {
+    fn from(err: &ChunkError) -> Self {
+        match err {
+            ChunkError::ChecksumFail => CiebiiStatus::ChecksumFail,
+            ChunkError::Io(_) => CiebiiStatus::Io,
+            _ => CiebiiStatus::Corrupt,
+        }
+    }
+}
+
+impl From<&CiebiiError> for CiebiiStatus {
+    fn from(err: &CiebiiError) -> Self {
+        err.chunk_error().map_or(CiebiiStatus::Io, CiebiiStatus::from)
+    }
+}
+
+/// Runs the body of an exported function. A panic must not unwind into C, so it's reported as
+/// `CIEBII_STATUS_CORRUPT`, the input being the only thing that can cause one.
+fn guard(body: impl FnOnce() -> CiebiiStatus) -> CiebiiStatus {
+    panic::catch_unwind(AssertUnwindSafe(body)).unwrap_or(CiebiiStatus::Corrupt)
+}
+
+/// Hands `file` to C through `out`
+///
+/// # Safety
+/// `out` must be valid for writes.
+unsafe fn hand_out(file: CIEBIIFILE, out: *mut *mut CiebiiImage) -> CiebiiStatus {
+    *out = Box::into_raw(Box::new(CiebiiImage { file }));
+
+    CiebiiStatus::Ok
+}
+
+/// Reads and decodes the ciebii file at `path`. On success `*out` points to the image, which
+/// is freed with `ciebii_free`, and is left alone otherwise.
+///
+/// # Safety
+/// `path` must be null or a NUL-terminated string, and `out` null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn ciebii_open(
+    path: *const c_char,
+    out: *mut *mut CiebiiImage,
+) -> CiebiiStatus {
+    guard(|| {
+        if path.is_null() || out.is_null() {
+            return CiebiiStatus::InvalidArgument;
+        }
+        let Ok(path) = CStr::from_ptr(path).to_str() else {
+            return CiebiiStatus::InvalidArgument;
+        };
+
+        match read_file(Path::new(path)) {
+            Ok(file) => hand_out(file, out),
+            Err(err) => CiebiiStatus::from(&err),
+        }
+    })
+}
+
+/// Decodes a ciebii file from the `len` bytes at `bytes`, like `ciebii_open`.
+///
+/// # Safety
+/// `bytes` must be null or valid for reads of `len` bytes, and `out` null or valid for
+/// writes.
+#[no_mangle]
+pub unsafe extern "C" fn ciebii_decode(
+    bytes: *const u8,
+    len: usize,
+    out: *mut *mut CiebiiImage,
+) -> CiebiiStatus {
+    guard(|| {
+        if bytes.is_null() || out.is_null() {
+            return CiebiiStatus::InvalidArgument;
+        }
+
+        match CIEBIIFILE::try_from(slice::from_raw_parts(bytes, len)) {
+            Ok(file) => hand_out(file, out),
+            Err(err) => CiebiiStatus::from(&err),
+        }
+    })
+}
+
+/// Writes the width and height of `image` in pixels to `width` and `height`.
+///
+/// # Safety
+/// `image` must be null or come from `ciebii_open` or `ciebii_decode`, and `width` and
+/// `height` null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn ciebii_dimensions(
+    image: *const CiebiiImage,
+    width: *mut usize,
+    height: *mut usize,
+) -> CiebiiStatus {
+    guard(|| {
+        if image.is_null() || width.is_null() || height.is_null() {
+            return CiebiiStatus::InvalidArgument;
+        }
+
+        (*width, *height) = (*image).file.dimensions();
+
+        CiebiiStatus::Ok
+    })
+}
+
+/// Returns the number of frames of `image`, 1 for a still image and 0 for null.
+///
+/// # Safety
+/// `image` must be null or come from `ciebii_open` or `ciebii_decode`.
+#[no_mangle]
+pub unsafe extern "C" fn ciebii_frame_count(image: *const CiebiiImage) -> usize {
+    panic::catch_unwind(|| match image.is_null() {
+        true => 0,
+        false => (*image).file.frame_count(),
+    })
+    .unwrap_or(0)
+}
+
+/// Copies the pixels of frame `frame` of `image` into `buffer` row by row, four bytes each
+/// in RGBA order. `len` is the size of the buffer, which needs to hold `width * height * 4`
+/// bytes.
+///
+/// # Safety
+/// `image` must be null or come from `ciebii_open` or `ciebii_decode`, and `buffer` null or
+/// valid for writes of `len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn ciebii_copy_pixels(
+    image: *const CiebiiImage,
+    frame: usize,
+    buffer: *mut u8,
+    len: usize,
+) -> CiebiiStatus {
+    guard(|| {
+        if image.is_null() || buffer.is_null() {
+            return CiebiiStatus::InvalidArgument;
+        }
+        let Some(chunks) = (*image).file.frame(frame) else {
+            return CiebiiStatus::NoSuchFrame;
+        };
+        if len < chunks.len() * 4 {
+            return CiebiiStatus::BufferTooSmall;
+        }
+
+        let buffer = slice::from_raw_parts_mut(buffer, len);
+        for (target, chunk) in buffer.chunks_exact_mut(4).zip(chunks) {
+            let (r, g, b, a) = chunk.rgba().color();
+            target.copy_from_slice(&[r, g, b, a]);
+        }
+
+        CiebiiStatus::Ok
+    })
+}
+
+/// Frees an image. Null is ignored.
+///
+/// # Safety
+/// `image` must be null or come from `ciebii_open` or `ciebii_decode`, and not be used
+/// again afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn ciebii_free(image: *mut CiebiiImage) {
+    let _ = panic::catch_unwind(|| {
+        if !image.is_null() {
+            drop(Box::from_raw(image));
+        }
+    });
+}
+
+#[cfg(test)]
+mod ffi_tests {
+    use super::*;
+    use crate::{
+        checksum::ChecksumAlgorithm,
+        chunk::{self, Chunk},
+        header::Header,
+    };
+    use std::{ffi::CString, ptr};
+    use tempdir::TempDir;
+
+    fn test_file() -> CIEBIIFILE {
+        let chunks = vec![
+            Chunk::new(255, 0, 0),
+            Chunk::with_alpha(0, 255, 0, 128),
+            Chunk::new(0, 0, 255),
+            Chunk::new(1, 2, 3),
+        ];
+
+        CIEBIIFILE::try_from_chunks(2, 2, chunks).unwrap()
+    }
+
+    #[test]
+    fn decode_and_copy() {
+        let bytes = test_file().as_bytes();
+        let mut image = ptr::null_mut();
+
+        unsafe {
+            assert_eq!(ciebii_decode(bytes.as_ptr(), bytes.len(), &mut image), CiebiiStatus::Ok);
+
+            let (mut width, mut height) = (0, 0);
+            assert_eq!(ciebii_dimensions(image, &mut width, &mut height), CiebiiStatus::Ok);
+            assert_eq!((width, height), (2, 2));
+            assert_eq!(ciebii_frame_count(image), 1);
+
+            let mut pixels = [0; 16];
+            assert_eq!(
+                ciebii_copy_pixels(image, 0, pixels.as_mut_ptr(), pixels.len()),
+                CiebiiStatus::Ok
+            );
+            assert_eq!(pixels[..8], [255, 0, 0, 255, 0, 255, 0, 128]);
+
+            assert_eq!(
+                ciebii_copy_pixels(image, 0, pixels.as_mut_ptr(), 15),
+                CiebiiStatus::BufferTooSmall
+            );
+            assert_eq!(
+                ciebii_copy_pixels(image, 1, pixels.as_mut_ptr(), pixels.len()),
+                CiebiiStatus::NoSuchFrame
+            );
+
+            ciebii_free(image);
+        }
+    }
+
+    #[test]
+    fn open_file() {
+        let dir = TempDir::new("tests").unwrap();
+        let path = dir.path().join("test.cib");
+        std::fs::write(&path, test_file().as_bytes()).unwrap();
+
+        let path = CString::new(path.to_str().unwrap()).unwrap();
+        let missing = CString::new(dir.path().join("missing.cib").to_str().unwrap()).unwrap();
+        let mut image = ptr::null_mut();
+
+        unsafe {
+            assert_eq!(ciebii_open(missing.as_ptr(), &mut image), CiebiiStatus::Io);
+            assert!(image.is_null());
+
+            assert_eq!(ciebii_open(path.as_ptr(), &mut image), CiebiiStatus::Ok);
+            assert_eq!(ciebii_frame_count(image), 1);
+            ciebii_free(image);
+        }
+    }
+
+    #[test]
+    fn bad_input() {
+        let mut bytes = test_file().as_bytes();
+        bytes[32] ^= 1;
+        let mut image = ptr::null_mut();
+
+        unsafe {
+            assert_eq!(
+                ciebii_decode(bytes.as_ptr(), bytes.len(), &mut image),
+                CiebiiStatus::ChecksumFail
+            );
+            assert_eq!(ciebii_decode(bytes.as_ptr(), 10, &mut image), CiebiiStatus::Corrupt);
+            assert_eq!(
+                ciebii_decode(ptr::null(), 0, &mut image),
+                CiebiiStatus::InvalidArgument
+            );
+            assert!(image.is_null());
+
+            assert_eq!(ciebii_frame_count(ptr::null()), 0);
+            ciebii_free(ptr::null_mut());
+        }
+    }
+
+    #[test]
+    fn hostile_compressed_header() {
+        let mut bytes = Header::new(1 << 31, 1 << 31).compressed(true).as_bytes();
+        chunk::encode_runs(&[Chunk::new(1, 2, 3)], false, ChecksumAlgorithm::Crc32, &mut bytes);
+        let mut image = ptr::null_mut();
+
+        unsafe {
+            assert_eq!(
+                ciebii_decode(bytes.as_ptr(), bytes.len(), &mut image),
+                CiebiiStatus::Corrupt
+            );
+            assert!(image.is_null());
+        }
+    }
+
+    #[test]
+    fn panics_stay_in_rust() {
+        assert_eq!(guard(|| panic!("hostile input")), CiebiiStatus::Corrupt);
+    }
+}
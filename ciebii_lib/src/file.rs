@@ -1,4 +1,22 @@
-use super::{chunk::Chunk, error::ChunkError, header::Header};
+use super::{
+    anim::{self, Frame},
+    checksum::ChecksumAlgorithm,
+    chunk::{self, Chunk},
+    crop,
+    cursor::Cursor,
+    error::ChunkError,
+    header::Header,
+    resize::{self, Filter},
+    section::Section,
+    thumbnail,
+    writer::{Codec, DigestWriter, WriteOptions},
+};
+
+use sha2::{Digest, Sha256};
+use std::io::{self, Write};
+
+/// How many chunks `content_hash` serializes at once
+const WRITE_BUFFER_CHUNKS: usize = 1024;
 
 #[allow(clippy::upper_case_acronyms)]
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -11,6 +29,15 @@ pub struct CIEBIIFILE {
     chunks: Vec<Chunk>,
     bytes: Vec<u8>,
     header: Header,
+
+    // How long the first frame is shown in animated files, in milliseconds
+    delay: u32,
+
+    // Every frame after the first one
+    frames: Vec<Frame>,
+
+    // EXIF and XMP sections, in the order they were added
+    metadata: Vec<Section>,
 }
 
 #[allow(dead_code)]
@@ -30,10 +57,14 @@ impl CIEBIIFILE {
             chunks: Vec::new(),
             bytes: Vec::new(),
             header,
+            delay: 0,
+            frames: Vec::new(),
+            metadata: Vec::new(),
         }
     }
 
-    /// Attemps to construct a CIEBIIFILE given a stream of chunks along with some dimensions
+    /// Attemps to construct a CIEBIIFILE given a stream of chunks along with some dimensions.
+    /// The file gets an alpha channel if any of the chunks isn't fully opaque.
     pub fn try_from_chunks(x: usize, y: usize, chunks: Vec<Chunk>) -> Result<Self, ChunkError> {
 
         // See if the dimensions correspond the amount of given chunks
@@ -41,27 +72,145 @@ impl CIEBIIFILE {
             return Err(ChunkError::DimensionMismatch);
         }
 
-        let header = Header::new(x, y);
+        let alpha = chunks.iter().any(|chunk| !chunk.is_opaque());
+        let header = Header::with_alpha(x, y, alpha);
 
         // The actual payload of the chunks.
-        let bytes: Vec<u8> = chunks.iter().flat_map(|chunk| chunk.as_bytes()).collect();
+        let mut bytes = Vec::new();
+        chunk::encode_into(&chunks, alpha, header.checksum_algorithm(), &mut bytes);
 
         Ok(Self {
             chunks,
             bytes,
             header,
+            delay: 0,
+            frames: Vec::new(),
+            metadata: Vec::new(),
         })
     }
 
+    /// Returns the version of the file format this file uses, as stored in its header.
+    /// Every file currently uses the original layout, version 0.
+    pub fn version(&self) -> u8 {
+        self.header.version()
+    }
+
     /// Returns the dimensions of the file
     pub fn dimensions(&self) -> (usize, usize) {
         self.header.dimensions()
     }
 
+    /// Returns whether the chunks of this file are stored with their alpha. Files get an alpha
+    /// channel as soon as any of their chunks isn't fully opaque, and keep it from then on.
+    pub fn has_alpha(&self) -> bool {
+        self.header.has_alpha()
+    }
+
+    /// Returns the length of every chunk of this file in bytes, as it's stored
+    pub fn chunk_len(&self) -> usize {
+        self.header.chunk_len()
+    }
+
+    /// Returns whether the chunks of this file are stored as runs of equal chunks
+    pub fn is_compressed(&self) -> bool {
+        self.header.is_compressed()
+    }
+
+    /// Sets whether the chunks of this file are stored as runs of equal chunks, each run taking
+    /// a byte for its length and a single chunk. Images with large areas of a single color get
+    /// a lot smaller, noisy ones a little larger.
+    /// ```
+    /// use ciebii_lib::{chunk::Chunk, file::CIEBIIFILE};
+    /// let mut file = CIEBIIFILE::try_from_chunks(8, 8, vec![Chunk::new(255, 0, 0); 64]).unwrap();
+    /// file.set_compressed(true);
+    ///
+    /// assert_eq!(file.as_bytes().len(), 30 + 6);
+    /// assert_eq!(CIEBIIFILE::try_from(file.as_bytes()).unwrap(), file);
+    /// ```
+    pub fn set_compressed(&mut self, compressed: bool) {
+        self.header = self.header.compressed(compressed);
+    }
+
+    /// Returns how the header, chunks and sections of this file are checksummed
+    pub fn checksum_algorithm(&self) -> ChecksumAlgorithm {
+        self.header.checksum_algorithm()
+    }
+
+    /// Sets how the header, chunks and sections of this file are checksummed. New files use
+    /// CRC-32, files read from disk keep whatever they were written with.
+    /// ```
+    /// use ciebii_lib::{checksum::ChecksumAlgorithm, chunk::Chunk, file::CIEBIIFILE};
+    /// let mut file = CIEBIIFILE::try_from_chunks(1, 1, vec![Chunk::new(255, 0, 0)]).unwrap();
+    /// file.set_checksum_algorithm(ChecksumAlgorithm::Ciebii);
+    ///
+    /// assert_eq!(file.as_bytes()[30..], Chunk::new(255, 0, 0).as_bytes());
+    /// assert_eq!(CIEBIIFILE::try_from(file.as_bytes()).unwrap(), file);
+    /// ```
+    pub fn set_checksum_algorithm(&mut self, algorithm: ChecksumAlgorithm) {
+        if algorithm == self.checksum_algorithm() {
+            return;
+        }
+
+        self.header = self.header.checksummed(algorithm);
+
+        self.bytes.clear();
+        chunk::encode_into(&self.chunks, self.has_alpha(), algorithm, &mut self.bytes);
+    }
+
+    /// Returns the checksum `chunk` is stored with in this file, which is the one it carries
+    /// unless the file is checksummed with CRC-32.
+    /// ```
+    /// use ciebii_lib::{checksum::ChecksumAlgorithm, chunk::Chunk, file::CIEBIIFILE};
+    /// let chunk = Chunk::new(255, 0, 0);
+    /// let mut file = CIEBIIFILE::try_from_chunks(1, 1, vec![chunk]).unwrap();
+    /// assert_eq!(file.stored_checksum(&chunk), ChecksumAlgorithm::Crc32.checksum(&[255, 0, 0]));
+    ///
+    /// file.set_checksum_algorithm(ChecksumAlgorithm::Ciebii);
+    /// assert_eq!(file.stored_checksum(&chunk), chunk.checksum());
+    /// ```
+    pub fn stored_checksum(&self, chunk: &Chunk) -> u16 {
+        let alpha = self.has_alpha();
+        let mut bytes = [0; Chunk::RGBA_LEN];
+        let bytes = &mut bytes[..chunk::len(alpha)];
+        chunk::encode_one(chunk, alpha, self.checksum_algorithm(), bytes);
+
+        u16::from_be_bytes([bytes[bytes.len() - 2], bytes[bytes.len() - 1]])
+    }
+
+    /// Returns the header of a file like this one with an alpha channel
+    fn with_alpha_header(&self) -> Header {
+        let (x, y) = self.dimensions();
+
+        Header::with_alpha(x, y, true)
+            .compressed(self.is_compressed())
+            .checksummed(self.checksum_algorithm())
+    }
+
+    /// Gives the file an alpha channel if any of `chunks` needs one, encoding the bytes of the
+    /// chunks so far again with their alpha.
+    fn fit_alpha(&mut self, chunks: &[Chunk]) {
+        if self.has_alpha() || chunks.iter().all(Chunk::is_opaque) {
+            return;
+        }
+
+        self.header = self.with_alpha_header();
+
+        self.bytes.clear();
+        chunk::encode_into(&self.chunks, true, self.checksum_algorithm(), &mut self.bytes);
+    }
+
     /// Pushes a chunk and its bytes
     pub fn push_chunk(&mut self, chunk: Chunk) {
+        self.fit_alpha(&[chunk]);
+
+        // Make room for the rest of the image at once, rather than growing a chunk at a time
+        let (x, y) = self.dimensions();
+        let remaining = (x * y).saturating_sub(self.chunks.len()).max(1);
+        self.chunks.reserve(remaining);
+        self.bytes.reserve(remaining * self.chunk_len());
+
         self.chunks.push(chunk);
-        self.bytes.append(&mut chunk.as_bytes());
+        chunk::encode_into(&[chunk], self.has_alpha(), self.checksum_algorithm(), &mut self.bytes);
     }
 
     /// Returns the chunks in a vec
@@ -69,15 +218,290 @@ impl CIEBIIFILE {
         &self.chunks
     }
 
+    /// Returns the amount of frames in this file. Still images have a single frame.
+    pub fn frame_count(&self) -> usize {
+        self.frames.len() + 1
+    }
+
+    /// Returns whether this file holds more than one frame
+    pub fn is_animated(&self) -> bool {
+        !self.frames.is_empty()
+    }
+
+    /// Returns the chunks of the frame at a given index. Frame 0 is the same as `chunks()`.
+    pub fn frame(&self, index: usize) -> Option<&Vec<Chunk>> {
+        match index {
+            0 => Some(&self.chunks),
+            _ => self.frames.get(index - 1).map(|frame| frame.chunks()),
+        }
+    }
+
+    /// Returns how long the frame at a given index is shown, in milliseconds
+    pub fn frame_delay(&self, index: usize) -> Option<u32> {
+        match index {
+            0 => Some(self.delay),
+            _ => self.frames.get(index - 1).map(|frame| frame.delay()),
+        }
+    }
+
+    /// Sets how long the frame at a given index is shown, in milliseconds
+    pub fn set_frame_delay(&mut self, index: usize, delay: u32) -> Result<(), ChunkError> {
+        match index {
+            0 => self.delay = delay,
+            _ => self
+                .frames
+                .get_mut(index - 1)
+                .ok_or(ChunkError::NonExistentChunk)?
+                .set_delay(delay),
+        }
+
+        Ok(())
+    }
+
+    /// Appends a frame shown for `delay` milliseconds, turning this file into an animation
+    pub fn push_frame(&mut self, chunks: Vec<Chunk>, delay: u32) -> Result<(), ChunkError> {
+
+        // Every frame has to cover the whole image
+        let (x, y) = self.dimensions();
+        if x * y != chunks.len() {
+            return Err(ChunkError::DimensionMismatch);
+        }
+
+        self.fit_alpha(&chunks);
+        self.frames.push(Frame::new(chunks, delay));
+
+        Ok(())
+    }
+
+    /// Returns the payload of the metadata section with the given tag, like `Section::EXIF`
+    pub fn metadata(&self, tag: [u8; 4]) -> Option<&[u8]> {
+        self.metadata
+            .iter()
+            .find(|section| section.tag() == tag)
+            .map(|section| section.payload())
+    }
+
+    /// Stores a metadata section, replacing any previous one with the same tag
+    pub fn set_metadata(&mut self, tag: [u8; 4], payload: Vec<u8>) {
+        self.metadata.retain(|section| section.tag() != tag);
+        self.metadata.push(Section::new(tag, payload));
+    }
+
+    /// Returns the embedded thumbnail, if this file has one
+    pub fn thumbnail(&self) -> Result<Option<CIEBIIFILE>, ChunkError> {
+        self.metadata(Section::THUMBNAIL)
+            .map(|bytes| CIEBIIFILE::try_from(bytes.to_vec()))
+            .transpose()
+    }
+
+    /// Embeds `thumbnail`, replacing any previous one
+    pub fn set_thumbnail(&mut self, thumbnail: &CIEBIIFILE) {
+        self.set_metadata(Section::THUMBNAIL, thumbnail.as_bytes());
+    }
+
+    /// Returns the sections stored after the pixel data
+    pub fn sections(&self) -> Vec<Section> {
+        let mut sections = Vec::new();
+
+        if self.is_animated() {
+            sections.push(Section::new(
+                Section::ANIMATION,
+                anim::encode(self.delay, &self.frames, &self.header),
+            ));
+        }
+
+        sections.extend(self.metadata.iter().cloned());
+
+        sections
+    }
+
     /// Turns this file into a raw byte format.
+    ///
+    /// # Panics
+    /// If a section holds 4 GiB or more, which the format can't store. `write_into` reports that
+    /// as an error instead.
     pub fn as_bytes(&self) -> Vec<u8> {
 
-        // Header, then bytes.
-        self.header
-            .as_bytes()
-            .iter()
-            .chain(self.bytes.iter())
-            .cloned()
+        let animation = self.is_animated().then(|| {
+            Section::new(
+                Section::ANIMATION,
+                anim::encode(self.delay, &self.frames, &self.header),
+            )
+        });
+        let sections = || animation.iter().chain(&self.metadata);
+        let len = Header::LEN
+            + self.bytes.len()
+            + sections().map(Section::encoded_len).sum::<usize>();
+
+        // Header, then bytes, then any sections.
+        let mut bytes = Vec::with_capacity(len);
+        bytes.extend_from_slice(&self.header.to_array());
+        match self.is_compressed() {
+            true => chunk::encode_runs(
+                &self.chunks,
+                self.has_alpha(),
+                self.checksum_algorithm(),
+                &mut bytes,
+            ),
+            false => bytes.extend_from_slice(&self.bytes),
+        }
+        for section in sections() {
+            section
+                .encode_into(&mut bytes, self.checksum_algorithm())
+                .expect("sections hold less than 4 GiB");
+        }
+
+        bytes
+    }
+
+    /// Writes this file to `writer` in the same format as `as_bytes`, without building the whole
+    /// file in memory first. The chunks are written straight from the bytes kept alongside
+    /// them, unless they're stored as runs, which are encoded up front.
+    /// ```
+    /// use ciebii_lib::{chunk::Chunk, file::CIEBIIFILE};
+    /// let file = CIEBIIFILE::try_from_chunks(1, 1, vec![Chunk::new(255, 0, 0)]).unwrap();
+    ///
+    /// let mut bytes = Vec::new();
+    /// file.write_into(&mut bytes).unwrap();
+    /// assert_eq!(bytes, file.as_bytes());
+    /// ```
+    pub fn write_into(&self, writer: &mut impl Write) -> io::Result<()> {
+        writer.write_all(&self.header.to_array())?;
+
+        let checksum = self.checksum_algorithm();
+
+        if self.is_compressed() {
+            let mut runs = Vec::new();
+            chunk::encode_runs(&self.chunks, self.has_alpha(), checksum, &mut runs);
+            writer.write_all(&runs)?;
+        } else {
+            writer.write_all(&self.bytes)?;
+        }
+
+        if self.is_animated() {
+            let payload = anim::encode(self.delay, &self.frames, &self.header);
+            Section::new(Section::ANIMATION, payload).write_into(writer, checksum)?;
+        }
+
+        for section in &self.metadata {
+            section.write_into(writer, checksum)?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes this file to `writer` in the flavor `options` ask for, like with a footer,
+    /// checksummed with another algorithm or without its thumbnail. Fails with `IllegalHeader`
+    /// for a format version this library can't write.
+    pub fn write_into_with(
+        &self,
+        writer: &mut impl Write,
+        options: &WriteOptions,
+    ) -> Result<(), ChunkError> {
+        options.check()?;
+
+        let mut file = self.clone();
+        match options.includes_thumbnail() {
+            Some(true) if self.metadata(Section::THUMBNAIL).is_none() => {
+                file.set_thumbnail(&thumbnail::generate(self))
+            }
+            Some(false) => file.metadata.retain(|section| section.tag() != Section::THUMBNAIL),
+            _ => {}
+        }
+        if let Some(codec) = options.encodes_with() {
+            file.set_compressed(codec == Codec::Rle);
+        }
+        if let Some(checksum) = options.checksums_with() {
+            file.set_checksum_algorithm(checksum);
+        }
+
+        if !options.includes_footer() {
+            return Ok(file.write_into(writer)?);
+        }
+
+        let mut writer = DigestWriter::new(writer);
+        file.write_into(&mut writer)?;
+        writer.write_footer(file.checksum_algorithm())?;
+
+        Ok(())
+    }
+
+    /// Returns the file as bytes in the flavor `options` ask for, see `write_into_with`.
+    pub fn as_bytes_with(&self, options: &WriteOptions) -> Result<Vec<u8>, ChunkError> {
+        let mut bytes = Vec::new();
+        self.write_into_with(&mut bytes, options)?;
+
+        Ok(bytes)
+    }
+
+    /// Returns a SHA-256 digest of the dimensions, and the pixels and delay of every frame.
+    /// Checksums, metadata and how the file is laid out on disk don't change it, so two files
+    /// showing the same image always hash the same, with this or any later version of the library.
+    /// The alpha is only hashed for images that aren't fully opaque.
+    /// ```
+    /// use ciebii_lib::{chunk::Chunk, file::CIEBIIFILE};
+    /// let a = CIEBIIFILE::try_from_chunks(1, 1, vec![Chunk::new(255, 0, 0)]).unwrap();
+    /// let b = CIEBIIFILE::try_from(a.as_bytes()).unwrap();
+    ///
+    /// assert_eq!(a.content_hash(), b.content_hash());
+    /// ```
+    pub fn content_hash(&self) -> [u8; 32] {
+        let (x, y) = self.dimensions();
+
+        let mut hasher = Sha256::new();
+        hasher.update((x as u64).to_be_bytes());
+        hasher.update((y as u64).to_be_bytes());
+        hasher.update((self.frame_count() as u64).to_be_bytes());
+
+        let frames = || {
+            std::iter::once((self.delay, &self.chunks))
+                .chain(self.frames.iter().map(|frame| (frame.delay(), frame.chunks())))
+        };
+        let translucent = frames().any(|(_, chunks)| !chunks.iter().all(Chunk::is_opaque));
+        let len = if translucent { 4 } else { 3 };
+
+        let mut buffer = [0; WRITE_BUFFER_CHUNKS * 4];
+        for (delay, chunks) in frames() {
+            hasher.update(delay.to_be_bytes());
+
+            for chunks in chunks.chunks(WRITE_BUFFER_CHUNKS) {
+                for (target, chunk) in buffer.chunks_exact_mut(len).zip(chunks) {
+                    let (r, g, b, a) = chunk.rgba().color();
+                    target.copy_from_slice(&[r, g, b, a][..len]);
+                }
+
+                hasher.update(&buffer[..chunks.len() * len]);
+            }
+        }
+
+        hasher.finalize().into()
+    }
+
+    /// Returns a SHA-256 digest of every row of the first frame, top to bottom, each taken over
+    /// the row's chunks as they're stored. Row `y` starts `Header::LEN + y * width * chunk_len()`
+    /// bytes into the file, so two copies can be compared row by row and only the rows that
+    /// differ fetched. The rows of compressed files are hashed as if they weren't, since runs
+    /// don't line up with rows.
+    /// ```
+    /// use ciebii_lib::{chunk::Chunk, file::CIEBIIFILE};
+    /// let file = CIEBIIFILE::try_from_chunks(1, 2, vec![Chunk::new(1, 2, 3); 2]).unwrap();
+    /// let rows = file.row_hashes();
+    ///
+    /// assert_eq!(rows.len(), 2);
+    /// assert_eq!(rows[0], rows[1]);
+    /// ```
+    pub fn row_hashes(&self) -> Vec<[u8; 32]> {
+        let (x, _) = self.dimensions();
+        let row_len = x * self.chunk_len();
+
+        // A file without pixels has no rows
+        if row_len == 0 {
+            return Vec::new();
+        }
+
+        self.bytes
+            .chunks(row_len)
+            .map(|row| Sha256::digest(row).into())
             .collect()
     }
 
@@ -92,20 +516,137 @@ impl CIEBIIFILE {
         let removed = self.chunks.remove(index);
 
         // Update the bytes
-        self.bytes = self
-            .chunks
-            .iter()
-            .flat_map(|chunk| chunk.as_bytes())
-            .collect();
+        let len = self.chunk_len();
+        self.bytes.drain(index * len..index * len + len);
 
         Ok(removed)
     }
 
+    /// Lets `f` change the chunks of the first frame in place, then updates the bytes once.
+    pub(crate) fn modify_chunks(&mut self, f: impl FnOnce(&mut [Chunk])) {
+        f(&mut self.chunks);
+
+        if !self.chunks.iter().all(Chunk::is_opaque) {
+            self.header = self.with_alpha_header();
+        }
+
+        // Update the bytes, reusing the buffer
+        let (alpha, checksum) = (self.has_alpha(), self.checksum_algorithm());
+        self.bytes.clear();
+        chunk::encode_into(&self.chunks, alpha, checksum, &mut self.bytes);
+    }
+
+    /// Builds an `x` by `y` file out of every frame passed through `f`, keeping the frame delays
+    /// and metadata. An embedded thumbnail is generated again from the new first frame.
+    pub(crate) fn map_frames(
+        &self,
+        x: usize,
+        y: usize,
+        mut f: impl FnMut(&[Chunk]) -> Vec<Chunk>,
+    ) -> Result<CIEBIIFILE, ChunkError> {
+        let mut file = CIEBIIFILE::try_from_chunks(x, y, f(&self.chunks))?;
+        file.set_compressed(self.is_compressed());
+        file.set_checksum_algorithm(self.checksum_algorithm());
+        file.delay = self.delay;
+
+        for frame in &self.frames {
+            file.push_frame(f(frame.chunks()), frame.delay())?;
+        }
+
+        for section in &self.metadata {
+            match section.tag() {
+                Section::THUMBNAIL => file.set_thumbnail(&thumbnail::generate(&file)),
+                tag => file.set_metadata(tag, section.payload().to_vec()),
+            }
+        }
+
+        Ok(file)
+    }
+
     /// Get a chunk at a given index
     pub fn get_at_index(&self, index: usize) -> Option<&Chunk> {
         self.chunks.get(index)
     }
 
+    /// Returns the index of the pixel at `x`, `y`, if it's inside the image
+    fn index_of(&self, x: usize, y: usize) -> Option<usize> {
+        let (width, height) = self.dimensions();
+
+        (x < width && y < height).then(|| y * width + x)
+    }
+
+    /// Returns the chunk of the pixel at `x`, `y` of the first frame, counting from the top left
+    /// corner. Returns `None` for pixels outside of the image, or not pushed yet.
+    /// ```
+    /// use ciebii_lib::{chunk::Chunk, file::CIEBIIFILE};
+    /// let chunks = vec![Chunk::new(1, 2, 3), Chunk::new(4, 5, 6)];
+    /// let file = CIEBIIFILE::try_from_chunks(1, 2, chunks).unwrap();
+    ///
+    /// assert_eq!(file.get_pixel(0, 1), Some(&Chunk::new(4, 5, 6)));
+    /// assert_eq!(file.get_pixel(1, 0), None);
+    /// ```
+    pub fn get_pixel(&self, x: usize, y: usize) -> Option<&Chunk> {
+        self.index_of(x, y).and_then(|index| self.chunks.get(index))
+    }
+
+    /// Overwrites the pixel at `x`, `y` of the first frame like `modify`. Fails with
+    /// `NonExistentChunk` for pixels outside of the image, or not pushed yet.
+    pub fn set_pixel(&mut self, x: usize, y: usize, chunk: Chunk) -> Result<(), ChunkError> {
+        let index = self.index_of(x, y).ok_or(ChunkError::NonExistentChunk)?;
+
+        self.modify(index, chunk)
+    }
+
+    /// Returns the rows of the first frame from top to bottom, each with one chunk per pixel
+    /// from left to right. A file that is still being pushed to ends with a partial row.
+    pub fn rows(&self) -> impl Iterator<Item = &[Chunk]> {
+        let (width, _) = self.dimensions();
+
+        // An image without columns has no chunks to split
+        self.chunks.chunks(width.max(1))
+    }
+
+    /// Returns a new file holding the `width` by `height` rectangle starting at `x`, `y` of
+    /// every frame, see `crop::crop`.
+    /// ```
+    /// use ciebii_lib::{chunk::Chunk, file::CIEBIIFILE};
+    /// let chunks = (0..4).map(|i| Chunk::new(i, 0, 0)).collect();
+    /// let file = CIEBIIFILE::try_from_chunks(2, 2, chunks).unwrap();
+    ///
+    /// let cropped = file.crop(0, 1, 2, 1).unwrap();
+    /// assert_eq!(cropped.dimensions(), (2, 1));
+    /// assert_eq!(cropped.chunks(), &[Chunk::new(2, 0, 0), Chunk::new(3, 0, 0)]);
+    /// ```
+    pub fn crop(
+        &self,
+        x: usize,
+        y: usize,
+        width: usize,
+        height: usize,
+    ) -> Result<CIEBIIFILE, ChunkError> {
+        crop::crop(self, x, y, width, height)
+    }
+
+    /// Returns a new file with every frame scaled to `width` by `height` pixels with `filter`,
+    /// see `resize::resize`.
+    /// ```
+    /// use ciebii_lib::{chunk::Chunk, file::CIEBIIFILE, resize::Filter};
+    /// let chunks = vec![Chunk::new(0, 0, 0), Chunk::new(200, 0, 0)];
+    /// let file = CIEBIIFILE::try_from_chunks(2, 1, chunks).unwrap();
+    ///
+    /// let resized = file.resize(4, 2, Filter::Nearest).unwrap();
+    /// assert_eq!(resized.dimensions(), (4, 2));
+    /// assert_eq!(resized.get_pixel(3, 1), Some(&Chunk::new(200, 0, 0)));
+    /// ```
+    pub fn resize(
+        &self,
+        width: usize,
+        height: usize,
+        filter: Filter,
+    ) -> Result<CIEBIIFILE, ChunkError> {
+        resize::resize(self, width, height, filter)
+    }
+
     /// Modify a chunk at a given index
     pub fn modify(&mut self, index: usize, new_chunk: Chunk) -> Result<(), ChunkError> {
 
@@ -115,14 +656,14 @@ impl CIEBIIFILE {
         }
 
         // Set the new chunk
+        self.fit_alpha(&[new_chunk]);
         self.chunks[index] = new_chunk;
 
         // Update the bytes
-        self.bytes = self
-            .chunks
-            .iter()
-            .flat_map(|chunk| chunk.as_bytes())
-            .collect();
+        let (len, alpha) = (self.chunk_len(), self.has_alpha());
+        let checksum = self.checksum_algorithm();
+        let target = &mut self.bytes[index * len..index * len + len];
+        chunk::encode_one(&new_chunk, alpha, checksum, target);
 
         Ok(())
     }
@@ -132,33 +673,82 @@ impl TryFrom<Vec<u8>> for CIEBIIFILE {
     type Error = ChunkError;
 
     fn try_from(bytes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from(&bytes[..])
+    }
+}
 
-        // The header is always the first 30 bytes
-        let header = &bytes[0..30];
+impl TryFrom<&[u8]> for CIEBIIFILE {
+    type Error = ChunkError;
 
-        // Try to construct a header
-        let header = Header::try_from(header.to_owned())?;
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(bytes);
 
-        let dimensions = header.dimensions();
+        let header = cursor.header()?;
+        let (x, y) = header.dimensions();
 
+        // One chunk per pixel, any sections follow
+        let pixels = x.checked_mul(y).ok_or(ChunkError::DimensionMismatch)?;
+        let (chunks, chunk_bytes) = match header.is_compressed() {
+            true => {
+                let chunks = cursor.runs(pixels, &header)?;
+                let mut bytes = Vec::new();
+                chunk::encode_into(
+                    &chunks,
+                    header.has_alpha(),
+                    header.checksum_algorithm(),
+                    &mut bytes,
+                );
 
+                (chunks, bytes)
+            }
+            false => {
+                let bytes = cursor.chunk_bytes(pixels, header.chunk_len());
 
-        // Cant use iterators :(
-        let mut chunks = Vec::new();        
+                let checksum = header.checksum_algorithm();
 
-        for chunk in bytes.chunks(5).skip(6) {
-            chunks.push(Chunk::try_from(chunk)?);
-        }
+                (chunk::decode_all(bytes, header.has_alpha(), checksum)?, bytes.to_vec())
+            }
+        };
 
         // Verify that the length corresponds to the amount of chunks
-        if chunks.len() != dimensions.0 * dimensions.1 {
+        if chunks.len() != pixels {
             return Err(ChunkError::DimensionMismatch);
         }
 
+        let mut delay = 0;
+        let mut frames = Vec::new();
+        let mut metadata = Vec::new();
+
+        // Parse the sections, skipping the ones we don't know about
+        while !cursor.is_empty() {
+
+            // Anything that doesn't start like a section is garbage rather than a broken section
+            if !cursor.at_section() {
+                return Err(ChunkError::TrailingData(cursor.offset()));
+            }
+
+            let start = cursor.offset();
+            let section = cursor.section(&header)?;
+
+            if section.tag() == Section::ANIMATION {
+                (delay, frames) =
+                    anim::decode(section.payload(), chunks.len(), &header)?;
+            } else if Section::METADATA.contains(&section.tag()) {
+                metadata.push(section);
+            } else if section.tag() == Section::FOOTER
+                && section.payload() != Sha256::digest(&bytes[..start]).as_slice()
+            {
+                return Err(ChunkError::ChecksumFail);
+            }
+        }
+
         Ok(Self {
             chunks,
-            bytes: bytes[30..].to_vec(),
+            bytes: chunk_bytes,
             header,
+            delay,
+            frames,
+            metadata,
         })
     }
 }
@@ -199,6 +789,7 @@ mod file_tests {
     #[test]
     fn as_bytes() {
         let mut file = CIEBIIFILE::new(20, 20);
+        file.set_checksum_algorithm(ChecksumAlgorithm::Ciebii);
         let chunk = Chunk::new(0xFF, 0x00, 0x00);
         file.push_chunk(chunk);
 
@@ -214,6 +805,7 @@ mod file_tests {
     #[test]
     fn remove_at_index() {
         let mut file = CIEBIIFILE::new(20, 20);
+        file.set_checksum_algorithm(ChecksumAlgorithm::Ciebii);
         file.push_chunk(Chunk::new(0x69, 0x42, 0x00));
         file.push_chunk(Chunk::new(0xAB, 0xCD, 0xEF));
         file.push_chunk(Chunk::new(0x12, 0x34, 0x56));
@@ -249,9 +841,40 @@ mod file_tests {
         assert_eq!(file.get_at_index(2).unwrap(), &Chunk::new(0x12, 0x34, 0x56));
     }
 
+    #[test]
+    fn pixels() {
+        let chunks = (0..6).map(|i| Chunk::new(i, 0, 0)).collect();
+        let mut file = CIEBIIFILE::try_from_chunks(3, 2, chunks).unwrap();
+
+        assert_eq!(file.get_pixel(2, 1), Some(&Chunk::new(5, 0, 0)));
+        assert_eq!(file.get_pixel(3, 0), None);
+        assert_eq!(file.get_pixel(0, 2), None);
+
+        file.set_pixel(1, 1, Chunk::new(9, 9, 9)).unwrap();
+        assert_eq!(file.get_at_index(4), Some(&Chunk::new(9, 9, 9)));
+        assert_eq!(CIEBIIFILE::try_from(file.as_bytes()).unwrap(), file);
+
+        if let Err(ChunkError::NonExistentChunk) = file.set_pixel(3, 0, Chunk::new(1, 2, 3)) {
+        } else {
+            panic!()
+        }
+    }
+
+    #[test]
+    fn rows() {
+        let chunks: Vec<Chunk> = (0..6).map(|i| Chunk::new(i, 0, 0)).collect();
+        let file = CIEBIIFILE::try_from_chunks(3, 2, chunks.clone()).unwrap();
+
+        let rows: Vec<&[Chunk]> = file.rows().collect();
+        assert_eq!(rows, vec![&chunks[..3], &chunks[3..]]);
+
+        assert_eq!(CIEBIIFILE::new(0, 4).rows().count(), 0);
+    }
+
     #[test]
     fn modify_chunk() {
         let mut file = CIEBIIFILE::new(20, 20);
+        file.set_checksum_algorithm(ChecksumAlgorithm::Ciebii);
         file.push_chunk(Chunk::new(0x69, 0x42, 0x00));
         file.push_chunk(Chunk::new(0xAB, 0xCD, 0xEF));
         file.push_chunk(Chunk::new(0x12, 0x34, 0x56));
@@ -377,8 +1000,10 @@ mod file_tests {
         let file = CIEBIIFILE::try_from_chunks(2, 2, chunks);
 
         assert!(file.is_ok());
-        let file = file.unwrap();
+        let mut file = file.unwrap();
         assert_eq!(file.dimensions(), (2, 2));
+
+        file.set_checksum_algorithm(ChecksumAlgorithm::Ciebii);
         assert_eq!(
             file.as_bytes(),
             [
@@ -388,4 +1013,336 @@ mod file_tests {
             ]
         );
     }
+
+    #[test]
+    fn push_frame() {
+        let mut file = CIEBIIFILE::try_from_chunks(1, 1, vec![Chunk::new(1, 2, 3)]).unwrap();
+
+        assert!(!file.is_animated());
+        assert!(file.push_frame(vec![Chunk::new(4, 5, 6)], 50).is_ok());
+        assert!(file.set_frame_delay(0, 100).is_ok());
+
+        assert!(file.is_animated());
+        assert_eq!(file.frame_count(), 2);
+        assert_eq!(file.frame(0), Some(&vec![Chunk::new(1, 2, 3)]));
+        assert_eq!(file.frame(1), Some(&vec![Chunk::new(4, 5, 6)]));
+        assert_eq!(file.frame(2), None);
+        assert_eq!(file.frame_delay(0), Some(100));
+        assert_eq!(file.frame_delay(1), Some(50));
+
+        if let ChunkError::DimensionMismatch = file.push_frame(vec![], 50).unwrap_err() {
+        } else {
+            panic!()
+        }
+    }
+
+    #[test]
+    fn animation_round_trip() {
+        let mut file =
+            CIEBIIFILE::try_from_chunks(2, 1, vec![Chunk::new(1, 2, 3), Chunk::new(4, 5, 6)])
+                .unwrap();
+        file.push_frame(vec![Chunk::new(7, 8, 9), Chunk::new(10, 11, 12)], 40)
+            .unwrap();
+        file.push_frame(vec![Chunk::new(13, 14, 15), Chunk::new(16, 17, 18)], 60)
+            .unwrap();
+        file.set_frame_delay(0, 20).unwrap();
+
+        let parsed = CIEBIIFILE::try_from(file.as_bytes()).unwrap();
+
+        assert_eq!(parsed, file);
+        assert_eq!(parsed.frame_count(), 3);
+        assert_eq!(parsed.frame_delay(2), Some(60));
+    }
+
+    #[test]
+    fn metadata_round_trip() {
+        let mut file = CIEBIIFILE::try_from_chunks(1, 1, vec![Chunk::new(1, 2, 3)]).unwrap();
+        file.set_metadata(Section::EXIF, vec![1, 2, 3]);
+        file.set_metadata(Section::XMP, b"<x:xmpmeta/>".to_vec());
+        file.set_metadata(Section::EXIF, vec![4, 5]);
+
+        let parsed = CIEBIIFILE::try_from(file.as_bytes()).unwrap();
+
+        assert_eq!(parsed, file);
+        assert_eq!(parsed.metadata(Section::EXIF), Some(&[4, 5][..]));
+        assert_eq!(parsed.metadata(Section::XMP), Some(&b"<x:xmpmeta/>"[..]));
+    }
+
+    #[test]
+    fn thumbnail_round_trip() {
+        let mut file = CIEBIIFILE::try_from_chunks(1, 1, vec![Chunk::new(1, 2, 3)]).unwrap();
+        assert!(file.thumbnail().unwrap().is_none());
+
+        let thumbnail = CIEBIIFILE::try_from_chunks(1, 1, vec![Chunk::new(4, 5, 6)]).unwrap();
+        file.set_thumbnail(&thumbnail);
+
+        let file = CIEBIIFILE::try_from(file.as_bytes()).unwrap();
+        assert_eq!(file.thumbnail().unwrap().unwrap().chunks(), thumbnail.chunks());
+    }
+
+    #[test]
+    fn write_into_matches_as_bytes() {
+        // More chunks than fit in the write buffer at once
+        let chunks = (0..3000).map(|i| Chunk::new(i as u8, (i / 256) as u8, 0)).collect();
+        let mut file = CIEBIIFILE::try_from_chunks(60, 50, chunks).unwrap();
+        file.push_frame(vec![Chunk::new(1, 2, 3); 3000], 40).unwrap();
+        file.set_metadata(Section::EXIF, vec![1, 2, 3]);
+
+        let mut bytes = Vec::new();
+        file.write_into(&mut bytes).unwrap();
+
+        assert_eq!(bytes, file.as_bytes());
+    }
+
+    #[test]
+    fn content_hash() {
+        let file = CIEBIIFILE::try_from_chunks(2, 1, vec![Chunk::new(1, 2, 3); 2]).unwrap();
+
+        let mut with_metadata = file.clone();
+        with_metadata.set_metadata(Section::EXIF, vec![1, 2, 3]);
+        assert_eq!(with_metadata.content_hash(), file.content_hash());
+
+        let transposed = CIEBIIFILE::try_from_chunks(1, 2, vec![Chunk::new(1, 2, 3); 2]).unwrap();
+        assert_ne!(transposed.content_hash(), file.content_hash());
+
+        let mut animated = file.clone();
+        animated.push_frame(vec![Chunk::new(1, 2, 3); 2], 100).unwrap();
+        assert_ne!(animated.content_hash(), file.content_hash());
+    }
+
+    #[test]
+    fn row_hashes() {
+        let chunks = vec![
+            Chunk::new(1, 2, 3),
+            Chunk::new(4, 5, 6),
+            Chunk::new(1, 2, 3),
+            Chunk::new(4, 5, 7),
+        ];
+        let file = CIEBIIFILE::try_from_chunks(2, 2, chunks).unwrap();
+        let rows = file.row_hashes();
+
+        assert_eq!(rows.len(), 2);
+        assert_ne!(rows[0], rows[1]);
+
+        let bytes = file.as_bytes();
+        let first_row: [u8; 32] = Sha256::digest(&bytes[Header::LEN..Header::LEN + 10]).into();
+        assert_eq!(rows[0], first_row);
+    }
+
+    #[test]
+    fn alpha_round_trip() {
+        let chunks = vec![Chunk::new(1, 2, 3), Chunk::with_alpha(4, 5, 6, 0)];
+        let mut file = CIEBIIFILE::try_from_chunks(2, 1, chunks).unwrap();
+        file.push_frame(vec![Chunk::with_alpha(7, 8, 9, 128); 2], 40).unwrap();
+
+        assert!(file.has_alpha());
+        assert_eq!(file.chunk_len(), Chunk::RGBA_LEN);
+
+        let bytes = file.as_bytes();
+        assert_eq!(&bytes[Header::LEN + 6..Header::LEN + 10], &[4, 5, 6, 0]);
+
+        let parsed = CIEBIIFILE::try_from(bytes).unwrap();
+        assert_eq!(parsed, file);
+        assert_eq!(parsed.frame(1).unwrap()[0].alpha(), 128);
+    }
+
+    #[test]
+    fn opaque_files_stay_opaque() {
+        let chunks = vec![Chunk::with_alpha(1, 2, 3, 255)];
+        let file = CIEBIIFILE::try_from_chunks(1, 1, chunks).unwrap();
+
+        assert!(!file.has_alpha());
+        assert_eq!(file.as_bytes().len(), Header::LEN + Chunk::LEN);
+    }
+
+    #[test]
+    fn modify_adds_alpha() {
+        let mut file = CIEBIIFILE::try_from_chunks(2, 1, vec![Chunk::new(1, 2, 3); 2]).unwrap();
+        file.modify(1, Chunk::with_alpha(4, 5, 6, 7)).unwrap();
+
+        assert!(file.has_alpha());
+        assert_eq!(
+            CIEBIIFILE::try_from(file.as_bytes()).unwrap().chunks(),
+            &vec![Chunk::new(1, 2, 3), Chunk::with_alpha(4, 5, 6, 7)]
+        );
+
+        file.remove_at_index(0).unwrap();
+        let mut bytes = Vec::new();
+        let chunk = Chunk::with_alpha(4, 5, 6, 7);
+        chunk::encode_into(&[chunk], true, ChecksumAlgorithm::Crc32, &mut bytes);
+        assert_eq!(file.bytes, bytes);
+    }
+
+    #[test]
+    fn content_hash_with_alpha() {
+        let opaque = CIEBIIFILE::try_from_chunks(1, 1, vec![Chunk::new(1, 2, 3)]).unwrap();
+        let translucent =
+            CIEBIIFILE::try_from_chunks(1, 1, vec![Chunk::with_alpha(1, 2, 3, 0)]).unwrap();
+
+        assert_ne!(opaque.content_hash(), translucent.content_hash());
+    }
+
+    #[test]
+    fn content_hash_is_stable() {
+        let file = CIEBIIFILE::try_from_chunks(1, 1, vec![Chunk::new(255, 0, 0)]).unwrap();
+        let hex: String = file
+            .content_hash()
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect();
+
+        // Changing this breaks every cache keyed by content hashes
+        assert_eq!(
+            hex,
+            "0a99a3935b0404dfd3c60a276fbf4c6ff93176598f3cfd1768f8eed47eb0aebc"
+        );
+    }
+
+    #[test]
+    fn from_32bit_target() {
+        let dimensions = [0, 0, 0, 2, 0, 0, 0, 1];
+        let checksum = ChecksumAlgorithm::Ciebii.checksum(&dimensions) as u32;
+        let mut bytes = [&CIEBIIFILE::MAGIC_BYTES[..], &dimensions, &checksum.to_be_bytes()].concat();
+        let chunks = vec![Chunk::new(1, 2, 3), Chunk::new(4, 5, 6)];
+        chunk::encode_into(&chunks, false, ChecksumAlgorithm::Ciebii, &mut bytes);
+        assert_eq!(bytes.len(), Header::LEN_32BIT + 2 * Chunk::LEN);
+
+        let file = CIEBIIFILE::try_from(&bytes[..]).unwrap();
+
+        assert_eq!(file.dimensions(), (2, 1));
+        assert_eq!(file.chunks(), &chunks);
+        assert_eq!(file.checksum_algorithm(), ChecksumAlgorithm::Ciebii);
+
+        // Written again with the current header
+        assert_eq!(file.as_bytes().len(), Header::LEN + 2 * Chunk::LEN);
+        assert_eq!(CIEBIIFILE::try_from(file.as_bytes()).unwrap(), file);
+    }
+
+    #[test]
+    fn test_from_bytes_trailing_data() {
+        let mut bytes = CIEBIIFILE::try_from_chunks(1, 1, vec![Chunk::new(1, 2, 3)])
+            .unwrap()
+            .as_bytes();
+        bytes.extend([0, 0, 0, 5, 1, 2]);
+
+        if let ChunkError::TrailingData(35) = CIEBIIFILE::try_from(bytes).unwrap_err() {
+        } else {
+            panic!()
+        }
+    }
+
+    #[test]
+    fn test_from_bytes_trailing_text() {
+        let mut bytes = CIEBIIFILE::try_from_chunks(1, 1, vec![Chunk::new(1, 2, 3)])
+            .unwrap()
+            .as_bytes();
+        bytes.extend(b"hello world\n");
+
+        if let ChunkError::TrailingData(35) = CIEBIIFILE::try_from(bytes).unwrap_err() {
+        } else {
+            panic!()
+        }
+    }
+
+    #[test]
+    fn test_from_bytes_truncated_section() {
+        let mut bytes = CIEBIIFILE::try_from_chunks(1, 1, vec![Chunk::new(1, 2, 3)])
+            .unwrap()
+            .as_bytes();
+        let section = Section::new(Section::EXIF, vec![1, 2, 3]).as_bytes(ChecksumAlgorithm::Crc32).unwrap();
+        bytes.extend(&section[..section.len() - 1]);
+
+        if let ChunkError::InvalidLen = CIEBIIFILE::try_from(bytes).unwrap_err() {
+        } else {
+            panic!()
+        }
+    }
+
+    #[test]
+    fn test_from_bytes_unknown_section() {
+        let mut bytes = CIEBIIFILE::try_from_chunks(1, 1, vec![Chunk::new(1, 2, 3)])
+            .unwrap()
+            .as_bytes();
+        bytes.extend(Section::new(*b"NOTE", vec![1, 2, 3]).as_bytes(ChecksumAlgorithm::Crc32).unwrap());
+
+        let file = CIEBIIFILE::try_from(bytes).unwrap();
+
+        assert_eq!(file.chunks(), &vec![Chunk::new(1, 2, 3)]);
+        assert!(!file.is_animated());
+    }
+
+    #[test]
+    fn checksum_algorithm() {
+        let chunks = vec![Chunk::new(1, 2, 3), Chunk::with_alpha(4, 5, 6, 7)];
+        let mut file = CIEBIIFILE::try_from_chunks(2, 1, chunks).unwrap();
+        file.set_metadata(Section::EXIF, vec![1, 2, 3]);
+        assert_eq!(file.checksum_algorithm(), ChecksumAlgorithm::Crc32);
+
+        let crc = file.as_bytes();
+        file.set_checksum_algorithm(ChecksumAlgorithm::Ciebii);
+        let legacy = file.as_bytes();
+
+        // Only the flags and the checksums differ
+        assert_eq!(crc.len(), legacy.len());
+        assert_eq!(crc[26] & !4, legacy[26]);
+        assert_eq!(crc[Header::LEN..Header::LEN + 4], legacy[Header::LEN..Header::LEN + 4]);
+
+        // Files are read back with whatever they were written with
+        let read = CIEBIIFILE::try_from(legacy).unwrap();
+        assert_eq!(read.checksum_algorithm(), ChecksumAlgorithm::Ciebii);
+        assert_eq!(read, file);
+        assert_eq!(CIEBIIFILE::try_from(crc).unwrap().chunks(), file.chunks());
+    }
+
+    #[test]
+    fn compressed_round_trip() {
+        let mut chunks = vec![Chunk::new(1, 2, 3); 6];
+        chunks[5] = Chunk::with_alpha(4, 5, 6, 7);
+        let mut file = CIEBIIFILE::try_from_chunks(3, 2, chunks).unwrap();
+        file.push_frame(vec![Chunk::new(8, 9, 10); 6], 40).unwrap();
+        file.set_metadata(Section::EXIF, vec![1, 2, 3]);
+        file.set_compressed(true);
+
+        let bytes = file.as_bytes();
+        assert_eq!(bytes[Header::LEN], 4);
+
+        let parsed = CIEBIIFILE::try_from(&bytes[..]).unwrap();
+        assert!(parsed.is_compressed() && parsed.has_alpha());
+        assert_eq!(parsed, file);
+
+        // The alpha channel doesn't lose the compression
+        let mut file = CIEBIIFILE::try_from_chunks(2, 1, vec![Chunk::new(1, 2, 3); 2]).unwrap();
+        file.set_compressed(true);
+        file.modify(1, Chunk::with_alpha(4, 5, 6, 7)).unwrap();
+        assert!(file.is_compressed());
+    }
+
+    #[test]
+    fn compressed_truncated() {
+        let mut file = CIEBIIFILE::try_from_chunks(2, 1, vec![Chunk::new(1, 2, 3); 2]).unwrap();
+        file.set_compressed(true);
+        let bytes = file.as_bytes();
+
+        if let Err(ChunkError::InvalidLen) = CIEBIIFILE::try_from(&bytes[..bytes.len() - 1]) {
+        } else {
+            panic!()
+        }
+        if let Err(ChunkError::DimensionMismatch) = CIEBIIFILE::try_from(&bytes[..Header::LEN]) {
+        } else {
+            panic!()
+        }
+    }
+
+    #[test]
+    fn compressed_hostile_dimensions() {
+        for side in [1 << 20, 1 << 31] {
+            let mut bytes = Header::new(side, side).compressed(true).as_bytes();
+            chunk::encode_runs(&[Chunk::new(1, 2, 3)], false, ChecksumAlgorithm::Crc32, &mut bytes);
+
+            if let Err(ChunkError::DimensionMismatch) = CIEBIIFILE::try_from(&bytes[..]) {
+            } else {
+                panic!()
+            }
+        }
+    }
 }
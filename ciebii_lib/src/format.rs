@@ -0,0 +1,85 @@
+use super::{compat, file::CIEBIIFILE};
+
+/// The canonical extension of ciebii files
+pub const EXTENSION: &str = "cib";
+
+/// The MIME type of ciebii files
+pub const MIME_TYPE: &str = "image/x-ciebii";
+
+/// The MIME type of legacy SHITFILEs
+pub const LEGACY_MIME_TYPE: &str = "image/x-shitfile";
+
+/// A format recognized by its magic bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FormatKind {
+    /// A ciebii file
+    Ciebii,
+
+    /// A file in the legacy SHITFILE format
+    Legacy,
+}
+
+impl FormatKind {
+    /// Returns the canonical extension of this format, without the dot
+    pub fn extension(self) -> &'static str {
+        match self {
+            FormatKind::Ciebii => EXTENSION,
+            FormatKind::Legacy => compat::LEGACY_EXTENSION,
+        }
+    }
+
+    /// Returns the MIME type of this format
+    pub fn mime_type(self) -> &'static str {
+        match self {
+            FormatKind::Ciebii => MIME_TYPE,
+            FormatKind::Legacy => LEGACY_MIME_TYPE,
+        }
+    }
+}
+
+/// Works out whether `bytes` start like a ciebii or legacy file. Only the magic bytes
+/// are looked at, so the first few bytes of a file are enough.
+/// ```
+/// use ciebii_lib::{sniff, FormatKind};
+/// assert_eq!(sniff(b"CIEBIIFILE"), Some(FormatKind::Ciebii));
+/// assert_eq!(sniff(b"\x89PNG"), None);
+/// ```
+pub fn sniff(bytes: &[u8]) -> Option<FormatKind> {
+    if bytes.starts_with(&CIEBIIFILE::MAGIC_BYTES) {
+        return Some(FormatKind::Ciebii);
+    }
+
+    if bytes.starts_with(&compat::LEGACY_MAGIC_BYTES) {
+        return Some(FormatKind::Legacy);
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod format_tests {
+    use super::*;
+    use crate::chunk::Chunk;
+
+    #[test]
+    fn sniff_files() {
+        let file = CIEBIIFILE::try_from_chunks(1, 1, vec![Chunk::new(1, 2, 3)]).unwrap();
+
+        assert_eq!(sniff(&file.as_bytes()), Some(FormatKind::Ciebii));
+        assert_eq!(sniff(&compat::encode(&file)), Some(FormatKind::Legacy));
+    }
+
+    #[test]
+    fn sniff_unknown() {
+        assert_eq!(sniff(b""), None);
+        assert_eq!(sniff(b"CIEB"), None);
+        assert_eq!(sniff(b"qoif"), None);
+    }
+
+    #[test]
+    fn names() {
+        assert_eq!(FormatKind::Ciebii.extension(), "cib");
+        assert_eq!(FormatKind::Legacy.extension(), "shf");
+        assert_eq!(FormatKind::Ciebii.mime_type(), "image/x-ciebii");
+    }
+}
@@ -1,4 +1,13 @@
-use super::{checksum::checksum, error::ChunkError, file::CIEBIIFILE};
+use super::{checksum::ChecksumAlgorithm, chunk, error::ChunkError, file::CIEBIIFILE};
+
+/// Set in the flags of files whose chunks carry an alpha byte
+const ALPHA_FLAG: u8 = 1;
+
+/// Set in the flags of files whose chunks are stored as runs of equal chunks
+const RLE_FLAG: u8 = 2;
+
+/// Set in the flags of files checksummed with CRC-32 rather than the legacy ciebii checksum
+const CRC_FLAG: u8 = 4;
 
 /// A header chunk consisting of 3 chunks. It contains the dimensions of the file and a checksum of the dimensions.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -6,6 +15,18 @@ pub struct Header {
     x: usize,
     y: usize,
     checksum: u32,
+
+    // The version of the format the file is written in
+    version: u8,
+
+    // Whether the chunks carry an alpha byte
+    alpha: bool,
+
+    // Whether the chunks are stored as runs
+    compressed: bool,
+
+    // How the header, chunks and sections are checksummed
+    algorithm: ChecksumAlgorithm,
 }
 
 #[allow(dead_code)]
@@ -15,19 +36,56 @@ impl Header {
     //! TODO
     const MAGIC_BYTES: [u8; 10] = [67, 73, 69, 66, 73, 73, 70, 73, 76, 69];
 
+    /// The length of a header in bytes
+    pub const LEN: usize = 30;
+
+    /// The length of the headers 32-bit builds wrote before the dimensions were fixed to
+    /// 64 bits, with 4 bytes for each dimension and the checksum
+    pub const LEN_32BIT: usize = 22;
+
+    /// The version of the format headers are written in
+    pub const VERSION: u8 = 0;
+
+    /// Starts building a header field by field, see `HeaderBuilder`
+    pub fn builder() -> HeaderBuilder {
+        HeaderBuilder::default()
+    }
+
     pub fn new(x: usize, y: usize) -> Self {
+        Self::with_alpha(x, y, false)
+    }
+
+    /// Creates a header for chunks that carry an alpha byte if `alpha` is set. New headers are
+    /// checksummed with CRC-32.
+    pub fn with_alpha(x: usize, y: usize, alpha: bool) -> Self {
+        let mut header = Self {
+            x,
+            y,
+            checksum: 0,
+            version: Self::VERSION,
+            alpha,
+            compressed: false,
+            algorithm: ChecksumAlgorithm::default(),
+        };
+        header.checksum = header_checksum(x as u64, y as u64, header.flags());
+
+        header
+    }
 
-        // Merge the bytes of x and y to use them to create a checksum.
-        let bytes: Vec<u8> = x
-            .to_be_bytes()
-            .iter()
-            .chain(y.to_be_bytes().iter())
-            .cloned()
-            .collect();
+    /// Returns this header for chunks stored as runs of equal chunks if `compressed` is set
+    pub fn compressed(self, compressed: bool) -> Self {
+        let mut header = Self { compressed, ..self };
+        header.checksum = header_checksum(self.x as u64, self.y as u64, header.flags());
 
-        let checksum = checksum(&bytes) as u32;
+        header
+    }
 
-        Self { x, y, checksum }
+    /// Returns this header for a file checksummed with `algorithm`, the header itself included
+    pub fn checksummed(self, algorithm: ChecksumAlgorithm) -> Self {
+        let mut header = Self { algorithm, ..self };
+        header.checksum = header_checksum(self.x as u64, self.y as u64, header.flags());
+
+        header
     }
 
     /// Returns the checksum of this header
@@ -40,19 +98,161 @@ impl Header {
         (self.x, self.y)
     }
 
-    // Returns the bytes as [HEADER, X (usize), Y (usize), CHECKSUM ]
-    //                         10b     8b         8b          4b
+    /// Returns the version of the format the file is written in
+    pub fn version(&self) -> u8 {
+        self.version
+    }
+
+    /// Returns whether the chunks of the file carry an alpha byte
+    pub fn has_alpha(&self) -> bool {
+        self.alpha
+    }
+
+    /// Returns whether the chunks of the file are stored as runs of equal chunks, each run
+    /// taking a byte for its length and a single chunk
+    pub fn is_compressed(&self) -> bool {
+        self.compressed
+    }
+
+    /// Returns how the header, chunks and sections of the file are checksummed
+    pub fn checksum_algorithm(&self) -> ChecksumAlgorithm {
+        self.algorithm
+    }
+
+    /// Returns the length of every chunk of the file in bytes
+    pub fn chunk_len(&self) -> usize {
+        chunk::len(self.alpha)
+    }
+
+    fn flags(&self) -> u8 {
+        let alpha = if self.alpha { ALPHA_FLAG } else { 0 };
+        let rle = if self.compressed { RLE_FLAG } else { 0 };
+        let crc = match self.algorithm {
+            ChecksumAlgorithm::Ciebii => 0,
+            ChecksumAlgorithm::Crc32 => CRC_FLAG,
+        };
+
+        alpha | rle | crc
+    }
+
+    // Returns the bytes as [HEADER, X (u64), Y (u64), FLAGS, VERSION, CHECKSUM ]
+    //                         10b     8b       8b       1b       1b        2b
     /// Returns the header as a byte array.
     pub fn as_bytes(&self) -> Vec<u8> {
+        self.to_array().to_vec()
+    }
+
+    /// Returns the header as bytes like `as_bytes`, without allocating.
+    pub fn to_array(&self) -> [u8; Self::LEN] {
+        let mut bytes = [0; Self::LEN];
+
+        // magic bytes, then x, then y, then the flags, the version and the checksum.
+        bytes[..10].copy_from_slice(&Header::MAGIC_BYTES);
+        bytes[10..18].copy_from_slice(&(self.x as u64).to_be_bytes());
+        bytes[18..26].copy_from_slice(&(self.y as u64).to_be_bytes());
+        bytes[26] = self.flags();
+        bytes[27] = self.version;
+        bytes[28..].copy_from_slice(&(self.checksum as u16).to_be_bytes());
+
+        bytes
+    }
+}
+
+/// Checksums the bytes of the dimensions, and the flags if any are set, so headers without
+/// flags checksum just like they did before there were any. The flags pick the algorithm.
+fn header_checksum(x: u64, y: u64, flags: u8) -> u32 {
+    let mut bytes = [x.to_be_bytes(), y.to_be_bytes()].concat();
+    if flags != 0 {
+        bytes.push(flags);
+    }
+
+    algorithm(flags).checksum(&bytes) as u32
+}
 
-        // magic bytes, then x, then y, then the checksum.
-        Header::MAGIC_BYTES
-            .iter()
-            .chain(self.x.to_be_bytes().iter())
-            .chain(self.y.to_be_bytes().iter())
-            .chain(self.checksum.to_be_bytes().iter())
-            .cloned()
-            .collect()
+/// Returns the algorithm a file with `flags` is checksummed with
+fn algorithm(flags: u8) -> ChecksumAlgorithm {
+    match flags & CRC_FLAG {
+        0 => ChecksumAlgorithm::Ciebii,
+        _ => ChecksumAlgorithm::Crc32,
+    }
+}
+
+/// Builds a header out of its fields, checking them before anything is encoded.
+/// ```
+/// use ciebii_lib::header::Header;
+/// let header = Header::builder().width(640).height(480).build().unwrap();
+///
+/// assert_eq!(header.dimensions(), (640, 480));
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+pub struct HeaderBuilder {
+    width: Option<usize>,
+    height: Option<usize>,
+    version: u8,
+    alpha: bool,
+    compressed: bool,
+    algorithm: ChecksumAlgorithm,
+}
+
+impl HeaderBuilder {
+
+    /// Sets the width in pixels
+    pub fn width(mut self, width: usize) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Sets the height in pixels
+    pub fn height(mut self, height: usize) -> Self {
+        self.height = Some(height);
+        self
+    }
+
+    /// Sets the format version, `Header::VERSION` unless set
+    pub fn version(mut self, version: u8) -> Self {
+        self.version = version;
+        self
+    }
+
+    /// Sets whether the chunks carry an alpha byte, they don't unless set
+    pub fn alpha(mut self, alpha: bool) -> Self {
+        self.alpha = alpha;
+        self
+    }
+
+    /// Sets whether the chunks are stored as runs, they aren't unless set
+    pub fn compressed(mut self, compressed: bool) -> Self {
+        self.compressed = compressed;
+        self
+    }
+
+    /// Sets how the file is checksummed, with CRC-32 unless set
+    pub fn checksum(mut self, algorithm: ChecksumAlgorithm) -> Self {
+        self.algorithm = algorithm;
+        self
+    }
+
+    /// Checks the fields and builds the header. Fails with `DimensionMismatch` if a dimension
+    /// is missing or the image is too large to address, and with `IllegalHeader` for a version
+    /// this library can't write.
+    pub fn build(self) -> Result<Header, ChunkError> {
+        let (Some(x), Some(y)) = (self.width, self.height) else {
+            return Err(ChunkError::DimensionMismatch);
+        };
+
+        // Every byte of the file has to be addressable
+        x.checked_mul(y)
+            .and_then(|pixels| pixels.checked_mul(chunk::len(self.alpha)))
+            .and_then(|len| len.checked_add(Header::LEN))
+            .ok_or(ChunkError::DimensionMismatch)?;
+
+        if self.version != Header::VERSION {
+            return Err(ChunkError::IllegalHeader);
+        }
+
+        Ok(Header::with_alpha(x, y, self.alpha)
+            .compressed(self.compressed)
+            .checksummed(self.algorithm))
     }
 }
 
@@ -61,8 +261,13 @@ impl TryFrom<Vec<u8>> for Header {
 
     fn try_from(bytes: Vec<u8>) -> Result<Self, Self::Error> {
 
-        // All headers must be 30 bytes long
-        if bytes.len() != 30 {
+        // Headers from 32-bit builds are laid out on their own
+        if bytes.len() == Header::LEN_32BIT {
+            return parse_32bit(&bytes);
+        }
+
+        // All other headers must be 30 bytes long
+        if bytes.len() != Header::LEN {
             return Err(ChunkError::InvalidLen);
         }
 
@@ -74,47 +279,72 @@ impl TryFrom<Vec<u8>> for Header {
             return Err(ChunkError::IllegalHeader);
         }
 
-        // bytes for width
-        let x = &bytes[10..18];
-
-        // bytes for height
-        let y = &bytes[18..26];
-
-        // Original checksum input
-        let old_checksum_data = &bytes[26..30];
-
-        // New checksum input
-        let new_checksum_data: Vec<u8> = x.iter().chain(y.iter()).cloned().collect();
-
-        // try to create X from bytes
-        let x = usize::from_be_bytes(x.try_into()?);
+        // The width and height are 64 bits wide on every target
+        let x = u64::from_be_bytes(bytes[10..18].try_into()?);
+        let y = u64::from_be_bytes(bytes[18..26].try_into()?);
 
-        // try to create Y from bytes
-        let y = usize::from_be_bytes(y.try_into()?);
+        // The flags and the version take the first two bytes of the checksum, which used to
+        // always be 0
+        let flags = bytes[26];
+        let version = bytes[27];
 
-        let old_checksum = u32::from_be_bytes(old_checksum_data.try_into()?);
+        // Flags and versions this one doesn't know about change the layout in ways it can't
+        // read
+        if flags & !(ALPHA_FLAG | RLE_FLAG | CRC_FLAG) != 0 || version > Header::VERSION {
+            return Err(ChunkError::IllegalHeader);
+        }
 
-        let new_checksum = checksum(&new_checksum_data) as u32;
+        let old_checksum = u16::from_be_bytes([bytes[28], bytes[29]]) as u32;
+        let new_checksum = header_checksum(x, y, flags);
 
         // Compare the checksums
         if old_checksum != new_checksum {
             return Err(ChunkError::ChecksumFail);
         }
 
+        // Images too large to address on this target can't be read on it
+        let x = usize::try_from(x).map_err(|_| ChunkError::DimensionMismatch)?;
+        let y = usize::try_from(y).map_err(|_| ChunkError::DimensionMismatch)?;
+
         Ok(Self {
             x,
             y,
             checksum: new_checksum,
+            version,
+            alpha: flags & ALPHA_FLAG != 0,
+            compressed: flags & RLE_FLAG != 0,
+            algorithm: algorithm(flags),
         })
     }
 }
 
+// Parses the bytes as [HEADER, X (u32), Y (u32), CHECKSUM (u32)]
+//                        10b      4b       4b        4b
+/// Those builds serialized the `usize` dimensions as is, so they predate the flags, the
+/// version and CRC-32. The header gets the current layout when the file is written again.
+fn parse_32bit(bytes: &[u8]) -> Result<Header, ChunkError> {
+    if bytes[0..10] != CIEBIIFILE::MAGIC_BYTES {
+        return Err(ChunkError::IllegalHeader);
+    }
+
+    let old_checksum = u32::from_be_bytes(bytes[18..22].try_into()?);
+    if old_checksum != ChecksumAlgorithm::Ciebii.checksum(&bytes[10..18]) as u32 {
+        return Err(ChunkError::ChecksumFail);
+    }
+
+    let x = u32::from_be_bytes(bytes[10..14].try_into()?) as usize;
+    let y = u32::from_be_bytes(bytes[14..18].try_into()?) as usize;
+
+    Ok(Header::new(x, y).checksummed(ChecksumAlgorithm::Ciebii))
+}
+
 #[cfg(test)]
 mod header_tests {
     use super::*;
 
+    // Checksummed the legacy way, so it's laid out just like before there were any flags
     fn create_header() -> Header {
-        Header::new(20, 20)
+        Header::new(20, 20).checksummed(ChecksumAlgorithm::Ciebii)
     }
 
     #[test]
@@ -204,4 +434,180 @@ mod header_tests {
         assert_eq!(header.y, 20);
         assert_eq!(header.dimensions(), (20, 20));
     }
+
+    #[test]
+    fn builder() {
+        let header = Header::builder()
+            .width(20)
+            .height(20)
+            .checksum(ChecksumAlgorithm::Ciebii)
+            .build()
+            .unwrap();
+
+        assert_eq!(header, create_header());
+        assert_eq!(Header::try_from(header.as_bytes()).unwrap(), header);
+    }
+
+    #[test]
+    fn builder_missing_dimension() {
+        if let ChunkError::DimensionMismatch = Header::builder().width(20).build().unwrap_err() {
+        } else {
+            panic!()
+        }
+    }
+
+    #[test]
+    fn builder_too_large() {
+        let err = Header::builder()
+            .width(usize::MAX / 2)
+            .height(3)
+            .build()
+            .unwrap_err();
+
+        if let ChunkError::DimensionMismatch = err {
+        } else {
+            panic!()
+        }
+    }
+
+    #[test]
+    fn alpha_flag() {
+        let header = Header::builder()
+            .width(20)
+            .height(20)
+            .alpha(true)
+            .checksum(ChecksumAlgorithm::Ciebii)
+            .build()
+            .unwrap();
+        let bytes = header.as_bytes();
+
+        assert_eq!(bytes[26], 1);
+        assert_ne!(header.checksum(), create_header().checksum());
+        assert_eq!(header.chunk_len(), 6);
+        assert_eq!(Header::try_from(bytes).unwrap(), header);
+    }
+
+    #[test]
+    fn alpha_flag_is_checksummed() {
+        let mut bytes = create_header().as_bytes();
+        bytes[26] = 1;
+
+        if let ChunkError::ChecksumFail = Header::try_from(bytes).unwrap_err() {
+        } else {
+            panic!()
+        }
+    }
+
+    #[test]
+    fn compressed_flag() {
+        let header = Header::builder()
+            .width(20)
+            .height(20)
+            .compressed(true)
+            .checksum(ChecksumAlgorithm::Ciebii)
+            .build()
+            .unwrap();
+        let bytes = header.as_bytes();
+
+        assert_eq!(bytes[26], 2);
+        assert!(header.is_compressed());
+        assert_eq!(Header::try_from(bytes).unwrap(), header);
+        assert_eq!(header.compressed(false), create_header());
+    }
+
+    #[test]
+    fn unknown_flag() {
+        let mut bytes = create_header().as_bytes();
+        bytes[26] = 8;
+
+        if let ChunkError::IllegalHeader = Header::try_from(bytes).unwrap_err() {
+        } else {
+            panic!()
+        }
+    }
+
+    #[test]
+    fn crc_flag() {
+        let header = Header::new(20, 20);
+        let bytes = header.as_bytes();
+
+        assert_eq!(bytes[26], 4);
+        assert_eq!(header.checksum_algorithm(), ChecksumAlgorithm::Crc32);
+        assert_eq!(
+            bytes[28..],
+            (crc32fast::hash(&[0, 0, 0, 0, 0, 0, 0, 20, 0, 0, 0, 0, 0, 0, 0, 20, 4]) as u16)
+                .to_be_bytes()
+        );
+        assert_eq!(Header::try_from(bytes).unwrap(), header);
+        assert_eq!(header.checksummed(ChecksumAlgorithm::Ciebii), create_header());
+    }
+
+    // The header a 32-bit build wrote for a 20x20 image
+    fn header_32bit() -> Vec<u8> {
+        let dimensions = [0, 0, 0, 20, 0, 0, 0, 20];
+        let checksum = ChecksumAlgorithm::Ciebii.checksum(&dimensions) as u32;
+
+        [&CIEBIIFILE::MAGIC_BYTES[..], &dimensions, &checksum.to_be_bytes()].concat()
+    }
+
+    #[test]
+    fn header_from_32bit_target() {
+        let bytes = header_32bit();
+        assert_eq!(bytes.len(), Header::LEN_32BIT);
+
+        let header = Header::try_from(bytes).unwrap();
+
+        assert_eq!(header, create_header());
+        assert_eq!(header.as_bytes(), create_header().as_bytes());
+    }
+
+    #[test]
+    fn header_from_32bit_target_checksum() {
+        let mut bytes = header_32bit();
+        bytes[13] = 21;
+
+        if let ChunkError::ChecksumFail = Header::try_from(bytes).unwrap_err() {
+        } else {
+            panic!()
+        }
+    }
+
+    #[test]
+    fn crc_flag_is_checksummed() {
+        let mut bytes = Header::new(20, 20).as_bytes();
+        bytes[26] = 0;
+
+        if let ChunkError::ChecksumFail = Header::try_from(bytes).unwrap_err() {
+        } else {
+            panic!()
+        }
+    }
+
+    #[test]
+    fn version_byte() {
+        let mut bytes = create_header().as_bytes();
+        assert_eq!(bytes[27], Header::VERSION);
+        assert_eq!(Header::try_from(bytes.clone()).unwrap().version(), Header::VERSION);
+
+        bytes[27] = Header::VERSION + 1;
+        if let ChunkError::IllegalHeader = Header::try_from(bytes).unwrap_err() {
+        } else {
+            panic!()
+        }
+    }
+
+    #[test]
+    fn builder_unknown_version() {
+        let err = Header::builder()
+            .width(1)
+            .height(1)
+            .version(1)
+            .build()
+            .unwrap_err();
+
+        if let ChunkError::IllegalHeader = err {
+        } else {
+            panic!()
+        }
+    }
 }
@@ -0,0 +1,92 @@
+use super::chunk::Chunk;
+
+/// The number of pixels at every level of each channel.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Histogram {
+    /// Pixels per red level
+    pub red: [u32; 256],
+
+    /// Pixels per green level
+    pub green: [u32; 256],
+
+    /// Pixels per blue level
+    pub blue: [u32; 256],
+
+    /// Pixels per luminance level, weighted like Rec. 709
+    pub luminance: [u32; 256],
+}
+
+impl Histogram {
+    /// Counts the levels of every chunk, usually a frame of a file.
+    /// ```
+    /// use ciebii_lib::{chunk::Chunk, histogram::Histogram};
+    /// let histogram = Histogram::new(&[Chunk::new(255, 0, 0), Chunk::new(255, 0, 0)]);
+    /// assert_eq!(histogram.red[255], 2);
+    /// assert_eq!(histogram.luminance[54], 2);
+    /// ```
+    pub fn new(chunks: &[Chunk]) -> Self {
+        let mut histogram = Self {
+            red: [0; 256],
+            green: [0; 256],
+            blue: [0; 256],
+            luminance: [0; 256],
+        };
+
+        for chunk in chunks {
+            let (r, g, b) = chunk.rgb().color();
+
+            histogram.red[r as usize] += 1;
+            histogram.green[g as usize] += 1;
+            histogram.blue[b as usize] += 1;
+            histogram.luminance[luminance(r, g, b) as usize] += 1;
+        }
+
+        histogram
+    }
+
+    /// Returns the highest count of any level in any channel, handy for scaling a plot
+    pub fn peak(&self) -> u32 {
+        [&self.red, &self.green, &self.blue, &self.luminance]
+            .iter()
+            .flat_map(|channel| channel.iter())
+            .copied()
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+/// Returns the luminance of a color, from 0 to 255.
+pub fn luminance(r: u8, g: u8, b: u8) -> u8 {
+    (0.2126 * r as f32 + 0.7152 * g as f32 + 0.0722 * b as f32).round() as u8
+}
+
+#[cfg(test)]
+mod histogram_tests {
+    use super::*;
+
+    #[test]
+    fn counts_levels() {
+        let chunks = [Chunk::new(0, 128, 255), Chunk::new(0, 0, 255), Chunk::new(10, 0, 0)];
+        let histogram = Histogram::new(&chunks);
+
+        assert_eq!(histogram.red[0], 2);
+        assert_eq!(histogram.red[10], 1);
+        assert_eq!(histogram.green[0], 2);
+        assert_eq!(histogram.green[128], 1);
+        assert_eq!(histogram.blue[255], 2);
+        assert_eq!(histogram.luminance.iter().sum::<u32>(), 3);
+        assert_eq!(histogram.peak(), 2);
+    }
+
+    #[test]
+    fn luminance_extremes() {
+        assert_eq!(luminance(0, 0, 0), 0);
+        assert_eq!(luminance(255, 255, 255), 255);
+        assert!(luminance(0, 255, 0) > luminance(255, 0, 0));
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(Histogram::new(&[]).peak(), 0);
+    }
+}
@@ -1,12 +1,23 @@
 use std::{
-    fs::{self, OpenOptions},
-    io::{Read, Write},
+    fs::{self, File, OpenOptions},
+    io::{self, BufReader, Read, Seek, SeekFrom, Write},
     path::Path,
 };
 
-use anyhow::{Context, Error};
+use super::{
+    chunk::Chunk,
+    cursor::{self, Cursor},
+    error::{ChunkError, CiebiiError},
+    file::CIEBIIFILE,
+    header::Header,
+    palette::Palette,
+    section::Section,
+    stream::truncated,
+    writer::WriteOptions,
+};
 
-use super::file::CIEBIIFILE;
+/// How many chunks a `Decoder` reads from its reader at once
+const DECODE_BATCH: usize = 4096;
 
 
 /// Tries to create a `CIEBIIFILE` from `path`
@@ -20,28 +31,308 @@ use super::file::CIEBIIFILE;
 /// let file = read_file(&path);
 /// ```
 /// 
-pub fn read_file(path: &Path) -> Result<CIEBIIFILE, Error> {
+pub fn read_file(path: &Path) -> Result<CIEBIIFILE, CiebiiError> {
+    let failed = |source| CiebiiError::Io {
+        path: path.to_path_buf(),
+        source,
+    };
 
     // try to open the file
-    let mut file = OpenOptions::new()
-        .read(true)
-        .open(path)
-        .with_context(|| format!("Failed to open file '{:?}'", path))?;
+    let mut file = OpenOptions::new().read(true).open(path).map_err(failed)?;
 
     // Get the metadata for the file length
-    let metadata = fs::metadata(path)?;
+    let metadata = fs::metadata(path).map_err(failed)?;
 
-    let mut vec = vec![0; metadata.len() as usize];
+    let mut vec = Vec::with_capacity(metadata.len() as usize);
 
     // read the file into a vec
-    file.read(&mut vec)?;
+    file.read_to_end(&mut vec).map_err(failed)?;
+
+    CIEBIIFILE::try_from(&vec[..]).map_err(|err| decode_error(path, &vec, err))
+}
+
+/// Turns a decode error into a diagnostic pointing at the offending bytes
+#[cfg(feature = "diagnostics")]
+fn decode_error(path: &Path, bytes: &[u8], err: ChunkError) -> CiebiiError {
+    CiebiiError::Diagnostic {
+        path: path.to_path_buf(),
+        source: Box::new(crate::diagnostic::diagnose(bytes, err)),
+    }
+}
+
+#[cfg(not(feature = "diagnostics"))]
+fn decode_error(path: &Path, _bytes: &[u8], err: ChunkError) -> CiebiiError {
+    CiebiiError::at(path, err)
+}
+
+/// Decodes the chunks of a ciebii file from any reader one at a time, as they're asked for.
+/// Only a small batch of bytes is read ahead, so huge images can be processed without holding
+/// the file, or all of its chunks, in memory. Sections after the pixel data are left unread.
+///
+/// Decoding stops at the first error. A file that ends early, or whose last run goes past the
+/// end of the image, fails with `DimensionMismatch` once every chunk it has was handed out.
+/// ```
+/// use ciebii_lib::{chunk::Chunk, file::CIEBIIFILE, io::Decoder};
+/// let file = CIEBIIFILE::try_from_chunks(2, 1, vec![Chunk::new(1, 2, 3); 2]).unwrap();
+/// let bytes = file.as_bytes();
+///
+/// let decoder = Decoder::new(&bytes[..]).unwrap();
+/// assert_eq!(decoder.dimensions(), (2, 1));
+///
+/// let chunks: Vec<Chunk> = decoder.collect::<Result<_, _>>().unwrap();
+/// assert_eq!(&chunks, file.chunks());
+/// ```
+pub struct Decoder<R: Read> {
+    inner: R,
+    header: Header,
+
+    // How many chunks haven't been handed out yet
+    remaining: usize,
+
+    // The batch read last, and how far into it decoding got
+    batch: Cursor<Vec<u8>>,
+
+    // The chunk of the run decoded last in compressed files, and how often it's still repeated
+    run: Option<(Chunk, usize)>,
+}
+
+impl Decoder<File> {
+    /// Opens the file at `path` for decoding, reading just its header.
+    ///
+    /// ```no_run
+    /// use ciebii_lib::io::Decoder;
+    /// use std::path::Path;
+    /// let decoder = Decoder::open(Path::new("my_file.cib"));
+    /// ```
+    pub fn open(path: &Path) -> Result<Self, CiebiiError> {
+        let file = File::open(path).map_err(|source| CiebiiError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+
+        Decoder::new(file).map_err(|err| CiebiiError::at(path, err))
+    }
+}
+
+impl<R: Read> Decoder<R> {
+    /// Reads and checks the header at the start of the file
+    pub fn new(mut inner: R) -> Result<Self, ChunkError> {
+        let mut header = vec![0; Header::LEN];
+        inner
+            .read_exact(&mut header)
+            .map_err(|err| truncated(err, ChunkError::InvalidLen))?;
+
+        let header = Header::try_from(header)?;
+        let (x, y) = header.dimensions();
+
+        Ok(Self {
+            inner,
+            header,
+            remaining: x.checked_mul(y).ok_or(ChunkError::DimensionMismatch)?,
+            batch: Cursor::new(Vec::new()),
+            run: None,
+        })
+    }
+
+    /// Returns the header of the file
+    pub fn header(&self) -> &Header {
+        &self.header
+    }
+
+    /// Returns the dimensions of the image
+    pub fn dimensions(&self) -> (usize, usize) {
+        self.header.dimensions()
+    }
+
+    /// Returns how many chunks are left to decode
+    pub fn remaining(&self) -> usize {
+        self.remaining
+    }
+
+    /// Returns the reader. Once every chunk of an uncompressed file was decoded it's right where
+    /// the sections start, before that, or with runs, it may have been read ahead.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    /// Reads the next batch of bytes, as many as there are left up to `DECODE_BATCH` chunks
+    fn fill(&mut self) -> Result<(), ChunkError> {
+        let want = self.remaining.min(DECODE_BATCH) * cursor::record_len(&self.header);
+
+        let mut bytes = Vec::with_capacity(want);
+        self.inner.by_ref().take(want as u64).read_to_end(&mut bytes)?;
+        self.batch = Cursor::new(bytes);
+
+        Ok(())
+    }
+}
+
+impl<R: Read> Iterator for Decoder<R> {
+    type Item = Result<Chunk, ChunkError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        // The rest of a run
+        if let Some((chunk, repeats)) = self.run.as_mut().filter(|(_, repeats)| *repeats > 0) {
+            *repeats -= 1;
+            self.remaining -= 1;
+            return Some(Ok(*chunk));
+        }
+
+        // Batches end between records, unless the file ended first
+        if self.batch.is_empty() {
+            if let Err(err) = self.fill() {
+                self.remaining = 0;
+                return Some(Err(err));
+            }
+        }
+
+        // A run repeats its chunk, but not past the end of the image
+        let record = self.batch.record(&self.header).and_then(|(chunk, length)| {
+            match length <= self.remaining {
+                true => Ok((chunk, length)),
+                false => Err(ChunkError::DimensionMismatch),
+            }
+        });
+
+        match record {
+            Ok((chunk, length)) => {
+                self.run = Some((chunk, length - 1));
+                self.remaining -= 1;
+
+                Some(Ok(chunk))
+            }
+            Err(err) => {
+                self.remaining = 0;
+
+                Some(Err(err))
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.remaining))
+    }
+}
+
+/// Reads only the embedded thumbnail of the file at `path`, seeking past the pixel data and
+/// any other sections. Listing many files this way is much cheaper than `read_file`.
+/// Returns `None` if the file has no thumbnail.
+///
+/// ```no_run
+/// use ciebii_lib::io::read_thumbnail;
+/// use std::path::Path;
+/// let thumbnail = read_thumbnail(Path::new("my_file.cib"));
+/// ```
+pub fn read_thumbnail(path: &Path) -> Result<Option<CIEBIIFILE>, CiebiiError> {
+    find_thumbnail(path).map_err(|err| CiebiiError::at(path, err))
+}
+
+fn find_thumbnail(path: &Path) -> Result<Option<CIEBIIFILE>, ChunkError> {
+    let mut file = File::open(path)?;
+    let len = file.metadata()?.len();
+
+    let mut header = vec![0; Header::LEN];
+    file.read_exact(&mut header).map_err(|_| ChunkError::InvalidLen)?;
+    let header = Header::try_from(header)?;
+    let (x, y) = header.dimensions();
+
+    // The sections start right after one chunk per pixel, or the runs adding up to them
+    let mut offset = match header.is_compressed() {
+        true => {
+            let pixels = x.checked_mul(y).ok_or(ChunkError::DimensionMismatch)?;
+            skip_runs(&mut file, pixels, header.chunk_len())?
+        }
+        false => (x as u64)
+            .checked_mul(y as u64)
+            .and_then(|pixels| pixels.checked_mul(header.chunk_len() as u64))
+            .and_then(|pixels| pixels.checked_add(Header::LEN as u64))
+            .filter(|&end| end <= len)
+            .ok_or(ChunkError::DimensionMismatch)?,
+    };
+
+    while offset < len {
+        file.seek(SeekFrom::Start(offset))?;
+
+        let mut head = vec![0; 8];
+        file.read_exact(&mut head).map_err(|_| ChunkError::InvalidLen)?;
+        let section_len = u32::from_be_bytes(head[4..8].try_into()?) as u64;
+
+        // The length is only trusted once the section is known to fit in the file
+        let end = offset + 8 + section_len + 2;
+        if end > len {
+            return Err(ChunkError::InvalidLen);
+        }
+
+        if head[..4] == Section::THUMBNAIL {
+            // Read the payload and checksum, letting `Section::parse` verify them
+            let mut bytes = head;
+            bytes.resize(8 + section_len as usize + 2, 0);
+            file.read_exact(&mut bytes[8..]).map_err(|_| ChunkError::InvalidLen)?;
+
+            let (section, _) = Section::parse(&bytes, header.checksum_algorithm())?;
+
+            return Ok(Some(CIEBIIFILE::try_from(section.payload().to_vec())?));
+        }
+
+        offset = end;
+    }
+
+    Ok(None)
+}
+
+/// Reads past the runs after the header of `file` until they add up to `count` chunks of
+/// `chunk_len` bytes each. Returns the offset they end at.
+fn skip_runs(file: &mut File, count: usize, chunk_len: usize) -> Result<u64, ChunkError> {
+    let mut reader = BufReader::new(file);
+    let mut run = vec![0; 1 + chunk_len];
 
-    match CIEBIIFILE::try_from(vec) {
-        Ok(file) => Ok(file),
-        Err(err) => Err(err.into()),
+    let (mut chunks, mut offset) = (0, Header::LEN as u64);
+    while chunks < count {
+        reader
+            .read_exact(&mut run)
+            .map_err(|err| truncated(err, ChunkError::DimensionMismatch))?;
+
+        chunks += run[0] as usize + 1;
+        offset += run.len() as u64;
+    }
+
+    if chunks > count {
+        return Err(ChunkError::DimensionMismatch);
     }
+
+    Ok(offset)
 }
 
+/// Reads the `.cibpal` palette at `path`.
+///
+/// ```no_run
+/// use ciebii_lib::io::read_palette;
+/// use std::path::Path;
+/// let palette = read_palette(Path::new("sprites.cibpal"));
+/// ```
+pub fn read_palette(path: &Path) -> Result<Palette, CiebiiError> {
+    let bytes = fs::read(path).map_err(|source| CiebiiError::Io {
+        path: path.to_path_buf(),
+        source,
+    })?;
+
+    Palette::try_from(&bytes[..]).map_err(|err| CiebiiError::at(path, err))
+}
+
+/// Writes `palette` to `path` as a `.cibpal` file, replacing it atomically like
+/// `write_file_atomic`.
+pub fn write_palette(path: &Path, palette: &Palette) -> Result<(), CiebiiError> {
+    write_atomic(path, |file| {
+        file.write_all(&palette.as_bytes()).map_err(|source| CiebiiError::Io {
+            path: path.to_path_buf(),
+            source,
+        })
+    })
+}
 
 /// Attemps to write a `CIEBIIFILE` to a file.
 /// 
@@ -54,15 +345,95 @@ pub fn read_file(path: &Path) -> Result<CIEBIIFILE, Error> {
 /// let file = write_file(&path, &ciebiifile);
 /// ```
 /// 
-pub fn write_file(path: &Path, ciebiifile: &CIEBIIFILE) -> anyhow::Result<()> {
+pub fn write_file(path: &Path, ciebiifile: &CIEBIIFILE) -> Result<(), CiebiiError> {
+    let failed = |source| CiebiiError::Io {
+        path: path.to_path_buf(),
+        source,
+    };
 
     // open file
-    let mut file = OpenOptions::new().write(true).append(true).open(path)?;
+    let mut file = OpenOptions::new().append(true).open(path).map_err(failed)?;
 
     // try to write to the file
-    file.write_all(&ciebiifile.as_bytes())?;
+    ciebiifile.write_into(&mut file).map_err(failed)
+}
 
-    Ok(())
+/// Writes a `CIEBIIFILE` to a temporary file next to `path` and renames it into place,
+/// so `path` is left untouched if anything goes wrong.
+///
+/// ```no_run
+/// use ciebii_lib::io::write_file_atomic;
+/// use ciebii_lib::file::CIEBIIFILE;
+/// use std::path::Path;
+/// let path = Path::new("my_file.cib");
+/// let ciebiifile = CIEBIIFILE::new(2, 2);
+/// let file = write_file_atomic(&path, &ciebiifile);
+/// ```
+///
+pub fn write_file_atomic(path: &Path, ciebiifile: &CIEBIIFILE) -> Result<(), CiebiiError> {
+    write_atomic(path, |file| {
+        ciebiifile.write_into(file).map_err(|source| CiebiiError::Io {
+            path: path.to_path_buf(),
+            source,
+        })
+    })
+}
+
+/// Writes a `CIEBIIFILE` to `path` in the flavor `options` ask for, like with a footer, through
+/// a temporary file like `write_file_atomic`.
+///
+/// ```no_run
+/// use ciebii_lib::{file::CIEBIIFILE, io::write_file_with, writer::WriteOptions};
+/// use std::path::Path;
+/// let ciebiifile = CIEBIIFILE::new(2, 2);
+/// let options = WriteOptions::default().footer(true);
+/// let file = write_file_with(Path::new("my_file.cib"), &ciebiifile, &options);
+/// ```
+pub fn write_file_with(
+    path: &Path,
+    ciebiifile: &CIEBIIFILE,
+    options: &WriteOptions,
+) -> Result<(), CiebiiError> {
+    write_atomic(path, |file| {
+        ciebiifile
+            .write_into_with(file, options)
+            .map_err(|err| CiebiiError::at(path, err))
+    })
+}
+
+/// Lets `write` fill a temporary file next to `path`, then renames it into place.
+/// If `write` fails the temporary file is removed and `path` is left untouched.
+/// Its error is passed on as is, so it can be any error a `CiebiiError` converts into.
+pub fn write_atomic<E: From<CiebiiError>>(
+    path: &Path,
+    write: impl FnOnce(&mut File) -> Result<(), E>,
+) -> Result<(), E> {
+    let failed = |source| CiebiiError::Io {
+        path: path.to_path_buf(),
+        source,
+    };
+
+    let name = path.file_name().ok_or_else(|| {
+        failed(io::Error::new(io::ErrorKind::InvalidInput, "not a file path"))
+    })?;
+
+    // Same directory, so the rename can't cross file systems
+    let temp = path.with_file_name(format!(
+        ".{}.{}.tmp",
+        name.to_string_lossy(),
+        std::process::id()
+    ));
+
+    let result = File::create(&temp)
+        .map_err(|err| E::from(failed(err)))
+        .and_then(|mut file| write(&mut file))
+        .and_then(|_| fs::rename(&temp, path).map_err(|err| E::from(failed(err))));
+
+    if result.is_err() {
+        let _ = fs::remove_file(&temp);
+    }
+
+    result
 }
 
 #[cfg(test)]
@@ -74,7 +445,15 @@ mod file_tests {
 
     use tempdir::TempDir;
 
-    use crate::{chunk::Chunk, file::CIEBIIFILE};
+    use crate::{
+        checksum::ChecksumAlgorithm,
+        chunk::Chunk,
+        error::{ChunkError, CiebiiError},
+        file::CIEBIIFILE,
+        section::Section,
+    };
+
+    use super::{read_file, read_thumbnail, write_file_atomic, Decoder};
 
     fn test_file() -> CIEBIIFILE {
         let chunks = vec![
@@ -93,7 +472,8 @@ mod file_tests {
         let file = dir.path().join("testfile.shf");
         let mut f = File::create(&file).unwrap();
 
-        let test_file = test_file();
+        let mut test_file = test_file();
+        test_file.set_checksum_algorithm(ChecksumAlgorithm::Ciebii);
 
         assert!(f.write_all(&test_file.as_bytes()).is_ok());
 
@@ -134,4 +514,187 @@ mod file_tests {
             ]
         );
     }
+
+    #[test]
+    fn atomic_write() {
+        let dir = TempDir::new("tests").unwrap();
+        let path = dir.path().join("out.cib");
+
+        std::fs::write(&path, b"existing").unwrap();
+
+        // A failed write leaves the existing file alone
+        assert!(write_file_atomic(&dir.path().join("missing/out.cib"), &test_file()).is_err());
+        assert_eq!(std::fs::read(&path).unwrap(), b"existing");
+
+        write_file_atomic(&path, &test_file()).unwrap();
+
+        assert_eq!(read_file(&path).unwrap().chunks(), test_file().chunks());
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 1);
+    }
+
+    #[test]
+    fn missing_file() {
+        let dir = TempDir::new("tests").unwrap();
+
+        match read_file(&dir.path().join("missing.cib")).unwrap_err() {
+            CiebiiError::Io { source, .. } => {
+                assert_eq!(source.kind(), std::io::ErrorKind::NotFound)
+            }
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn corrupt_file() {
+        let dir = TempDir::new("tests").unwrap();
+        let path = dir.path().join("corrupt.cib");
+
+        let mut bytes = test_file().as_bytes();
+        bytes[30] ^= 1;
+        std::fs::write(&path, bytes).unwrap();
+
+        let err = read_file(&path).unwrap_err();
+
+        if let Some(ChunkError::ChecksumFail) = err.chunk_error() {
+        } else {
+            panic!()
+        }
+    }
+
+    #[test]
+    fn thumbnail_only() {
+        let dir = TempDir::new("tests").unwrap();
+        let path = dir.path().join("thumbnail.cib");
+
+        write_file_atomic(&path, &test_file()).unwrap();
+        assert!(read_thumbnail(&path).unwrap().is_none());
+
+        // The thumbnail comes after another section, which has to be skipped
+        let mut file = test_file();
+        file.push_frame(test_file().chunks().clone(), 100).unwrap();
+        file.set_metadata(crate::section::Section::EXIF, vec![1, 2, 3]);
+        let thumbnail = CIEBIIFILE::try_from_chunks(1, 1, vec![Chunk::new(1, 2, 3)]).unwrap();
+        file.set_thumbnail(&thumbnail);
+        write_file_atomic(&path, &file).unwrap();
+
+        assert_eq!(read_thumbnail(&path).unwrap(), Some(thumbnail.clone()));
+
+        // Runs are skipped one by one
+        file.set_compressed(true);
+        write_file_atomic(&path, &file).unwrap();
+
+        assert_eq!(read_thumbnail(&path).unwrap(), Some(thumbnail));
+    }
+
+    #[test]
+    fn decoder() {
+        let dir = TempDir::new("tests").unwrap();
+        let path = dir.path().join("decoder.cib");
+
+        // More chunks than a single batch, with a section after them
+        let chunks: Vec<Chunk> = (0..5000).map(|i| Chunk::with_alpha(i as u8, 0, 0, 7)).collect();
+        let mut file = CIEBIIFILE::try_from_chunks(100, 50, chunks).unwrap();
+        file.set_metadata(crate::section::Section::EXIF, vec![1, 2, 3]);
+        write_file_atomic(&path, &file).unwrap();
+
+        let mut decoder = Decoder::open(&path).unwrap();
+        assert_eq!(decoder.dimensions(), (100, 50));
+
+        let decoded: Vec<Chunk> = decoder.by_ref().collect::<Result<_, _>>().unwrap();
+        assert_eq!(&decoded, file.chunks());
+        assert_eq!(decoder.remaining(), 0);
+
+        // The reader stops right where the sections start
+        let mut rest = Vec::new();
+        decoder.into_inner().read_to_end(&mut rest).unwrap();
+        assert_eq!(rest, file.sections()[0].as_bytes(file.checksum_algorithm()).unwrap());
+    }
+
+    #[test]
+    fn decoder_truncated() {
+        let bytes = test_file().as_bytes();
+        let decoder = Decoder::new(&bytes[..bytes.len() - 3]).unwrap();
+        let results: Vec<_> = decoder.collect();
+
+        assert_eq!(results.len(), 4);
+        assert!(results[..3].iter().all(Result::is_ok));
+
+        if let Err(ChunkError::DimensionMismatch) = results[3] {
+        } else {
+            panic!()
+        }
+    }
+
+    #[test]
+    fn decoder_checksum_fail() {
+        let mut bytes = test_file().as_bytes();
+        bytes[35] ^= 1;
+
+        let mut decoder = Decoder::new(&bytes[..]).unwrap();
+        assert!(decoder.next().unwrap().is_ok());
+
+        if let Some(Err(ChunkError::ChecksumFail)) = decoder.next() {
+        } else {
+            panic!()
+        }
+
+        assert!(decoder.next().is_none());
+    }
+
+    #[test]
+    fn decoder_runs() {
+        let mut chunks = vec![Chunk::new(1, 2, 3); 300];
+        chunks.extend((0..5000).map(|i| Chunk::new(i as u8, 0, 0)));
+        let mut file = CIEBIIFILE::try_from_chunks(53, 100, chunks).unwrap();
+        file.set_compressed(true);
+
+        let bytes = file.as_bytes();
+        let decoded: Result<Vec<Chunk>, _> = Decoder::new(&bytes[..]).unwrap().collect();
+        assert_eq!(&decoded.unwrap(), file.chunks());
+
+        // The last run goes one chunk past the end of the image
+        let mut bytes = file.as_bytes();
+        let last = bytes.len() - Chunk::LEN - 1;
+        bytes[last] = 1;
+
+        let results: Vec<_> = Decoder::new(&bytes[..]).unwrap().collect();
+        assert_eq!(results.len(), 5300);
+        if let Err(ChunkError::DimensionMismatch) = results[5299] {
+        } else {
+            panic!()
+        }
+    }
+
+    #[test]
+    fn thumbnail_truncated() {
+        let dir = TempDir::new("tests").unwrap();
+        let path = dir.path().join("truncated.cib");
+
+        let bytes = test_file().as_bytes();
+        std::fs::write(&path, &bytes[..bytes.len() - 1]).unwrap();
+
+        assert!(read_thumbnail(&path).is_err());
+    }
+
+    #[test]
+    fn thumbnail_oversized_length() {
+        let dir = TempDir::new("tests").unwrap();
+        let path = dir.path().join("oversized.cib");
+
+        // Sections before the thumbnail are skipped by their length too
+        for tag in [Section::THUMBNAIL, Section::EXIF] {
+            let mut bytes = test_file().as_bytes();
+            bytes.extend(tag);
+            bytes.extend(u32::MAX.to_be_bytes());
+            bytes.extend([0, 0]);
+            std::fs::write(&path, &bytes).unwrap();
+
+            if let Err(CiebiiError::Corrupt { source: ChunkError::InvalidLen, .. }) =
+                read_thumbnail(&path)
+            {
+            } else {
+                panic!()
+            }
+        }
+    }
 }
@@ -1,7 +1,34 @@
+pub mod anim;
 pub mod checksum;
 pub mod chunk;
+pub mod compat;
+pub mod crop;
+mod cursor;
+#[cfg(feature = "diagnostics")]
+pub mod diagnostic;
+pub mod draw;
 pub mod error;
+#[cfg(feature = "ffi")]
+pub mod ffi;
 pub mod file;
+pub mod format;
 pub mod header;
+pub mod histogram;
 pub mod io;
+pub mod net;
+pub mod netpbm;
+pub mod palette;
+pub mod qoi;
+pub mod resize;
 pub mod rgb;
+pub mod rotate;
+pub mod section;
+pub mod steg;
+pub mod stream;
+pub mod text;
+pub mod thumbnail;
+pub mod vectors;
+pub mod visit;
+pub mod writer;
+
+pub use format::{sniff, FormatKind};
@@ -0,0 +1,290 @@
+use std::io::{self, Read, Write};
+
+use super::{
+    chunk::{self, Chunk},
+    cursor::Cursor,
+    error::ChunkError,
+    file::CIEBIIFILE,
+    header::Header,
+};
+
+// A stream starts with a regular 30 byte ciebii header, sent once.
+// Every frame follows as [LENGTH (u32), CHUNKS], the length being the size of the chunks in bytes.
+
+/// The most bytes the chunks of a frame may take. The receiver only trusts the header of a
+/// stream this far, so a peer can't make it run out of memory.
+pub const MAX_FRAME_LEN: usize = 1 << 30;
+
+/// Returns how many bytes the chunks of a frame of the stream `header` starts take, or `None`
+/// if that's over `MAX_FRAME_LEN`.
+fn frame_len(header: &Header) -> Option<usize> {
+    let (x, y) = header.dimensions();
+
+    x.checked_mul(y)
+        .and_then(|pixels| pixels.checked_mul(header.chunk_len()))
+        .filter(|len| *len <= MAX_FRAME_LEN)
+}
+
+/// Sends frames of a fixed size over any writer, usually a `TcpStream`.
+/// ```no_run
+/// use ciebii_lib::{file::CIEBIIFILE, net::FrameSender};
+/// use std::net::TcpListener;
+/// let (stream, _) = TcpListener::bind("127.0.0.1:7878").unwrap().accept().unwrap();
+/// let mut sender = FrameSender::new(stream, 2, 2).unwrap();
+/// sender.send(&CIEBIIFILE::new(2, 2)).unwrap();
+/// ```
+pub struct FrameSender<W: Write> {
+    inner: W,
+    header: Header,
+}
+
+impl<W: Write> FrameSender<W> {
+    /// Starts a stream of `x` by `y` frames by sending the header
+    pub fn new(inner: W, x: usize, y: usize) -> io::Result<Self> {
+        Self::with_alpha(inner, x, y, false)
+    }
+
+    /// Starts a stream of `x` by `y` frames whose chunks carry their alpha if `alpha` is set.
+    /// Streams without it drop the alpha, like files without an alpha channel.
+    pub fn with_alpha(mut inner: W, x: usize, y: usize, alpha: bool) -> io::Result<Self> {
+        let header = Header::with_alpha(x, y, alpha);
+        if frame_len(&header).is_none() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "the frames are too large to stream",
+            ));
+        }
+
+        inner.write_all(&header.as_bytes())?;
+        inner.flush()?;
+
+        Ok(Self { inner, header })
+    }
+
+    /// Sends the first frame of `file`, which has to match the dimensions of the stream
+    pub fn send(&mut self, file: &CIEBIIFILE) -> Result<(), ChunkError> {
+        if file.dimensions() != self.header.dimensions() {
+            return Err(ChunkError::DimensionMismatch);
+        }
+
+        self.send_chunks(file.chunks())
+    }
+
+    /// Sends one frame worth of chunks
+    pub fn send_chunks(&mut self, chunks: &[Chunk]) -> Result<(), ChunkError> {
+        let (x, y) = self.header.dimensions();
+        if chunks.len() != x * y {
+            return Err(ChunkError::DimensionMismatch);
+        }
+
+        let mut payload = Vec::new();
+        let (alpha, checksum) = (self.header.has_alpha(), self.header.checksum_algorithm());
+        chunk::encode_into(chunks, alpha, checksum, &mut payload);
+
+        self.inner.write_all(&(payload.len() as u32).to_be_bytes())?;
+        self.inner.write_all(&payload)?;
+        self.inner.flush()?;
+
+        Ok(())
+    }
+}
+
+/// Receives the frames sent by a `FrameSender`.
+/// ```no_run
+/// use ciebii_lib::net::FrameReceiver;
+/// use std::net::TcpStream;
+/// let mut receiver = FrameReceiver::new(TcpStream::connect("127.0.0.1:7878").unwrap()).unwrap();
+/// while let Some(frame) = receiver.next_frame().unwrap() {
+///     println!("{:?}", frame.dimensions());
+/// }
+/// ```
+pub struct FrameReceiver<R: Read> {
+    inner: R,
+    header: Header,
+}
+
+impl<R: Read> FrameReceiver<R> {
+    /// Reads the header at the start of the stream
+    pub fn new(mut inner: R) -> Result<Self, ChunkError> {
+        let mut header = vec![0; Header::LEN];
+        inner.read_exact(&mut header)?;
+        let header = Header::try_from(header)?;
+
+        // Frames are always sent chunk by chunk, never as runs
+        if header.is_compressed() {
+            return Err(ChunkError::IllegalHeader);
+        }
+        if frame_len(&header).is_none() {
+            return Err(ChunkError::DimensionMismatch);
+        }
+
+        Ok(Self { inner, header })
+    }
+
+    /// Returns the dimensions of every frame in the stream
+    pub fn dimensions(&self) -> (usize, usize) {
+        self.header.dimensions()
+    }
+
+    /// Returns whether the chunks of the stream carry their alpha
+    pub fn has_alpha(&self) -> bool {
+        self.header.has_alpha()
+    }
+
+    /// Waits for the next frame. Returns `None` once the sender closed the stream.
+    pub fn next_frame(&mut self) -> Result<Option<CIEBIIFILE>, ChunkError> {
+        self.next_frame_by_rows(|_, _| {})
+    }
+
+    /// Waits for the next frame like `next_frame`, handing every row to `row` as soon as it
+    /// arrived along with its index, so slow streams can be shown while they come in.
+    pub fn next_frame_by_rows(
+        &mut self,
+        mut row: impl FnMut(usize, &[Chunk]),
+    ) -> Result<Option<CIEBIIFILE>, ChunkError> {
+        let mut len = [0; 4];
+
+        // The stream may only end between frames
+        match self.inner.read_exact(&mut len) {
+            Ok(()) => {}
+            Err(err) if err.kind() == io::ErrorKind::UnexpectedEof => return Ok(None),
+            Err(err) => return Err(err.into()),
+        }
+
+        // `new` made sure the frames aren't too large
+        let (x, y) = self.dimensions();
+        if Some(u32::from_be_bytes(len) as usize) != frame_len(&self.header) {
+            return Err(ChunkError::InvalidLen);
+        }
+
+        // Nothing is reserved up front, the frame only grows as its rows arrive
+        let mut chunks = Vec::new();
+        let mut bytes = Vec::new();
+
+        for index in 0..y {
+            let row_len = x * self.header.chunk_len();
+
+            bytes.clear();
+            self.inner.by_ref().take(row_len as u64).read_to_end(&mut bytes)?;
+            if bytes.len() != row_len {
+                return Err(io::Error::from(io::ErrorKind::UnexpectedEof).into());
+            }
+
+            let start = chunks.len();
+            chunks.extend(Cursor::new(&bytes[..]).chunks(x, &self.header)?);
+
+            row(index, &chunks[start..]);
+        }
+
+        Ok(Some(CIEBIIFILE::try_from_chunks(x, y, chunks)?))
+    }
+}
+
+#[cfg(test)]
+mod net_tests {
+    use std::{
+        net::{TcpListener, TcpStream},
+        thread,
+    };
+
+    use super::*;
+
+    fn frame(color: u8) -> CIEBIIFILE {
+        CIEBIIFILE::try_from_chunks(2, 1, vec![Chunk::new(color, 0, 0); 2]).unwrap()
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut sender = FrameSender::new(Vec::new(), 2, 1).unwrap();
+        sender.send(&frame(1)).unwrap();
+        sender.send(&frame(2)).unwrap();
+
+        let mut receiver = FrameReceiver::new(&sender.inner[..]).unwrap();
+
+        assert_eq!(receiver.dimensions(), (2, 1));
+        assert_eq!(receiver.next_frame().unwrap(), Some(frame(1)));
+        assert_eq!(receiver.next_frame().unwrap(), Some(frame(2)));
+        assert_eq!(receiver.next_frame().unwrap(), None);
+    }
+
+    #[test]
+    fn rows_arrive_in_order() {
+        let chunks = vec![Chunk::new(1, 0, 0), Chunk::new(2, 0, 0), Chunk::new(3, 0, 0)];
+        let file = CIEBIIFILE::try_from_chunks(1, 3, chunks).unwrap();
+
+        let mut sender = FrameSender::new(Vec::new(), 1, 3).unwrap();
+        sender.send(&file).unwrap();
+
+        let mut rows = Vec::new();
+        let mut receiver = FrameReceiver::new(&sender.inner[..]).unwrap();
+        let received = receiver
+            .next_frame_by_rows(|index, row| rows.push((index, row[0].rgb().color().0)))
+            .unwrap();
+
+        assert_eq!(rows, [(0, 1), (1, 2), (2, 3)]);
+        assert_eq!(received, Some(file));
+    }
+
+    #[test]
+    fn alpha_round_trip() {
+        let chunks = vec![Chunk::with_alpha(1, 2, 3, 4), Chunk::new(5, 6, 7)];
+        let file = CIEBIIFILE::try_from_chunks(2, 1, chunks).unwrap();
+
+        let mut sender = FrameSender::with_alpha(Vec::new(), 2, 1, true).unwrap();
+        sender.send(&file).unwrap();
+
+        let mut receiver = FrameReceiver::new(&sender.inner[..]).unwrap();
+        assert!(receiver.has_alpha());
+        assert_eq!(receiver.next_frame().unwrap(), Some(file));
+    }
+
+    #[test]
+    fn frames_too_large() {
+        assert!(FrameSender::new(Vec::new(), 1 << 20, 1 << 20).is_err());
+
+        for (x, y) in [(1 << 20, 1 << 20), (1 << 62, 1 << 62)] {
+            let header = Header::new(x, y).as_bytes();
+
+            if let Err(ChunkError::DimensionMismatch) = FrameReceiver::new(&header[..]) {
+            } else {
+                panic!()
+            }
+        }
+    }
+
+    #[test]
+    fn wrong_dimensions() {
+        let mut sender = FrameSender::new(Vec::new(), 1, 1).unwrap();
+
+        assert!(sender.send(&frame(1)).is_err());
+    }
+
+    #[test]
+    fn truncated_frame() {
+        let mut sender = FrameSender::new(Vec::new(), 2, 1).unwrap();
+        sender.send(&frame(1)).unwrap();
+
+        let bytes = &sender.inner[..sender.inner.len() - 1];
+        let mut receiver = FrameReceiver::new(bytes).unwrap();
+
+        assert!(receiver.next_frame().is_err());
+    }
+
+    #[test]
+    fn over_tcp() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+
+        let sending = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut sender = FrameSender::new(stream, 2, 1).unwrap();
+            sender.send(&frame(3)).unwrap();
+        });
+
+        let mut receiver = FrameReceiver::new(TcpStream::connect(address).unwrap()).unwrap();
+        assert_eq!(receiver.next_frame().unwrap(), Some(frame(3)));
+
+        sending.join().unwrap();
+        assert_eq!(receiver.next_frame().unwrap(), None);
+    }
+}
@@ -0,0 +1,281 @@
+use super::{chunk, error::ChunkError, file::CIEBIIFILE};
+
+/// The Netpbm formats ciebii files can be written as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Netpbm {
+    /// Binary RGB (P6)
+    Ppm,
+
+    /// Binary grayscale (P5)
+    Pgm,
+
+    /// Arbitrary tuples (P7), written as RGB
+    Pam,
+}
+
+impl Netpbm {
+
+    /// Picks the format matching a file extension like `ppm`
+    pub fn from_extension(extension: &str) -> Option<Self> {
+        match extension.to_ascii_lowercase().as_str() {
+            "ppm" => Some(Self::Ppm),
+            "pgm" => Some(Self::Pgm),
+            "pam" => Some(Self::Pam),
+            _ => None,
+        }
+    }
+}
+
+/// Encodes the first frame of `file` in a Netpbm format with 8 bits per sample.
+/// ```
+/// use ciebii_lib::{chunk::Chunk, file::CIEBIIFILE, netpbm::{self, Netpbm}};
+/// let file = CIEBIIFILE::try_from_chunks(1, 1, vec![Chunk::new(255, 0, 0)]).unwrap();
+/// assert_eq!(netpbm::encode(&file, Netpbm::Ppm), b"P6\n1 1\n255\n\xff\x00\x00");
+/// ```
+pub fn encode(file: &CIEBIIFILE, format: Netpbm) -> Vec<u8> {
+    let (width, height) = file.dimensions();
+
+    let header = match format {
+        Netpbm::Ppm => format!("P6\n{width} {height}\n255\n"),
+        Netpbm::Pgm => format!("P5\n{width} {height}\n255\n"),
+        Netpbm::Pam => format!(
+            "P7\nWIDTH {width}\nHEIGHT {height}\nDEPTH 3\nMAXVAL 255\nTUPLTYPE RGB\nENDHDR\n"
+        ),
+    };
+
+    let mut bytes = header.into_bytes();
+
+    for chunk in file.chunks() {
+        let (r, g, b) = chunk.rgb().color();
+
+        match format {
+            Netpbm::Ppm | Netpbm::Pam => bytes.extend([r, g, b]),
+            Netpbm::Pgm => bytes.push(luma(r, g, b)),
+        }
+    }
+
+    bytes
+}
+
+/// Decodes a PPM, PGM or PAM image (binary or plain) into a ciebii file.
+/// Samples are scaled to 8 bits, and the alpha of PAM images is dropped.
+pub fn decode(bytes: &[u8]) -> Result<CIEBIIFILE, ChunkError> {
+    let mut reader = Reader { bytes, offset: 0 };
+
+    let magic = reader.token()?;
+
+    let (width, height, depth, maxval, plain) = match magic.as_str() {
+        "P2" | "P3" | "P5" | "P6" => {
+            let width = reader.number()?;
+            let height = reader.number()?;
+            let maxval = reader.number()?;
+
+            // A single whitespace character separates the header from binary data
+            reader.offset += 1;
+
+            let depth = if matches!(magic.as_str(), "P2" | "P5") { 1 } else { 3 };
+            (width, height, depth, maxval, matches!(magic.as_str(), "P2" | "P3"))
+        }
+        "P7" => {
+            let (mut width, mut height, mut depth, mut maxval) = (None, None, None, None);
+
+            // PAM headers are a list of keywords, ending with ENDHDR
+            loop {
+                match reader.token()?.as_str() {
+                    "WIDTH" => width = Some(reader.number()?),
+                    "HEIGHT" => height = Some(reader.number()?),
+                    "DEPTH" => depth = Some(reader.number()?),
+                    "MAXVAL" => maxval = Some(reader.number()?),
+                    "TUPLTYPE" => {
+                        reader.token()?;
+                    }
+                    "ENDHDR" => break,
+                    other => return Err(invalid(format!("unknown PAM header field '{other}'"))),
+                }
+            }
+            reader.offset += 1;
+
+            match (width, height, depth, maxval) {
+                (Some(width), Some(height), Some(depth), Some(maxval)) => {
+                    (width, height, depth, maxval, false)
+                }
+                _ => return Err(invalid("incomplete PAM header".to_owned())),
+            }
+        }
+        _ => return Err(invalid(format!("unsupported magic '{magic}'"))),
+    };
+
+    if maxval == 0 || maxval > 65535 {
+        return Err(invalid(format!("invalid maximum value {maxval}")));
+    }
+    if !(1..=4).contains(&depth) {
+        return Err(invalid(format!("unsupported depth {depth}")));
+    }
+
+    let pixels = width
+        .checked_mul(height)
+        .ok_or(ChunkError::DimensionMismatch)?;
+
+    // Every sample takes at least a byte, so the dimensions can't claim more than are left
+    let remaining = bytes.len().saturating_sub(reader.offset);
+    if pixels.checked_mul(depth).is_none_or(|samples| samples > remaining) {
+        return Err(invalid(format!("{width}x{height} is larger than the image data")));
+    }
+
+    let mut colors = Vec::with_capacity(pixels);
+    for _ in 0..pixels {
+        let mut tuple = [0; 4];
+        for sample in tuple.iter_mut().take(depth) {
+            let value = match plain {
+                true => reader.number()?,
+                false => reader.sample(maxval)?,
+            };
+
+            // Scale every sample to 8 bits
+            *sample = ((value * 255 + maxval / 2) / maxval).min(255) as u8;
+        }
+
+        // Depths 1 and 2 are grayscale, 3 and 4 color; the last channel of 2 and 4 is alpha
+        colors.push(match depth {
+            1 | 2 => [tuple[0]; 3],
+            _ => [tuple[0], tuple[1], tuple[2]],
+        });
+    }
+
+    CIEBIIFILE::try_from_chunks(width, height, chunk::from_colors(&colors))
+}
+
+/// Rec. 601 luma of a color
+fn luma(r: u8, g: u8, b: u8) -> u8 {
+    ((r as u32 * 299 + g as u32 * 587 + b as u32 * 114 + 500) / 1000) as u8
+}
+
+fn invalid(reason: String) -> ChunkError {
+    ChunkError::InvalidImage(reason)
+}
+
+/// Reads the header tokens and samples of a Netpbm image.
+struct Reader<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl Reader<'_> {
+
+    /// Reads the next whitespace separated token, skipping comments
+    fn token(&mut self) -> Result<String, ChunkError> {
+        loop {
+            match self.bytes.get(self.offset) {
+                Some(byte) if byte.is_ascii_whitespace() => self.offset += 1,
+                Some(b'#') => {
+                    while !matches!(self.bytes.get(self.offset), Some(b'\n') | None) {
+                        self.offset += 1;
+                    }
+                }
+                Some(_) => break,
+                None => return Err(ChunkError::InvalidLen),
+            }
+        }
+
+        let start = self.offset;
+        while matches!(self.bytes.get(self.offset), Some(byte) if !byte.is_ascii_whitespace()) {
+            self.offset += 1;
+        }
+
+        Ok(String::from_utf8_lossy(&self.bytes[start..self.offset]).into_owned())
+    }
+
+    /// Reads the next token as a number
+    fn number(&mut self) -> Result<usize, ChunkError> {
+        let token = self.token()?;
+
+        token
+            .parse()
+            .map_err(|_| invalid(format!("'{token}' is not a number")))
+    }
+
+    /// Reads a binary sample, two bytes wide if `maxval` doesn't fit in one
+    fn sample(&mut self, maxval: usize) -> Result<usize, ChunkError> {
+        let width = if maxval > 255 { 2 } else { 1 };
+
+        let bytes = self
+            .bytes
+            .get(self.offset..self.offset + width)
+            .ok_or(ChunkError::InvalidLen)?;
+        self.offset += width;
+
+        Ok(bytes.iter().fold(0, |value, byte| (value << 8) | *byte as usize))
+    }
+}
+
+#[cfg(test)]
+mod netpbm_tests {
+    use super::*;
+    use crate::chunk::Chunk;
+    use crate::rgb::RGB;
+
+    fn test_file() -> CIEBIIFILE {
+        CIEBIIFILE::try_from_chunks(2, 1, vec![Chunk::new(255, 0, 0), Chunk::new(10, 20, 30)])
+            .unwrap()
+    }
+
+    #[test]
+    fn round_trip_ppm_and_pam() {
+        for format in [Netpbm::Ppm, Netpbm::Pam] {
+            assert_eq!(decode(&encode(&test_file(), format)).unwrap(), test_file());
+        }
+    }
+
+    #[test]
+    fn encode_pgm() {
+        assert_eq!(encode(&test_file(), Netpbm::Pgm), b"P5\n2 1\n255\n\x4c\x12");
+    }
+
+    #[test]
+    fn decode_plain_with_comments() {
+        let file = decode(b"P3\n# a comment\n2 1\n# another\n15\n15 0 0  0 15 0\n").unwrap();
+
+        assert_eq!(file.get_at_index(0).unwrap().rgb(), RGB::new(255, 0, 0));
+        assert_eq!(file.get_at_index(1).unwrap().rgb(), RGB::new(0, 255, 0));
+    }
+
+    #[test]
+    fn decode_16_bit_gray() {
+        let file = decode(b"P5 1 1 65535\n\xff\xff").unwrap();
+
+        assert_eq!(file.get_at_index(0).unwrap().rgb(), RGB::new(255, 255, 255));
+    }
+
+    #[test]
+    fn decode_pam_with_alpha() {
+        let bytes = b"P7\nWIDTH 1\nHEIGHT 1\nDEPTH 4\nMAXVAL 255\nTUPLTYPE RGB_ALPHA\nENDHDR\n\x01\x02\x03\x04";
+
+        assert_eq!(decode(bytes).unwrap().get_at_index(0).unwrap().rgb(), RGB::new(1, 2, 3));
+    }
+
+    #[test]
+    fn decode_truncated() {
+        if let ChunkError::InvalidLen = decode(b"P5\n2 1\n65535\n\x00\x00\x00").unwrap_err() {
+        } else {
+            panic!()
+        }
+    }
+
+    #[test]
+    fn decode_hostile_dimensions() {
+        for bytes in [&b"P6\n2 2\n255\n\x00\x00"[..], b"P6 4000000000 4000000000 255\n"] {
+            if let ChunkError::InvalidImage(_) = decode(bytes).unwrap_err() {
+            } else {
+                panic!()
+            }
+        }
+    }
+
+    #[test]
+    fn decode_unknown_magic() {
+        if let ChunkError::InvalidImage(_) = decode(b"P9\n1 1\n255\n").unwrap_err() {
+        } else {
+            panic!()
+        }
+    }
+}
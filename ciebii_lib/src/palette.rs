@@ -0,0 +1,269 @@
+use std::collections::{HashMap, HashSet};
+
+use super::{
+    checksum::ChecksumAlgorithm,
+    chunk::{self, Chunk},
+    error::ChunkError,
+    file::CIEBIIFILE,
+    rgb::RGB,
+};
+
+// A palette file is [MAGIC BYTES, COUNT (u32), CHUNKS], one chunk per color in order,
+// each with the legacy checksum, like the pixels of a ciebii file without the CRC-32 flag.
+
+/// Magic bytes spelling "CIBPAL" at the start of every palette file
+pub const MAGIC_BYTES: [u8; 6] = *b"CIBPAL";
+
+/// The length of the header of a palette file: magic bytes, then the number of colors
+pub const HEADER_LEN: usize = 10;
+
+/// The usual extension of palette files
+pub const EXTENSION: &str = "cibpal";
+
+/// An ordered list of colors, like the ones shared by every sprite of a project.
+/// ```
+/// use ciebii_lib::{palette::Palette, rgb::RGB};
+/// let palette = Palette::new(vec![RGB::new(0, 0, 0), RGB::new(255, 255, 255)]);
+/// assert_eq!(palette.nearest(RGB::new(200, 180, 220)), RGB::new(255, 255, 255));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Palette {
+    colors: Vec<RGB>,
+}
+
+impl Palette {
+    /// Creates a palette holding `colors` in the given order
+    pub fn new(colors: Vec<RGB>) -> Self {
+        Self { colors }
+    }
+
+    /// Collects the colors used by every frame of `file`, in the order they first appear.
+    pub fn from_file(file: &CIEBIIFILE) -> Self {
+        let mut seen = HashSet::new();
+
+        let colors = (0..file.frame_count())
+            .filter_map(|i| file.frame(i))
+            .flatten()
+            .map(Chunk::rgb)
+            .filter(|rgb| seen.insert(rgb.color()))
+            .collect();
+
+        Self { colors }
+    }
+
+    /// Returns the colors in order
+    pub fn colors(&self) -> &[RGB] {
+        &self.colors
+    }
+
+    /// Returns the number of colors
+    pub fn len(&self) -> usize {
+        self.colors.len()
+    }
+
+    /// Returns whether the palette has no colors at all
+    pub fn is_empty(&self) -> bool {
+        self.colors.is_empty()
+    }
+
+    /// Returns the color of the palette closest to `color`, going by the distance between the
+    /// two in RGB space. Ties go to the color that comes first. An empty palette has nothing
+    /// to offer, so `color` is returned as it is.
+    pub fn nearest(&self, color: RGB) -> RGB {
+        let (r, g, b) = color.color();
+
+        self.colors
+            .iter()
+            .copied()
+            .min_by_key(|candidate| {
+                let (cr, cg, cb) = candidate.color();
+                let distance = |a: u8, b: u8| (a as i32 - b as i32).pow(2);
+
+                distance(r, cr) + distance(g, cg) + distance(b, cb)
+            })
+            .unwrap_or(color)
+    }
+
+    /// Replaces every chunk with the nearest color of the palette, keeping its alpha.
+    /// Each distinct color is only looked up once.
+    pub fn quantize_chunks(&self, chunks: &[Chunk]) -> Vec<Chunk> {
+        let mut nearest = HashMap::new();
+
+        chunks
+            .iter()
+            .map(|chunk| {
+                *nearest.entry(chunk.rgba().color()).or_insert_with(|| {
+                    let (r, g, b) = self.nearest(chunk.rgb()).color();
+                    Chunk::with_alpha(r, g, b, chunk.alpha())
+                })
+            })
+            .collect()
+    }
+
+    /// Quantizes every frame of `file` to the palette, keeping the frame delays and metadata.
+    pub fn quantize(&self, file: &CIEBIIFILE) -> Result<CIEBIIFILE, ChunkError> {
+        let (width, height) = file.dimensions();
+
+        file.map_frames(width, height, |chunks| self.quantize_chunks(chunks))
+    }
+
+    /// Encodes the palette as a `.cibpal` file.
+    /// ```
+    /// use ciebii_lib::{palette::Palette, rgb::RGB};
+    /// let palette = Palette::new(vec![RGB::new(255, 0, 0), RGB::new(0, 0, 255)]);
+    /// assert_eq!(Palette::try_from(&palette.as_bytes()[..]).unwrap(), palette);
+    /// ```
+    pub fn as_bytes(&self) -> Vec<u8> {
+        let chunks: Vec<Chunk> = self
+            .colors
+            .iter()
+            .map(|rgb| {
+                let (r, g, b) = rgb.color();
+                Chunk::new(r, g, b)
+            })
+            .collect();
+
+        let mut bytes = Vec::with_capacity(HEADER_LEN + chunks.len() * Chunk::LEN);
+        bytes.extend_from_slice(&MAGIC_BYTES);
+        bytes.extend_from_slice(&(chunks.len() as u32).to_be_bytes());
+        chunk::encode_into(&chunks, false, ChecksumAlgorithm::Ciebii, &mut bytes);
+
+        bytes
+    }
+
+    /// Returns whether `bytes` start like a palette file
+    pub fn sniff(bytes: &[u8]) -> bool {
+        bytes.starts_with(&MAGIC_BYTES)
+    }
+}
+
+impl TryFrom<&[u8]> for Palette {
+    type Error = ChunkError;
+
+    /// Decodes a `.cibpal` file, verifying the checksum of every color.
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        let header = bytes.get(..HEADER_LEN).ok_or(ChunkError::InvalidLen)?;
+
+        if !Self::sniff(header) {
+            return Err(ChunkError::IllegalHeader);
+        }
+
+        let count = u32::from_be_bytes(header[MAGIC_BYTES.len()..].try_into()?) as usize;
+
+        // The colors may be cut short, but nothing may follow them
+        let end = count
+            .checked_mul(Chunk::LEN)
+            .and_then(|len| len.checked_add(HEADER_LEN))
+            .ok_or(ChunkError::DimensionMismatch)?;
+        if bytes.len() > end {
+            return Err(ChunkError::TrailingData(end));
+        }
+
+        let chunks = chunk::decode_all(&bytes[HEADER_LEN..], false, ChecksumAlgorithm::Ciebii)?;
+        if chunks.len() != count {
+            return Err(ChunkError::DimensionMismatch);
+        }
+
+        Ok(Self::new(chunks.iter().map(Chunk::rgb).collect()))
+    }
+}
+
+#[cfg(test)]
+mod palette_tests {
+    use super::*;
+
+    fn test_file() -> CIEBIIFILE {
+        let chunks = vec![
+            Chunk::new(255, 0, 0),
+            Chunk::new(0, 0, 255),
+            Chunk::new(255, 0, 0),
+            Chunk::new(0, 250, 0),
+        ];
+
+        CIEBIIFILE::try_from_chunks(2, 2, chunks).unwrap()
+    }
+
+    #[test]
+    fn colors_in_order() {
+        let palette = Palette::from_file(&test_file());
+
+        assert_eq!(
+            palette.colors(),
+            &[RGB::new(255, 0, 0), RGB::new(0, 0, 255), RGB::new(0, 250, 0)]
+        );
+    }
+
+    #[test]
+    fn quantize_file() {
+        let palette = Palette::new(vec![RGB::new(200, 0, 0), RGB::new(0, 0, 0)]);
+        let quantized = palette.quantize(&test_file()).unwrap();
+
+        assert_eq!(
+            quantized.chunks(),
+            &vec![
+                Chunk::new(200, 0, 0),
+                Chunk::new(0, 0, 0),
+                Chunk::new(200, 0, 0),
+                Chunk::new(0, 0, 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        let palette = Palette::from_file(&test_file());
+        let bytes = palette.as_bytes();
+
+        assert_eq!(bytes.len(), HEADER_LEN + 3 * Chunk::LEN);
+        assert_eq!(Palette::try_from(&bytes[..]).unwrap(), palette);
+    }
+
+    #[test]
+    fn bad_checksum() {
+        let mut bytes = Palette::from_file(&test_file()).as_bytes();
+        bytes[HEADER_LEN + Chunk::LEN] ^= 1;
+
+        if let Err(ChunkError::ChecksumFail) = Palette::try_from(&bytes[..]) {
+        } else {
+            panic!()
+        }
+    }
+
+    #[test]
+    fn truncated() {
+        let bytes = Palette::from_file(&test_file()).as_bytes();
+
+        if let Err(ChunkError::DimensionMismatch) = Palette::try_from(&bytes[..bytes.len() - 5]) {
+        } else {
+            panic!()
+        }
+    }
+
+    #[test]
+    fn trailing_data() {
+        let mut bytes = Palette::from_file(&test_file()).as_bytes();
+        let end = bytes.len();
+        bytes.push(0);
+
+        if let Err(ChunkError::TrailingData(offset)) = Palette::try_from(&bytes[..]) {
+            assert_eq!(offset, end);
+        } else {
+            panic!()
+        }
+    }
+
+    #[test]
+    fn not_a_palette() {
+        let bytes = test_file().as_bytes();
+
+        if let Err(ChunkError::IllegalHeader) = Palette::try_from(&bytes[..]) {
+        } else {
+            panic!()
+        }
+    }
+
+    #[test]
+    fn empty_palette() {
+        assert_eq!(Palette::new(Vec::new()).nearest(RGB::new(1, 2, 3)), RGB::new(1, 2, 3));
+    }
+}
@@ -0,0 +1,250 @@
+use super::{chunk, error::ChunkError, file::CIEBIIFILE};
+
+// Magic bytes spelling "qoif"
+const MAGIC_BYTES: [u8; 4] = *b"qoif";
+
+// Every QOI stream ends with seven zero bytes and a one
+const END_MARKER: [u8; 8] = [0, 0, 0, 0, 0, 0, 0, 1];
+
+const OP_INDEX: u8 = 0x00;
+const OP_DIFF: u8 = 0x40;
+const OP_LUMA: u8 = 0x80;
+const OP_RUN: u8 = 0xC0;
+const OP_RGB: u8 = 0xFE;
+const OP_RGBA: u8 = 0xFF;
+const MASK: u8 = 0xC0;
+
+// The most pixels a single op stands for, with a run
+const MAX_RUN: usize = 62;
+
+/// Where a pixel goes in the table of recently seen pixels
+fn hash([r, g, b, a]: [u8; 4]) -> usize {
+    (r as usize * 3 + g as usize * 5 + b as usize * 7 + a as usize * 11) % 64
+}
+
+/// Encodes the first frame of `file` as a QOI image with 3 channels in the sRGB color space.
+/// ```
+/// use ciebii_lib::{chunk::Chunk, file::CIEBIIFILE, qoi};
+/// let file = CIEBIIFILE::try_from_chunks(1, 1, vec![Chunk::new(255, 0, 0)]).unwrap();
+/// assert_eq!(qoi::decode(&qoi::encode(&file).unwrap()).unwrap(), file);
+/// ```
+pub fn encode(file: &CIEBIIFILE) -> Result<Vec<u8>, ChunkError> {
+    let (width, height) = file.dimensions();
+
+    // QOI stores the dimensions as u32
+    let width = u32::try_from(width).map_err(|_| ChunkError::DimensionMismatch)?;
+    let height = u32::try_from(height).map_err(|_| ChunkError::DimensionMismatch)?;
+
+    let mut bytes: Vec<u8> = MAGIC_BYTES
+        .iter()
+        .chain(width.to_be_bytes().iter())
+        .chain(height.to_be_bytes().iter())
+        .chain([3, 0].iter())
+        .cloned()
+        .collect();
+
+    let mut seen = [[0u8; 4]; 64];
+    let mut previous = [0, 0, 0, 255];
+    let mut run = 0u8;
+
+    let pixels = file.chunks();
+    for (i, chunk) in pixels.iter().enumerate() {
+        let (r, g, b) = chunk.rgb().color();
+        let pixel = [r, g, b, 255];
+
+        if pixel == previous {
+            run += 1;
+
+            // Runs are at most 62 long, and have to be flushed at the end
+            if run == 62 || i == pixels.len() - 1 {
+                bytes.push(OP_RUN | (run - 1));
+                run = 0;
+            }
+            continue;
+        }
+
+        if run > 0 {
+            bytes.push(OP_RUN | (run - 1));
+            run = 0;
+        }
+
+        let index = hash(pixel);
+        if seen[index] == pixel {
+            bytes.push(OP_INDEX | index as u8);
+        } else {
+            seen[index] = pixel;
+
+            let dr = r.wrapping_sub(previous[0]) as i8;
+            let dg = g.wrapping_sub(previous[1]) as i8;
+            let db = b.wrapping_sub(previous[2]) as i8;
+            let (dr_dg, db_dg) = (dr.wrapping_sub(dg), db.wrapping_sub(dg));
+
+            if (-2..=1).contains(&dr) && (-2..=1).contains(&dg) && (-2..=1).contains(&db) {
+                bytes.push(
+                    OP_DIFF | ((dr + 2) as u8) << 4 | ((dg + 2) as u8) << 2 | (db + 2) as u8,
+                );
+            } else if (-32..=31).contains(&dg) && (-8..=7).contains(&dr_dg) && (-8..=7).contains(&db_dg) {
+                bytes.push(OP_LUMA | (dg + 32) as u8);
+                bytes.push(((dr_dg + 8) as u8) << 4 | (db_dg + 8) as u8);
+            } else {
+                bytes.extend([OP_RGB, r, g, b]);
+            }
+        }
+
+        previous = pixel;
+    }
+
+    bytes.extend(END_MARKER);
+
+    Ok(bytes)
+}
+
+/// Decodes a QOI image into a ciebii file. The alpha channel is dropped.
+pub fn decode(bytes: &[u8]) -> Result<CIEBIIFILE, ChunkError> {
+
+    // The header is 14 bytes long
+    if bytes.len() < 14 {
+        return Err(ChunkError::InvalidLen);
+    }
+
+    // Make sure that the magic bytes match
+    if bytes[0..4] != MAGIC_BYTES {
+        return Err(ChunkError::IllegalHeader);
+    }
+
+    let width = u32::from_be_bytes(bytes[4..8].try_into()?) as usize;
+    let height = u32::from_be_bytes(bytes[8..12].try_into()?) as usize;
+    let pixels = width
+        .checked_mul(height)
+        .ok_or(ChunkError::DimensionMismatch)?;
+
+    // Even if every byte left were a full run, there wouldn't be enough of them
+    if pixels > (bytes.len() - 14).saturating_mul(MAX_RUN) {
+        return Err(ChunkError::DimensionMismatch);
+    }
+
+    let mut seen = [[0u8; 4]; 64];
+    let mut pixel = [0, 0, 0, 255];
+    let mut colors = Vec::with_capacity(pixels);
+
+    let mut offset = 14;
+    let mut next = || {
+        let byte = bytes.get(offset).copied().ok_or(ChunkError::InvalidLen);
+        offset += 1;
+        byte
+    };
+
+    while colors.len() < pixels {
+        let op = next()?;
+        let mut run = 1;
+
+        match op {
+            OP_RGB => {
+                pixel[0] = next()?;
+                pixel[1] = next()?;
+                pixel[2] = next()?;
+            }
+            OP_RGBA => {
+                pixel = [next()?, next()?, next()?, next()?];
+            }
+            _ => match op & MASK {
+                OP_INDEX => pixel = seen[op as usize],
+                OP_DIFF => {
+                    pixel[0] = pixel[0].wrapping_add((op >> 4) & 3).wrapping_sub(2);
+                    pixel[1] = pixel[1].wrapping_add((op >> 2) & 3).wrapping_sub(2);
+                    pixel[2] = pixel[2].wrapping_add(op & 3).wrapping_sub(2);
+                }
+                OP_LUMA => {
+                    let dg = (op & 0x3F).wrapping_sub(32);
+                    let second = next()?;
+
+                    pixel[0] = pixel[0].wrapping_add(dg).wrapping_add(second >> 4).wrapping_sub(8);
+                    pixel[1] = pixel[1].wrapping_add(dg);
+                    pixel[2] = pixel[2].wrapping_add(dg).wrapping_add(second & 0x0F).wrapping_sub(8);
+                }
+                _ => run = (op & 0x3F) as usize + 1,
+            },
+        }
+
+        seen[hash(pixel)] = pixel;
+
+        let count = run.min(pixels - colors.len());
+        colors.extend(std::iter::repeat_n([pixel[0], pixel[1], pixel[2]], count));
+    }
+
+    CIEBIIFILE::try_from_chunks(width, height, chunk::from_colors(&colors))
+}
+
+#[cfg(test)]
+mod qoi_tests {
+    use super::*;
+    use crate::chunk::Chunk;
+
+    fn test_file() -> CIEBIIFILE {
+
+        // Runs, small and large differences and repeats so every op gets used
+        let colors = [
+            (0, 0, 0),
+            (0, 0, 0),
+            (0, 0, 0),
+            (1, 0, 255),
+            (20, 25, 30),
+            (200, 10, 99),
+            (1, 0, 255),
+            (1, 0, 255),
+            (50, 60, 70),
+        ];
+        let chunks = colors.iter().map(|(r, g, b)| Chunk::new(*r, *g, *b)).collect();
+
+        CIEBIIFILE::try_from_chunks(3, 3, chunks).unwrap()
+    }
+
+    #[test]
+    fn round_trip() {
+        let bytes = encode(&test_file()).unwrap();
+
+        assert_eq!(bytes[..14], [113, 111, 105, 102, 0, 0, 0, 3, 0, 0, 0, 3, 3, 0]);
+        assert_eq!(bytes[bytes.len() - 8..], END_MARKER);
+        assert_eq!(decode(&bytes).unwrap(), test_file());
+    }
+
+    #[test]
+    fn long_run() {
+        let file = CIEBIIFILE::try_from_chunks(100, 1, vec![Chunk::new(200, 9, 9); 100]).unwrap();
+        let bytes = encode(&file).unwrap();
+
+        // One RGB op, then runs of 62 and 37
+        assert_eq!(bytes[14..20], [OP_RGB, 200, 9, 9, OP_RUN | 61, OP_RUN | 36]);
+        assert_eq!(decode(&bytes).unwrap(), file);
+    }
+
+    #[test]
+    fn decode_illegal_header() {
+        if let ChunkError::IllegalHeader = decode(&[0; 22]).unwrap_err() {
+        } else {
+            panic!()
+        }
+    }
+
+    #[test]
+    fn decode_hostile_dimensions() {
+        let mut bytes = b"qoif".to_vec();
+        bytes.extend([0xFF; 8]);
+        bytes.extend([3, 0]);
+
+        if let ChunkError::DimensionMismatch = decode(&bytes).unwrap_err() {
+        } else {
+            panic!()
+        }
+    }
+
+    #[test]
+    fn decode_truncated() {
+        let bytes = encode(&test_file()).unwrap();
+
+        if let ChunkError::InvalidLen = decode(&bytes[..16]).unwrap_err() {
+        } else {
+            panic!()
+        }
+    }
+}
@@ -0,0 +1,166 @@
+use super::{chunk::Chunk, error::ChunkError, file::CIEBIIFILE};
+
+/// How the pixels of a resized image are picked from the original.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Filter {
+    /// Takes the closest pixel, keeping edges sharp. Best for pixel art.
+    Nearest,
+
+    /// Blends the four closest pixels, smoothing the result
+    Bilinear,
+}
+
+/// Returns the largest size with the aspect ratio of a `width` by `height` image
+/// that fits inside `max_width` by `max_height`, never less than a pixel per side.
+/// ```
+/// use ciebii_lib::resize::fit;
+/// assert_eq!(fit(400, 200, 100, 100), (100, 50));
+/// assert_eq!(fit(10, 20, 100, 100), (50, 100));
+/// ```
+pub fn fit(width: usize, height: usize, max_width: usize, max_height: usize) -> (usize, usize) {
+    if width == 0 || height == 0 {
+        return (max_width, max_height);
+    }
+
+    // Whichever side runs into its limit first decides the scale
+    if width * max_height >= height * max_width {
+        (max_width, ((height * max_width + width / 2) / width).max(1))
+    } else {
+        (((width * max_height + height / 2) / height).max(1), max_height)
+    }
+}
+
+/// Scales every frame of `file` to `width` by `height` pixels. Frame delays and metadata
+/// are kept. Fails with `DimensionMismatch` when either size is zero, or the image is empty.
+/// ```
+/// use ciebii_lib::{chunk::Chunk, file::CIEBIIFILE, resize::{resize, Filter}};
+/// let file = CIEBIIFILE::try_from_chunks(1, 1, vec![Chunk::new(255, 0, 0)]).unwrap();
+///
+/// let resized = resize(&file, 2, 2, Filter::Nearest).unwrap();
+/// assert_eq!(resized.chunks(), &vec![Chunk::new(255, 0, 0); 4]);
+/// ```
+pub fn resize(
+    file: &CIEBIIFILE,
+    width: usize,
+    height: usize,
+    filter: Filter,
+) -> Result<CIEBIIFILE, ChunkError> {
+    let (file_width, file_height) = file.dimensions();
+
+    if width == 0 || height == 0 || file_width == 0 || file_height == 0 {
+        return Err(ChunkError::DimensionMismatch);
+    }
+
+    file.map_frames(width, height, |chunks| {
+        let pixel = |x: usize, y: usize| chunks[y * file_width + x].rgba().color();
+
+        (0..width * height)
+            .map(|i| {
+                let (x, y) = (i % width, i / width);
+
+                // The position of this pixel's center in the original image
+                let source_x = (x as f32 + 0.5) * file_width as f32 / width as f32 - 0.5;
+                let source_y = (y as f32 + 0.5) * file_height as f32 / height as f32 - 0.5;
+
+                let (r, g, b, a) = match filter {
+                    Filter::Nearest => {
+                        let x = (source_x.round().max(0.0) as usize).min(file_width - 1);
+                        let y = (source_y.round().max(0.0) as usize).min(file_height - 1);
+
+                        pixel(x, y)
+                    }
+                    Filter::Bilinear => {
+                        let (source_x, source_y) = (source_x.max(0.0), source_y.max(0.0));
+                        let (x0, y0) = (source_x as usize, source_y as usize);
+                        let (x0, y0) = (x0.min(file_width - 1), y0.min(file_height - 1));
+                        let x1 = (x0 + 1).min(file_width - 1);
+                        let y1 = (y0 + 1).min(file_height - 1);
+                        let (tx, ty) = (source_x - x0 as f32, source_y - y0 as f32);
+
+                        let corners = [pixel(x0, y0), pixel(x1, y0), pixel(x0, y1), pixel(x1, y1)];
+                        let weights = [
+                            (1.0 - tx) * (1.0 - ty),
+                            tx * (1.0 - ty),
+                            (1.0 - tx) * ty,
+                            tx * ty,
+                        ];
+
+                        let blend = |channel: fn(&(u8, u8, u8, u8)) -> u8| {
+                            let value: f32 = corners
+                                .iter()
+                                .zip(weights)
+                                .map(|(corner, weight)| channel(corner) as f32 * weight)
+                                .sum();
+
+                            value.round().clamp(0.0, 255.0) as u8
+                        };
+
+                        (blend(|c| c.0), blend(|c| c.1), blend(|c| c.2), blend(|c| c.3))
+                    }
+                };
+
+                Chunk::with_alpha(r, g, b, a)
+            })
+            .collect()
+    })
+}
+
+#[cfg(test)]
+mod resize_tests {
+    use super::*;
+
+    // A 2x1 file, black on the left and white on the right
+    fn test_file() -> CIEBIIFILE {
+        let chunks = vec![Chunk::new(0, 0, 0), Chunk::new(255, 255, 255)];
+
+        CIEBIIFILE::try_from_chunks(2, 1, chunks).unwrap()
+    }
+
+    fn reds(file: &CIEBIIFILE) -> Vec<u8> {
+        file.chunks().iter().map(|chunk| chunk.rgb().color().0).collect()
+    }
+
+    #[test]
+    fn nearest_upscale() {
+        let resized = resize(&test_file(), 4, 2, Filter::Nearest).unwrap();
+
+        assert_eq!(resized.dimensions(), (4, 2));
+        assert_eq!(reds(&resized), [0, 0, 255, 255, 0, 0, 255, 255]);
+    }
+
+    #[test]
+    fn bilinear_blends() {
+        let resized = resize(&test_file(), 4, 1, Filter::Bilinear).unwrap();
+        let reds = reds(&resized);
+
+        // The outer pixels stay put, the ones in between are mixed
+        assert_eq!(reds[0], 0);
+        assert_eq!(reds[3], 255);
+        assert!(reds[1] > 0 && reds[1] < reds[2] && reds[2] < 255);
+    }
+
+    #[test]
+    fn same_size() {
+        for filter in [Filter::Nearest, Filter::Bilinear] {
+            assert_eq!(resize(&test_file(), 2, 1, filter).unwrap(), test_file());
+        }
+    }
+
+    #[test]
+    fn zero_size() {
+        let err = resize(&test_file(), 0, 1, Filter::Nearest).unwrap_err();
+
+        if let ChunkError::DimensionMismatch = err {
+        } else {
+            panic!()
+        }
+    }
+
+    #[test]
+    fn fit_keeps_aspect() {
+        assert_eq!(fit(640, 480, 320, 320), (320, 240));
+        assert_eq!(fit(480, 640, 320, 320), (240, 320));
+        assert_eq!(fit(1000, 1, 10, 10), (10, 1));
+        assert_eq!(fit(3, 3, 256, 128), (128, 128));
+    }
+}
@@ -1,4 +1,6 @@
-use std::fmt::Display;
+use std::{fmt::Display, str::FromStr};
+
+use super::error::ChunkError;
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 #[allow(clippy::upper_case_acronyms)]
@@ -38,8 +40,143 @@ impl RGB {
     }
 }
 
+/// A struct representing an RGB color along with its alpha, 255 being fully opaque
+/// ```
+/// use ciebii_lib::rgb::{RGB, RGBA};
+/// let rgba = RGBA::new(0xFF, 0x00, 0x00, 0x80);
+/// assert_eq!(rgba.rgb(), RGB::new(0xFF, 0x00, 0x00));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[allow(clippy::upper_case_acronyms)]
+pub struct RGBA(u8, u8, u8, u8);
+
+impl RGBA {
+
+    /// Create a new RGBA color
+    pub fn new(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Self(r, g, b, a)
+    }
+
+    /// Get the color along with its alpha
+    pub fn color(&self) -> (u8, u8, u8, u8) {
+        (self.0, self.1, self.2, self.3)
+    }
+
+    /// Returns the color without its alpha
+    pub fn rgb(&self) -> RGB {
+        RGB(self.0, self.1, self.2)
+    }
+
+    /// Returns the alpha, 255 being fully opaque
+    pub fn alpha(&self) -> u8 {
+        self.3
+    }
+
+    /// Returns this RGBA struct as 4 bytes, the alpha last.
+    pub fn as_bytes(&self) -> Vec<u8> {
+        vec![self.0, self.1, self.2, self.3]
+    }
+}
+
+impl From<RGB> for RGBA {
+
+    /// Turns a color into a fully opaque one
+    fn from(rgb: RGB) -> Self {
+        Self(rgb.0, rgb.1, rgb.2, 255)
+    }
+}
+
 impl Display for RGB {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "#{:x}{:x}{:x}", self.0, self.1, self.2)
+        write!(f, "#{:02x}{:02x}{:02x}", self.0, self.1, self.2)
+    }
+}
+
+impl FromStr for RGB {
+    type Err = ChunkError;
+
+    /// Parses a hex color code like `#FF8800` (the leading `#` is optional).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let hex = s.strip_prefix('#').unwrap_or(s);
+
+        if hex.len() != 6 || !hex.is_ascii() {
+            return Err(ChunkError::InvalidColor(s.to_owned()));
+        }
+
+        // Parse each pair of hex digits as a component
+        let component = |i: usize| {
+            u8::from_str_radix(&hex[i..i + 2], 16).map_err(|_| ChunkError::InvalidColor(s.to_owned()))
+        };
+
+        Ok(Self(component(0)?, component(2)?, component(4)?))
+    }
+}
+
+impl Display for RGBA {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "#{:02x}{:02x}{:02x}{:02x}", self.0, self.1, self.2, self.3)
+    }
+}
+
+impl FromStr for RGBA {
+    type Err = ChunkError;
+
+    /// Parses a hex color code like `#FF880080`, or `#FF8800` for an opaque color.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let hex = s.strip_prefix('#').unwrap_or(s);
+
+        if hex.len() == 6 {
+            return s.parse::<RGB>().map(RGBA::from);
+        }
+
+        if hex.len() != 8 || !hex.is_ascii() {
+            return Err(ChunkError::InvalidColor(s.to_owned()));
+        }
+
+        let invalid = || ChunkError::InvalidColor(s.to_owned());
+        let (r, g, b) = hex[..6].parse::<RGB>().map_err(|_| invalid())?.color();
+        let a = u8::from_str_radix(&hex[6..], 16).map_err(|_| invalid())?;
+
+        Ok(Self(r, g, b, a))
+    }
+}
+
+#[cfg(test)]
+mod rgb_tests {
+    use super::*;
+
+    #[test]
+    fn display_pads_components() {
+        assert_eq!(RGB::new(0xFF, 0x00, 0x0A).to_string(), "#ff000a");
+    }
+
+    #[test]
+    fn parse_hex() {
+        assert_eq!("#FF8800".parse::<RGB>().unwrap(), RGB::new(0xFF, 0x88, 0x00));
+        assert_eq!("abcdef".parse::<RGB>().unwrap(), RGB::new(0xAB, 0xCD, 0xEF));
+    }
+
+    #[test]
+    fn parse_invalid_hex() {
+        for invalid in ["", "#FFF", "#GG0000", "#FF00000", "#ÿÿÿ"] {
+            if let ChunkError::InvalidColor(_) = invalid.parse::<RGB>().unwrap_err() {
+            } else {
+                panic!()
+            }
+        }
+    }
+
+    #[test]
+    fn parse_rgba() {
+        assert_eq!("#FF880080".parse::<RGBA>().unwrap(), RGBA::new(0xFF, 0x88, 0x00, 0x80));
+        assert_eq!("#ff8800".parse::<RGBA>().unwrap(), RGBA::new(0xFF, 0x88, 0x00, 0xFF));
+        assert_eq!(RGBA::new(0xFF, 0x00, 0x0A, 0x05).to_string(), "#ff000a05");
+
+        for invalid in ["#FF88008", "#FF8800GG", "#FF88008000"] {
+            if let ChunkError::InvalidColor(_) = invalid.parse::<RGBA>().unwrap_err() {
+            } else {
+                panic!()
+            }
+        }
     }
 }
@@ -0,0 +1,165 @@
+use super::{error::ChunkError, file::CIEBIIFILE};
+
+/// A clockwise rotation by a multiple of 90 degrees.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rotation {
+    /// 90 degrees clockwise, swapping width and height
+    Quarter,
+
+    /// Upside down
+    Half,
+
+    /// 270 degrees clockwise, or 90 counterclockwise
+    ThreeQuarters,
+}
+
+impl Rotation {
+    /// Returns the rotation by `degrees` clockwise, which has to be 90, 180 or 270
+    pub fn from_degrees(degrees: u16) -> Option<Self> {
+        match degrees {
+            90 => Some(Rotation::Quarter),
+            180 => Some(Rotation::Half),
+            270 => Some(Rotation::ThreeQuarters),
+            _ => None,
+        }
+    }
+}
+
+/// The axis an image is mirrored along.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Axis {
+    /// Left becomes right
+    Horizontal,
+
+    /// Top becomes bottom
+    Vertical,
+}
+
+/// Rotates every frame of `file` clockwise, keeping the frame delays and metadata.
+/// ```
+/// use ciebii_lib::{chunk::Chunk, file::CIEBIIFILE, rotate::{rotate, Rotation}};
+/// let chunks = vec![Chunk::new(1, 0, 0), Chunk::new(2, 0, 0)];
+/// let file = CIEBIIFILE::try_from_chunks(2, 1, chunks).unwrap();
+///
+/// let rotated = rotate(&file, Rotation::Quarter).unwrap();
+/// assert_eq!(rotated.dimensions(), (1, 2));
+/// ```
+pub fn rotate(file: &CIEBIIFILE, rotation: Rotation) -> Result<CIEBIIFILE, ChunkError> {
+    let (width, height) = file.dimensions();
+
+    let (new_width, new_height) = match rotation {
+        Rotation::Half => (width, height),
+        Rotation::Quarter | Rotation::ThreeQuarters => (height, width),
+    };
+
+    file.map_frames(new_width, new_height, |chunks| {
+        (0..new_width * new_height)
+            .map(|i| {
+                let (x, y) = (i % new_width, i / new_width);
+
+                // Where the pixel ending up at (x, y) comes from
+                let (source_x, source_y) = match rotation {
+                    Rotation::Quarter => (y, height - 1 - x),
+                    Rotation::Half => (width - 1 - x, height - 1 - y),
+                    Rotation::ThreeQuarters => (width - 1 - y, x),
+                };
+
+                chunks[source_y * width + source_x]
+            })
+            .collect()
+    })
+}
+
+/// Mirrors every frame of `file` along `axis`, keeping the frame delays and metadata.
+/// ```
+/// use ciebii_lib::{chunk::Chunk, file::CIEBIIFILE, rotate::{flip, Axis}};
+/// let chunks = vec![Chunk::new(1, 0, 0), Chunk::new(2, 0, 0)];
+/// let file = CIEBIIFILE::try_from_chunks(2, 1, chunks).unwrap();
+///
+/// let flipped = flip(&file, Axis::Horizontal).unwrap();
+/// assert_eq!(flipped.chunks(), &[Chunk::new(2, 0, 0), Chunk::new(1, 0, 0)]);
+/// ```
+pub fn flip(file: &CIEBIIFILE, axis: Axis) -> Result<CIEBIIFILE, ChunkError> {
+    let (width, height) = file.dimensions();
+
+    file.map_frames(width, height, |chunks| {
+        (0..width * height)
+            .map(|i| {
+                let (x, y) = (i % width, i / width);
+
+                match axis {
+                    Axis::Horizontal => chunks[y * width + width - 1 - x],
+                    Axis::Vertical => chunks[(height - 1 - y) * width + x],
+                }
+            })
+            .collect()
+    })
+}
+
+#[cfg(test)]
+mod rotate_tests {
+    use super::*;
+    use crate::chunk::Chunk;
+
+    // A 3x2 file where the red channel is the index of the pixel:
+    // 0 1 2
+    // 3 4 5
+    fn test_file() -> CIEBIIFILE {
+        let chunks = (0..6).map(|i| Chunk::new(i, 0, 0)).collect();
+
+        CIEBIIFILE::try_from_chunks(3, 2, chunks).unwrap()
+    }
+
+    fn reds(file: &CIEBIIFILE) -> Vec<u8> {
+        file.chunks().iter().map(|chunk| chunk.rgb().color().0).collect()
+    }
+
+    #[test]
+    fn rotate_quarter() {
+        let rotated = rotate(&test_file(), Rotation::Quarter).unwrap();
+
+        assert_eq!(rotated.dimensions(), (2, 3));
+        assert_eq!(reds(&rotated), [3, 0, 4, 1, 5, 2]);
+    }
+
+    #[test]
+    fn rotate_half() {
+        let rotated = rotate(&test_file(), Rotation::Half).unwrap();
+
+        assert_eq!(rotated.dimensions(), (3, 2));
+        assert_eq!(reds(&rotated), [5, 4, 3, 2, 1, 0]);
+    }
+
+    #[test]
+    fn rotate_three_quarters() {
+        let rotated = rotate(&test_file(), Rotation::ThreeQuarters).unwrap();
+
+        assert_eq!(rotated.dimensions(), (2, 3));
+        assert_eq!(reds(&rotated), [2, 5, 1, 4, 0, 3]);
+    }
+
+    #[test]
+    fn full_turn() {
+        let mut file = test_file();
+        for _ in 0..4 {
+            file = rotate(&file, Rotation::Quarter).unwrap();
+        }
+
+        assert_eq!(file, test_file());
+    }
+
+    #[test]
+    fn flip_axes() {
+        let horizontal = flip(&test_file(), Axis::Horizontal).unwrap();
+        let vertical = flip(&test_file(), Axis::Vertical).unwrap();
+
+        assert_eq!(reds(&horizontal), [2, 1, 0, 5, 4, 3]);
+        assert_eq!(reds(&vertical), [3, 4, 5, 0, 1, 2]);
+    }
+
+    #[test]
+    fn degrees() {
+        assert_eq!(Rotation::from_degrees(270), Some(Rotation::ThreeQuarters));
+        assert_eq!(Rotation::from_degrees(45), None);
+    }
+}
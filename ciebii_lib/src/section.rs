@@ -0,0 +1,242 @@
+use std::io::{self, Write};
+
+use super::{checksum::ChecksumAlgorithm, error::ChunkError};
+
+/// An optional section stored after the pixel data, used for anything that isn't a pixel of the first frame.
+/// Readers skip sections with tags they don't know.
+/// ```
+/// use ciebii_lib::section::Section;
+/// let section = Section::new(*b"NOTE", b"hello".to_vec());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Section {
+    tag: [u8; 4],
+    payload: Vec<u8>,
+}
+
+impl Section {
+
+    /// Tag of the section holding the frames of an animated file
+    pub const ANIMATION: [u8; 4] = *b"ANIM";
+
+    /// Tag of the section holding raw EXIF data (a TIFF structure, as in a JPEG APP1 segment)
+    pub const EXIF: [u8; 4] = *b"EXIF";
+
+    /// Tag of the section holding an XMP packet
+    pub const XMP: [u8; 4] = *b"XMP ";
+
+    /// Tag of the section holding a small preview, stored as a complete ciebii file
+    pub const THUMBNAIL: [u8; 4] = *b"THMB";
+
+    /// Tag of the section ending a file, holding a SHA-256 digest of every byte before it
+    pub const FOOTER: [u8; 4] = *b"FOOT";
+
+    /// Tags of the sections kept as metadata
+    pub const METADATA: [[u8; 4]; 3] = [Self::EXIF, Self::XMP, Self::THUMBNAIL];
+
+    pub fn new(tag: [u8; 4], payload: Vec<u8>) -> Self {
+        Self { tag, payload }
+    }
+
+    /// Returns the tag identifying this section
+    pub fn tag(&self) -> [u8; 4] {
+        self.tag
+    }
+
+    /// Returns the payload of this section
+    pub fn payload(&self) -> &[u8] {
+        &self.payload
+    }
+
+    // Returns the bytes as [TAG, LENGTH (u32), PAYLOAD, CHECKSUM (u16)]
+    //                       4b     4b           LENGTH    2b
+    /// Returns this section as a vector of bytes, its payload checksummed with `checksum` like
+    /// the rest of the file it's in. Fails with `InvalidLen` for payloads of 4 GiB or more.
+    pub fn as_bytes(&self, checksum: ChecksumAlgorithm) -> Result<Vec<u8>, ChunkError> {
+        let mut bytes = Vec::new();
+        self.encode_into(&mut bytes, checksum)?;
+
+        Ok(bytes)
+    }
+
+    /// Returns how many bytes this section takes up in a file
+    pub fn encoded_len(&self) -> usize {
+        self.payload.len() + 10
+    }
+
+    /// Returns the length stored in front of the payload, which has to fit in a u32
+    fn len_bytes(&self) -> Result<[u8; 4], ChunkError> {
+        u32::try_from(self.payload.len())
+            .map(u32::to_be_bytes)
+            .map_err(|_| ChunkError::InvalidLen)
+    }
+
+    /// Appends this section to `bytes` in the same format as `as_bytes`.
+    pub(crate) fn encode_into(
+        &self,
+        bytes: &mut Vec<u8>,
+        checksum: ChecksumAlgorithm,
+    ) -> Result<(), ChunkError> {
+        let len = self.len_bytes()?;
+
+        bytes.reserve(self.encoded_len());
+        bytes.extend_from_slice(&self.tag);
+        bytes.extend_from_slice(&len);
+        bytes.extend_from_slice(&self.payload);
+        bytes.extend_from_slice(&checksum.checksum(&self.payload).to_be_bytes());
+
+        Ok(())
+    }
+
+    /// Writes this section to `writer` in the same format as `as_bytes`, without copying the payload.
+    /// Fails with `InvalidInput` for payloads of 4 GiB or more, before writing anything.
+    pub fn write_into(
+        &self,
+        writer: &mut impl Write,
+        checksum: ChecksumAlgorithm,
+    ) -> io::Result<()> {
+        let len = self.len_bytes().map_err(|_| {
+            io::Error::new(io::ErrorKind::InvalidInput, "sections hold less than 4 GiB")
+        })?;
+
+        writer.write_all(&self.tag)?;
+        writer.write_all(&len)?;
+        writer.write_all(&self.payload)?;
+        writer.write_all(&checksum.checksum(&self.payload).to_be_bytes())
+    }
+
+    /// Tags of every section this crate knows about
+    pub const KNOWN: [[u8; 4]; 5] =
+        [Self::ANIMATION, Self::EXIF, Self::XMP, Self::THUMBNAIL, Self::FOOTER];
+
+    /// Returns whether `bytes` start with a section rather than garbage.
+    /// Known tags always count, so a broken section is reported as such. Unknown tags have to be
+    /// printable ASCII, spaces included, and the length after them has to fit in `bytes`.
+    pub fn is_start(bytes: &[u8]) -> bool {
+        let Some(tag) = bytes.get(..4) else {
+            return false;
+        };
+
+        if Self::KNOWN.iter().any(|known| known == tag) {
+            return true;
+        }
+
+        if !tag.iter().all(|byte| byte.is_ascii_graphic() || *byte == b' ') {
+            return false;
+        }
+
+        // Text after the pixels also starts with four printable bytes
+        bytes
+            .get(4..8)
+            .map(|len| u32::from_be_bytes([len[0], len[1], len[2], len[3]]) as usize)
+            .is_some_and(|len| len <= bytes.len().saturating_sub(10))
+    }
+
+    /// Parses the section at the start of `bytes`, its payload checksummed with `checksum`.
+    /// Returns the section along with the amount of bytes it took up.
+    pub fn parse(bytes: &[u8], checksum: ChecksumAlgorithm) -> Result<(Self, usize), ChunkError> {
+
+        // Tag and length come first
+        if bytes.len() < 8 {
+            return Err(ChunkError::InvalidLen);
+        }
+
+        let tag: [u8; 4] = bytes[0..4].try_into()?;
+        let len = u32::from_be_bytes(bytes[4..8].try_into()?) as usize;
+
+        // Make sure the payload and checksum are actually there
+        let end = 8 + len + 2;
+        if bytes.len() < end {
+            return Err(ChunkError::InvalidLen);
+        }
+
+        let payload = &bytes[8..8 + len];
+        let original_checksum = u16::from_be_bytes(bytes[8 + len..end].try_into()?);
+
+        // Compare the checksums
+        if original_checksum != checksum.checksum(payload) {
+            return Err(ChunkError::ChecksumFail);
+        }
+
+        Ok((Self::new(tag, payload.to_vec()), end))
+    }
+}
+
+#[cfg(test)]
+mod section_tests {
+    use super::*;
+
+    const CIEBII: ChecksumAlgorithm = ChecksumAlgorithm::Ciebii;
+
+    #[test]
+    fn as_bytes() {
+        let section = Section::new(*b"NOTE", vec![1, 2, 3]);
+
+        assert_eq!(
+            section.as_bytes(CIEBII).unwrap(),
+            [78, 79, 84, 69, 0, 0, 0, 3, 1, 2, 3, 1, 253]
+        );
+    }
+
+    #[test]
+    fn parse_successfully() {
+        let bytes = vec![78, 79, 84, 69, 0, 0, 0, 3, 1, 2, 3, 1, 253, 99];
+
+        let (section, len) = Section::parse(&bytes, CIEBII).unwrap();
+
+        assert_eq!(section.tag(), *b"NOTE");
+        assert_eq!(section.payload(), [1, 2, 3]);
+        assert_eq!(len, 13);
+    }
+
+    #[test]
+    fn parse_truncated() {
+        let bytes = vec![78, 79, 84, 69, 0, 0, 0, 3, 1, 2];
+
+        if let ChunkError::InvalidLen = Section::parse(&bytes, CIEBII).unwrap_err() {
+        } else {
+            panic!()
+        }
+    }
+
+    #[test]
+    fn parse_checksum_fail() {
+        let bytes = vec![78, 79, 84, 69, 0, 0, 0, 3, 1, 2, 4, 1, 253];
+
+        if let ChunkError::ChecksumFail = Section::parse(&bytes, CIEBII).unwrap_err() {
+        } else {
+            panic!()
+        }
+    }
+
+    #[test]
+    fn large_payload() {
+        let section = Section::new(*b"BIGG", vec![0xFF; 100_000]);
+
+        let (parsed, _) = Section::parse(&section.as_bytes(CIEBII).unwrap(), CIEBII).unwrap();
+        assert_eq!(parsed, section);
+    }
+
+    #[test]
+    fn crc32() {
+        let section = Section::new(*b"NOTE", vec![1, 2, 3]);
+        let mut bytes = section.as_bytes(ChecksumAlgorithm::Crc32).unwrap();
+
+        assert_eq!(bytes[11..], (crc32fast::hash(&[1, 2, 3]) as u16).to_be_bytes());
+        assert_eq!(Section::parse(&bytes, ChecksumAlgorithm::Crc32).unwrap().0, section);
+
+        // The legacy checksum of the payload doesn't pass for a CRC-32
+        if let ChunkError::ChecksumFail = Section::parse(&bytes, CIEBII).unwrap_err() {
+        } else {
+            panic!()
+        }
+
+        bytes[9] ^= 1;
+        if let ChunkError::ChecksumFail =
+            Section::parse(&bytes, ChecksumAlgorithm::Crc32).unwrap_err()
+        {
+        } else {
+            panic!()
+        }
+    }
+}
@@ -0,0 +1,137 @@
+use super::{chunk::Chunk, error::ChunkError, file::CIEBIIFILE};
+
+// The hidden data is prefixed with its length as a u32
+const LENGTH_BYTES: usize = 4;
+
+/// Returns how many bytes can be hidden in `file`.
+/// Every color channel of the first frame carries one bit in its lowest bit.
+pub fn capacity(file: &CIEBIIFILE) -> usize {
+    (file.chunks().len() * 3 / 8).saturating_sub(LENGTH_BYTES)
+}
+
+/// Hides `data` in the lowest bit of every color channel of `file`, updating the checksums.
+/// The image barely changes, every channel is off by one at most.
+/// ```
+/// use ciebii_lib::{chunk::Chunk, file::CIEBIIFILE, steg};
+/// let mut file = CIEBIIFILE::try_from_chunks(4, 4, vec![Chunk::new(0, 0, 0); 16]).unwrap();
+/// steg::embed(&mut file, b"hi").unwrap();
+/// assert_eq!(steg::extract(&file).unwrap(), b"hi");
+/// ```
+pub fn embed(file: &mut CIEBIIFILE, data: &[u8]) -> Result<(), ChunkError> {
+
+    // Check if the data even fits
+    let capacity = capacity(file);
+    if data.len() > capacity {
+        return Err(ChunkError::PayloadTooLarge(data.len(), capacity));
+    }
+
+    let payload: Vec<u8> = (data.len() as u32)
+        .to_be_bytes()
+        .iter()
+        .chain(data)
+        .cloned()
+        .collect();
+
+    // Most significant bit first
+    let mut bits = payload
+        .iter()
+        .flat_map(|byte| (0..8).rev().map(move |i| (byte >> i) & 1));
+
+    file.modify_chunks(|chunks| {
+        for chunk in chunks.iter_mut() {
+            let mut channels = chunk.rgb().as_bytes();
+
+            for channel in channels.iter_mut() {
+                if let Some(bit) = bits.next() {
+                    *channel = (*channel & !1) | bit;
+                }
+            }
+
+            *chunk = Chunk::with_alpha(channels[0], channels[1], channels[2], chunk.alpha());
+        }
+    });
+
+    Ok(())
+}
+
+/// Reads data hidden with [`embed`] back out of `file`.
+pub fn extract(file: &CIEBIIFILE) -> Result<Vec<u8>, ChunkError> {
+    let mut bytes = file
+        .chunks()
+        .iter()
+        .flat_map(|chunk| chunk.rgb().as_bytes())
+        .map(|channel| channel & 1)
+        .collect::<Vec<u8>>()
+        .chunks_exact(8)
+        .map(|bits| bits.iter().fold(0, |byte, bit| (byte << 1) | bit))
+        .collect::<Vec<u8>>();
+
+    if bytes.len() < LENGTH_BYTES {
+        return Err(ChunkError::InvalidLen);
+    }
+
+    let len = u32::from_be_bytes(bytes[..LENGTH_BYTES].try_into()?) as usize;
+
+    // A length that doesn't fit means there's nothing hidden in the file
+    if len > bytes.len() - LENGTH_BYTES {
+        return Err(ChunkError::InvalidLen);
+    }
+
+    bytes.truncate(LENGTH_BYTES + len);
+    bytes.drain(..LENGTH_BYTES);
+
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod steg_tests {
+    use super::*;
+
+    fn cover() -> CIEBIIFILE {
+        let chunks = (0..64).map(|i| Chunk::new(i, 255 - i, i * 2)).collect();
+
+        CIEBIIFILE::try_from_chunks(8, 8, chunks).unwrap()
+    }
+
+    #[test]
+    fn capacity_of_file() {
+        assert_eq!(capacity(&cover()), 64 * 3 / 8 - 4);
+    }
+
+    #[test]
+    fn embed_and_extract() {
+        let mut file = cover();
+        embed(&mut file, b"secret").unwrap();
+
+        assert_eq!(extract(&file).unwrap(), b"secret");
+
+        // Every chunk has to stay valid, and channels change by one at most
+        for (old, new) in cover().chunks().iter().zip(file.chunks()) {
+            assert!(new.is_valid());
+
+            for (a, b) in old.rgb().as_bytes().iter().zip(new.rgb().as_bytes()) {
+                assert!(a.abs_diff(b) <= 1);
+            }
+        }
+    }
+
+    #[test]
+    fn embed_too_large() {
+        let mut file = cover();
+
+        if let ChunkError::PayloadTooLarge(21, 20) = embed(&mut file, &[0; 21]).unwrap_err() {
+        } else {
+            panic!()
+        }
+    }
+
+    #[test]
+    fn extract_nothing_hidden() {
+        let file = CIEBIIFILE::try_from_chunks(8, 8, vec![Chunk::new(255, 255, 255); 64]).unwrap();
+
+        if let ChunkError::InvalidLen = extract(&file).unwrap_err() {
+        } else {
+            panic!()
+        }
+    }
+}
@@ -0,0 +1,253 @@
+use std::io;
+
+use futures::{
+    io::{AsyncRead, AsyncReadExt},
+    stream::{self, Stream},
+};
+
+use super::{
+    chunk::Chunk,
+    cursor::{self, Cursor},
+    error::ChunkError,
+    header::Header,
+};
+
+/// A row of pixels decoded from a stream.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Row {
+    /// The index of the row, counting from the top
+    pub index: usize,
+
+    /// One chunk per pixel, from left to right
+    pub chunks: Vec<Chunk>,
+}
+
+/// Decodes a ciebii file from an async reader one row at a time, so async servers can forward
+/// rows as they arrive. Nothing is read ahead of the row being asked for, though a run of a
+/// compressed file may go on into the next row. Sections after the pixel data, like animation
+/// frames, are left unread.
+/// ```
+/// use ciebii_lib::{chunk::Chunk, file::CIEBIIFILE, stream::AsyncRows};
+/// use futures::{executor::block_on, TryStreamExt};
+///
+/// let file = CIEBIIFILE::try_from_chunks(1, 2, vec![Chunk::new(1, 2, 3); 2]).unwrap();
+/// let bytes = file.as_bytes();
+///
+/// block_on(async {
+///     let rows = AsyncRows::new(&bytes[..]).await.unwrap();
+///     assert_eq!(rows.dimensions(), (1, 2));
+///
+///     let rows: Vec<_> = rows.into_stream().try_collect().await.unwrap();
+///     assert_eq!(rows.len(), 2);
+/// });
+/// ```
+pub struct AsyncRows<R: AsyncRead + Unpin> {
+    inner: R,
+    header: Header,
+}
+
+impl<R: AsyncRead + Unpin> AsyncRows<R> {
+    /// Reads and checks the header at the start of the file
+    pub async fn new(mut inner: R) -> Result<Self, ChunkError> {
+        let mut header = vec![0; Header::LEN];
+        inner
+            .read_exact(&mut header)
+            .await
+            .map_err(|err| truncated(err, ChunkError::InvalidLen))?;
+
+        Ok(Self {
+            inner,
+            header: Header::try_from(header)?,
+        })
+    }
+
+    /// Returns the dimensions of the image
+    pub fn dimensions(&self) -> (usize, usize) {
+        self.header.dimensions()
+    }
+
+    /// Turns the reader into a stream of every row, from top to bottom. The stream has to be
+    /// pinned before polling it with `next`, like with `std::pin::pin!`.
+    /// A file that ends early fails with `DimensionMismatch`, like when parsing it whole.
+    pub fn into_stream(self) -> impl Stream<Item = Result<Row, ChunkError>> {
+        let (width, height) = self.dimensions();
+        let header = self.header;
+
+        stream::try_unfold((self.inner, 0, None), move |(mut inner, index, mut run)| async move {
+            if index == height {
+                return Ok(None);
+            }
+
+            let chunks = match header.is_compressed() {
+                true => read_runs(&mut inner, &header, width, &mut run).await?,
+                false => {
+                    let len = width
+                        .checked_mul(header.chunk_len())
+                        .ok_or(ChunkError::DimensionMismatch)?;
+
+                    // The width comes from the header, so the row only grows as bytes arrive
+                    let mut bytes = Vec::new();
+                    (&mut inner).take(len as u64).read_to_end(&mut bytes).await?;
+
+                    Cursor::new(&bytes[..]).chunks(width, &header)?
+                }
+            };
+
+            // The last run may not go past the end of the image
+            if index + 1 == height && run.is_some() {
+                return Err(ChunkError::DimensionMismatch);
+            }
+
+            Ok(Some((Row { index, chunks }, (inner, index + 1, run))))
+        })
+    }
+}
+
+/// Reads the next `count` chunks of a compressed file, starting with what's left of `run`.
+/// Whatever is left of the last run read is kept in `run` for the next call.
+async fn read_runs<R: AsyncRead + Unpin>(
+    inner: &mut R,
+    header: &Header,
+    count: usize,
+    run: &mut Option<(Chunk, usize)>,
+) -> Result<Vec<Chunk>, ChunkError> {
+    let mut chunks = Vec::new();
+    let mut bytes = vec![0; cursor::record_len(header)];
+
+    while chunks.len() < count {
+        let (chunk, length) = match run.take() {
+            Some(run) => run,
+            None => {
+                inner
+                    .read_exact(&mut bytes)
+                    .await
+                    .map_err(|err| truncated(err, ChunkError::DimensionMismatch))?;
+
+                Cursor::new(&bytes[..]).record(header)?
+            }
+        };
+
+        let taken = length.min(count - chunks.len());
+        chunks.extend(std::iter::repeat_n(chunk, taken));
+
+        if taken < length {
+            *run = Some((chunk, length - taken));
+        }
+    }
+
+    Ok(chunks)
+}
+
+/// Maps a read that ran out of data to `err`, keeping any other IO error as is.
+pub(crate) fn truncated(err: io::Error, truncated: ChunkError) -> ChunkError {
+    match err.kind() {
+        io::ErrorKind::UnexpectedEof => truncated,
+        _ => ChunkError::Io(err),
+    }
+}
+
+#[cfg(test)]
+mod stream_tests {
+    use std::pin::pin;
+
+    use futures::{executor::block_on, StreamExt, TryStreamExt};
+
+    use super::*;
+    use crate::{checksum::ChecksumAlgorithm, chunk, file::CIEBIIFILE};
+
+    fn test_file() -> CIEBIIFILE {
+        let chunks = (0..6).map(|i| Chunk::new(i, 0, 0)).collect();
+
+        CIEBIIFILE::try_from_chunks(3, 2, chunks).unwrap()
+    }
+
+    #[test]
+    fn rows_in_order() {
+        let bytes = test_file().as_bytes();
+
+        let rows: Vec<Row> = block_on(async {
+            let rows = AsyncRows::new(&bytes[..]).await.unwrap();
+            rows.into_stream().try_collect().await.unwrap()
+        });
+
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[1].index, 1);
+        assert_eq!(rows[1].chunks, test_file().chunks()[3..]);
+    }
+
+    #[test]
+    fn truncated_row() {
+        let bytes = test_file().as_bytes();
+        let bytes = &bytes[..bytes.len() - 1];
+
+        let results: Vec<_> = block_on(async {
+            AsyncRows::new(bytes).await.unwrap().into_stream().collect().await
+        });
+
+        assert!(results[0].is_ok());
+        if let Err(ChunkError::DimensionMismatch) = results[1] {
+        } else {
+            panic!()
+        }
+    }
+
+    #[test]
+    fn bad_checksum() {
+        let mut bytes = test_file().as_bytes();
+        bytes[34] ^= 1;
+
+        let first = block_on(async {
+            let rows = AsyncRows::new(&bytes[..]).await.unwrap().into_stream();
+            pin!(rows).next().await
+        });
+
+        if let Some(Err(ChunkError::ChecksumFail)) = first {
+        } else {
+            panic!()
+        }
+    }
+
+    #[test]
+    fn short_header() {
+        let bytes = test_file().as_bytes();
+
+        if let Err(ChunkError::InvalidLen) = block_on(AsyncRows::new(&bytes[..10])) {
+        } else {
+            panic!()
+        }
+    }
+
+    #[test]
+    fn compressed_rows() {
+        let mut file = CIEBIIFILE::try_from_chunks(3, 2, vec![Chunk::new(1, 2, 3); 6]).unwrap();
+        file.set_compressed(true);
+        let bytes = file.as_bytes();
+
+        let rows: Vec<Row> = block_on(async {
+            let rows = AsyncRows::new(&bytes[..]).await.unwrap();
+            rows.into_stream().try_collect().await.unwrap()
+        });
+
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[1].chunks, vec![Chunk::new(1, 2, 3); 3]);
+    }
+
+    #[test]
+    fn hostile_width() {
+        for compressed in [false, true] {
+            let mut bytes = Header::new(1 << 40, 1).compressed(compressed).as_bytes();
+            let checksum = ChecksumAlgorithm::Crc32;
+            chunk::encode_runs(&[Chunk::new(1, 2, 3)], false, checksum, &mut bytes);
+
+            let first = block_on(async {
+                let rows = AsyncRows::new(&bytes[..]).await.unwrap().into_stream();
+                pin!(rows).next().await
+            });
+
+            if let Some(Err(ChunkError::DimensionMismatch)) = first {
+            } else {
+                panic!()
+            }
+        }
+    }
+}
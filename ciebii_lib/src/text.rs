@@ -0,0 +1,92 @@
+use font8x8::legacy::BASIC_LEGACY;
+
+use super::{draw::fill_where, file::CIEBIIFILE, rgb::RGB};
+
+/// The width and height of a glyph before scaling
+pub const GLYPH_SIZE: usize = 8;
+
+/// Returns the size in pixels `text` takes up when drawn at `scale`.
+/// Every line break starts a new row of glyphs.
+pub fn text_size(text: &str, scale: usize) -> (usize, usize) {
+    let columns = text.lines().map(|line| line.chars().count()).max().unwrap_or(0);
+    let rows = text.lines().count();
+
+    (columns * GLYPH_SIZE * scale, rows * GLYPH_SIZE * scale)
+}
+
+/// Draws `text` onto `file` with an 8x8 bitmap font, with its top left corner at
+/// (x, y) and every glyph pixel scaled up to a `scale` x `scale` square.
+/// Characters outside of basic ASCII are drawn as '?'. Parts outside of the image are clipped.
+/// ```
+/// use ciebii_lib::{file::CIEBIIFILE, rgb::RGB, text::draw_text};
+/// let mut file = CIEBIIFILE::new(0, 0);
+/// draw_text(&mut file, 2, 2, "v1.2", RGB::new(0xFF, 0xFF, 0xFF), 1);
+/// ```
+pub fn draw_text(file: &mut CIEBIIFILE, x: usize, y: usize, text: &str, color: RGB, scale: usize) {
+    let scale = scale.max(1);
+    let lines: Vec<Vec<char>> = text.lines().map(|line| line.chars().collect()).collect();
+    let (width, height) = text_size(text, scale);
+
+    fill_where(file, color, |px, py| {
+
+        // Only look at pixels covered by the text
+        if px < x || py < y || px - x >= width || py - y >= height {
+            return false;
+        }
+
+        let (column, row) = ((px - x) / scale, (py - y) / scale);
+
+        match lines[row / GLYPH_SIZE].get(column / GLYPH_SIZE) {
+            Some(c) => glyph(*c)[row % GLYPH_SIZE] & (1 << (column % GLYPH_SIZE)) != 0,
+            None => false,
+        }
+    });
+}
+
+/// Returns the bitmap of a character, one byte per row with the leftmost pixel in the lowest bit.
+fn glyph(c: char) -> [u8; 8] {
+    match c.is_ascii() {
+        true => BASIC_LEGACY[c as usize],
+        false => BASIC_LEGACY['?' as usize],
+    }
+}
+
+#[cfg(test)]
+mod text_tests {
+    use super::*;
+    use crate::chunk::Chunk;
+
+    #[test]
+    fn size_of_text() {
+        assert_eq!(text_size("v1.2", 1), (32, 8));
+        assert_eq!(text_size("ab\nc", 2), (32, 32));
+        assert_eq!(text_size("", 1), (0, 0));
+    }
+
+    #[test]
+    fn draws_glyph() {
+        let mut file =
+            CIEBIIFILE::try_from_chunks(10, 10, vec![Chunk::new(0, 0, 0); 100]).unwrap();
+        draw_text(&mut file, 1, 1, "|", RGB::new(255, 255, 255), 1);
+
+        // Compare against the font bitmap, shifted by one pixel
+        let bitmap = glyph('|');
+        for y in 0..10 {
+            for x in 0..10 {
+                let lit = x >= 1
+                    && y >= 1
+                    && x < 9
+                    && y < 9
+                    && bitmap[y - 1] & (1 << (x - 1)) != 0;
+                let white = file.get_at_index(y * 10 + x).unwrap().rgb() == RGB::new(255, 255, 255);
+
+                assert_eq!(lit, white);
+            }
+        }
+    }
+
+    #[test]
+    fn non_ascii_fallback() {
+        assert_eq!(glyph('é'), glyph('?'));
+    }
+}
@@ -0,0 +1,94 @@
+use super::{chunk::Chunk, file::CIEBIIFILE};
+
+/// The longest side of a generated thumbnail, in pixels
+pub const MAX_SIZE: usize = 128;
+
+/// Returns the dimensions of a thumbnail of a `width` by `height` image,
+/// keeping the aspect ratio. Images that are already small enough keep their size.
+pub fn fit(width: usize, height: usize) -> (usize, usize) {
+    let longest = width.max(height);
+
+    if longest <= MAX_SIZE {
+        return (width, height);
+    }
+
+    // Round, but never shrink a side to nothing
+    let scale = |side: usize| ((side * MAX_SIZE + longest / 2) / longest).max(1);
+
+    (scale(width), scale(height))
+}
+
+/// Shrinks `file` to thumbnail size, averaging the pixels each thumbnail pixel covers.
+/// ```
+/// use ciebii_lib::{file::CIEBIIFILE, thumbnail};
+/// let file = CIEBIIFILE::new(0, 0);
+/// let thumbnail = thumbnail::generate(&file);
+/// ```
+pub fn generate(file: &CIEBIIFILE) -> CIEBIIFILE {
+    let (width, height) = file.dimensions();
+    let (thumb_width, thumb_height) = fit(width, height);
+
+    let chunks = (0..thumb_width * thumb_height)
+        .map(|i| {
+            let (column, row) = (i % thumb_width, i / thumb_width);
+
+            // The source pixels covered by this thumbnail pixel, at least one
+            let x_start = column * width / thumb_width;
+            let x_end = ((column + 1) * width / thumb_width).max(x_start + 1);
+            let y_start = row * height / thumb_height;
+            let y_end = ((row + 1) * height / thumb_height).max(y_start + 1);
+
+            let mut sum = [0usize; 4];
+            for y in y_start..y_end {
+                for x in x_start..x_end {
+                    let (r, g, b, a) = file.chunks()[y * width + x].rgba().color();
+
+                    sum[0] += r as usize;
+                    sum[1] += g as usize;
+                    sum[2] += b as usize;
+                    sum[3] += a as usize;
+                }
+            }
+
+            let count = (x_end - x_start) * (y_end - y_start);
+            let [r, g, b, a] = sum.map(|channel| ((channel + count / 2) / count) as u8);
+
+            Chunk::with_alpha(r, g, b, a)
+        })
+        .collect();
+
+    // One chunk per thumbnail pixel, so the dimensions always match
+    CIEBIIFILE::try_from_chunks(thumb_width, thumb_height, chunks).unwrap()
+}
+
+#[cfg(test)]
+mod thumbnail_tests {
+    use super::*;
+    use crate::rgb::RGB;
+
+    #[test]
+    fn fit_keeps_aspect_ratio() {
+        assert_eq!(fit(640, 480), (128, 96));
+        assert_eq!(fit(480, 640), (96, 128));
+        assert_eq!(fit(10000, 1), (128, 1));
+        assert_eq!(fit(50, 20), (50, 20));
+    }
+
+    #[test]
+    fn generate_averages() {
+        // Left half black, right half white
+        let chunks = (0..256 * 2)
+            .map(|i| match i % 256 < 128 {
+                true => Chunk::new(0, 0, 0),
+                false => Chunk::new(255, 255, 255),
+            })
+            .collect();
+        let file = CIEBIIFILE::try_from_chunks(256, 2, chunks).unwrap();
+
+        let thumbnail = generate(&file);
+
+        assert_eq!(thumbnail.dimensions(), (128, 1));
+        assert_eq!(thumbnail.get_at_index(0).unwrap().rgb(), RGB::new(0, 0, 0));
+        assert_eq!(thumbnail.get_at_index(127).unwrap().rgb(), RGB::new(255, 255, 255));
+    }
+}
@@ -0,0 +1,215 @@
+use super::{
+    checksum::ChecksumAlgorithm, chunk::Chunk, error::ChunkError, file::CIEBIIFILE,
+    section::Section,
+};
+
+/// A test vector: a file along with what parsing it must result in.
+/// Alternative implementations can check themselves against the corpus this crate generates.
+#[derive(Debug, Clone)]
+pub struct Vector {
+    /// A short unique name, usable as a file stem
+    pub name: &'static str,
+
+    /// What the vector checks
+    pub description: &'static str,
+
+    /// The contents of the file
+    pub bytes: Vec<u8>,
+
+    /// The dimensions of a valid file, or the name of the error an invalid one must fail with
+    pub expected: Result<(usize, usize), &'static str>,
+}
+
+/// Returns the name of an error as used in the manifest, like `"ChecksumFail"`.
+pub fn error_name(err: &ChunkError) -> &'static str {
+    match err {
+        ChunkError::InvalidLen => "InvalidLen",
+        ChunkError::ChecksumFail => "ChecksumFail",
+        ChunkError::IllegalHeader => "IllegalHeader",
+        ChunkError::NonExistentChunk => "NonExistentChunk",
+        ChunkError::DimensionMismatch => "DimensionMismatch",
+        ChunkError::TrailingData(_) => "TrailingData",
+        ChunkError::InvalidColor(_) => "InvalidColor",
+        ChunkError::PayloadTooLarge(..) => "PayloadTooLarge",
+        ChunkError::InvalidImage(_) => "InvalidImage",
+        ChunkError::ByteParseFail(_) => "ByteParseFail",
+        ChunkError::Io(_) => "Io",
+    }
+}
+
+/// A 2x2 file with four different colors
+fn quad() -> CIEBIIFILE {
+    let chunks = vec![
+        Chunk::new(0xAB, 0xCD, 0xEF),
+        Chunk::new(0x12, 0x34, 0x56),
+        Chunk::new(0x69, 0x42, 0x00),
+        Chunk::new(0xDE, 0xAD, 0xA5),
+    ];
+
+    CIEBIIFILE::try_from_chunks(2, 2, chunks).unwrap()
+}
+
+/// Returns `bytes` with the byte at `index` changed
+fn flip(mut bytes: Vec<u8>, index: usize) -> Vec<u8> {
+    bytes[index] ^= 0x01;
+    bytes
+}
+
+/// Returns the full corpus of valid and invalid vectors.
+/// ```
+/// use ciebii_lib::{file::CIEBIIFILE, vectors};
+/// for vector in vectors::corpus() {
+///     let result = CIEBIIFILE::try_from(vector.bytes.clone());
+///     assert_eq!(result.is_ok(), vector.expected.is_ok());
+/// }
+/// ```
+pub fn corpus() -> Vec<Vector> {
+    let single = CIEBIIFILE::try_from_chunks(1, 1, vec![Chunk::new(255, 0, 0)]).unwrap();
+    let wide = CIEBIIFILE::try_from_chunks(3, 1, vec![Chunk::new(0, 0, 0); 3]).unwrap();
+
+    let mut metadata = quad();
+    metadata.set_metadata(Section::EXIF, vec![0x4D, 0x4D, 0x00, 0x2A]);
+
+    let mut animated = quad();
+    animated.push_frame(quad().chunks().iter().rev().cloned().collect(), 100).unwrap();
+
+    // Readers must skip sections they don't know
+    let mut unknown = quad().as_bytes();
+    let note = Section::new(*b"NOTE", b"skip me".to_vec());
+    note.encode_into(&mut unknown, quad().checksum_algorithm())
+        .expect("sections hold less than 4 GiB");
+
+    // Files written before CRC-32 must still be read
+    let mut legacy = quad();
+    legacy.set_checksum_algorithm(ChecksumAlgorithm::Ciebii);
+
+    vec![
+        Vector {
+            name: "valid-1x1",
+            description: "A single red pixel",
+            bytes: single.as_bytes(),
+            expected: Ok((1, 1)),
+        },
+        Vector {
+            name: "valid-2x2",
+            description: "Four pixels of different colors",
+            bytes: quad().as_bytes(),
+            expected: Ok((2, 2)),
+        },
+        Vector {
+            name: "valid-3x1",
+            description: "A non-square image, rows come first",
+            bytes: wide.as_bytes(),
+            expected: Ok((3, 1)),
+        },
+        Vector {
+            name: "valid-empty",
+            description: "A 0x0 image without any chunks",
+            bytes: CIEBIIFILE::new(0, 0).as_bytes(),
+            expected: Ok((0, 0)),
+        },
+        Vector {
+            name: "valid-metadata",
+            description: "An EXIF section after the pixel data",
+            bytes: metadata.as_bytes(),
+            expected: Ok((2, 2)),
+        },
+        Vector {
+            name: "valid-animated",
+            description: "A second frame in an animation section",
+            bytes: animated.as_bytes(),
+            expected: Ok((2, 2)),
+        },
+        Vector {
+            name: "valid-unknown-section",
+            description: "A section with an unknown tag, which must be skipped",
+            bytes: unknown,
+            expected: Ok((2, 2)),
+        },
+        Vector {
+            name: "valid-legacy-checksum",
+            description: "Checksummed with the legacy ciebii checksum rather than CRC-32",
+            bytes: legacy.as_bytes(),
+            expected: Ok((2, 2)),
+        },
+        Vector {
+            name: "invalid-magic",
+            description: "The magic bytes are wrong",
+            bytes: flip(quad().as_bytes(), 0),
+            expected: Err("IllegalHeader"),
+        },
+        Vector {
+            name: "invalid-short-header",
+            description: "The file ends inside the header",
+            bytes: quad().as_bytes()[..20].to_vec(),
+            expected: Err("InvalidLen"),
+        },
+        Vector {
+            name: "invalid-header-checksum",
+            description: "The checksum of the dimensions doesn't match",
+            bytes: flip(quad().as_bytes(), 29),
+            expected: Err("ChecksumFail"),
+        },
+        Vector {
+            name: "invalid-chunk-checksum",
+            description: "The checksum of the first chunk doesn't match its color",
+            bytes: flip(quad().as_bytes(), 34),
+            expected: Err("ChecksumFail"),
+        },
+        Vector {
+            name: "invalid-legacy-chunk-checksum",
+            description: "The legacy checksum of the first chunk doesn't match its color",
+            bytes: flip(legacy.as_bytes(), 34),
+            expected: Err("ChecksumFail"),
+        },
+        Vector {
+            name: "invalid-missing-chunk",
+            description: "The last chunk is missing",
+            bytes: quad().as_bytes()[..45].to_vec(),
+            expected: Err("DimensionMismatch"),
+        },
+        Vector {
+            name: "invalid-trailing-data",
+            description: "Bytes after the pixel data that aren't a section",
+            bytes: [quad().as_bytes(), vec![0xFF, 0x00, 0x13]].concat(),
+            expected: Err("TrailingData"),
+        },
+        Vector {
+            name: "invalid-section-checksum",
+            description: "The checksum of a section doesn't match its payload",
+            bytes: flip(metadata.as_bytes(), metadata.as_bytes().len() - 1),
+            expected: Err("ChecksumFail"),
+        },
+        Vector {
+            name: "invalid-truncated-section",
+            description: "The file ends inside a section",
+            bytes: metadata.as_bytes()[..metadata.as_bytes().len() - 1].to_vec(),
+            expected: Err("InvalidLen"),
+        },
+    ]
+}
+
+#[cfg(test)]
+mod vectors_tests {
+    use super::*;
+
+    #[test]
+    fn corpus_matches_parser() {
+        for vector in corpus() {
+            let result = CIEBIIFILE::try_from(vector.bytes.clone())
+                .map(|file| file.dimensions())
+                .map_err(|err| error_name(&err));
+
+            assert_eq!(result, vector.expected, "{}", vector.name);
+        }
+    }
+
+    #[test]
+    fn unique_names() {
+        let mut names: Vec<_> = corpus().iter().map(|vector| vector.name).collect();
+        names.sort();
+        names.dedup();
+
+        assert_eq!(names.len(), corpus().len());
+    }
+}
@@ -0,0 +1,198 @@
+use std::io::Read;
+
+use super::{chunk::Chunk, error::ChunkError, header::Header, io::Decoder};
+
+/// Receives the parts of a ciebii file as `decode_with` parses them. Every callback does
+/// nothing by default, so visitors only implement the ones they care about.
+pub trait Visitor {
+    /// Called once the header has been read and checked, before any pixel
+    fn on_header(&mut self, _header: &Header) {}
+
+    /// Called with every row of pixels, from top to bottom, once all of its checksums passed.
+    /// Hands each pixel to `on_pixel` unless it's overridden.
+    fn on_row(&mut self, y: usize, chunks: &[Chunk]) {
+        for (x, chunk) in chunks.iter().enumerate() {
+            self.on_pixel(x, y, *chunk);
+        }
+    }
+
+    /// Called with every pixel from left to right, by the default `on_row`
+    fn on_pixel(&mut self, _x: usize, _y: usize, _chunk: Chunk) {}
+
+    /// Called with the error that stopped decoding, right before `decode_with` returns it
+    fn on_error(&mut self, _error: &ChunkError) {}
+}
+
+/// Decodes a ciebii file from `reader` in a single pass, handing each part to `visitor` as
+/// soon as it's parsed instead of building a `CIEBIIFILE`. Only one row, and the small batch
+/// of bytes a `Decoder` reads ahead, is held in memory at a time. Sections after the pixel
+/// data, like animation frames, are left unread.
+/// ```
+/// use ciebii_lib::{chunk::Chunk, file::CIEBIIFILE, visit::{decode_with, Visitor}};
+///
+/// // Adds up the red of every pixel
+/// struct Red(u64);
+///
+/// impl Visitor for Red {
+///     fn on_pixel(&mut self, _x: usize, _y: usize, chunk: Chunk) {
+///         self.0 += chunk.rgb().color().0 as u64;
+///     }
+/// }
+///
+/// let file = CIEBIIFILE::try_from_chunks(2, 2, vec![Chunk::new(100, 0, 0); 4]).unwrap();
+/// let mut red = Red(0);
+/// decode_with(&file.as_bytes()[..], &mut red).unwrap();
+///
+/// assert_eq!(red.0, 400);
+/// ```
+pub fn decode_with<R: Read, V: Visitor + ?Sized>(
+    reader: R,
+    visitor: &mut V,
+) -> Result<(), ChunkError> {
+    let result = visit(reader, visitor);
+
+    if let Err(err) = &result {
+        visitor.on_error(err);
+    }
+
+    result
+}
+
+fn visit<R: Read, V: Visitor + ?Sized>(reader: R, visitor: &mut V) -> Result<(), ChunkError> {
+    let mut decoder = Decoder::new(reader)?;
+    visitor.on_header(decoder.header());
+
+    // The row grows as chunks decode, the header alone can't make it allocate
+    let (width, height) = decoder.dimensions();
+    let mut row = Vec::new();
+
+    for y in 0..height {
+
+        // Images without pixels have no rows to hand out, however tall they claim to be
+        if decoder.remaining() == 0 {
+            break;
+        }
+
+        row.clear();
+        for chunk in decoder.by_ref().take(width) {
+            row.push(chunk?);
+        }
+
+        visitor.on_row(y, &row);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod visit_tests {
+    use super::*;
+    use crate::{checksum::ChecksumAlgorithm, chunk, file::CIEBIIFILE};
+
+    /// Records every callback it receives
+    #[derive(Default)]
+    struct Recorder {
+        dimensions: Option<(usize, usize)>,
+        pixels: Vec<(usize, usize, Chunk)>,
+        error: Option<String>,
+    }
+
+    impl Visitor for Recorder {
+        fn on_header(&mut self, header: &Header) {
+            self.dimensions = Some(header.dimensions());
+        }
+
+        fn on_pixel(&mut self, x: usize, y: usize, chunk: Chunk) {
+            self.pixels.push((x, y, chunk));
+        }
+
+        fn on_error(&mut self, error: &ChunkError) {
+            self.error = Some(error.to_string());
+        }
+    }
+
+    fn test_file() -> CIEBIIFILE {
+        let chunks = (0..6).map(|i| Chunk::new(i, 0, 0)).collect();
+
+        CIEBIIFILE::try_from_chunks(3, 2, chunks).unwrap()
+    }
+
+    #[test]
+    fn every_pixel() {
+        let mut recorder = Recorder::default();
+        decode_with(&test_file().as_bytes()[..], &mut recorder).unwrap();
+
+        assert_eq!(recorder.dimensions, Some((3, 2)));
+        assert_eq!(recorder.pixels.len(), 6);
+        assert_eq!(recorder.pixels[4], (1, 1, Chunk::new(4, 0, 0)));
+        assert!(recorder.error.is_none());
+    }
+
+    #[test]
+    fn rows_before_the_error() {
+        let mut bytes = test_file().as_bytes();
+        bytes[Header::LEN + 4 * Chunk::LEN] ^= 1;
+
+        let mut recorder = Recorder::default();
+        let result = decode_with(&bytes[..], &mut recorder);
+
+        if let Err(ChunkError::ChecksumFail) = result {
+        } else {
+            panic!()
+        }
+        assert_eq!(recorder.pixels.len(), 3);
+        assert_eq!(recorder.error, Some(ChunkError::ChecksumFail.to_string()));
+    }
+
+    #[test]
+    fn truncated_file() {
+        let bytes = test_file().as_bytes();
+
+        let mut recorder = Recorder::default();
+        let result = decode_with(&bytes[..bytes.len() - 1], &mut recorder);
+
+        if let Err(ChunkError::DimensionMismatch) = result {
+        } else {
+            panic!()
+        }
+        assert!(recorder.error.is_some());
+    }
+
+    #[test]
+    fn hostile_width() {
+        let mut bytes = Header::new(1 << 40, 1).as_bytes();
+        chunk::encode_into(&[Chunk::new(1, 2, 3)], false, ChecksumAlgorithm::Crc32, &mut bytes);
+
+        let mut recorder = Recorder::default();
+        let result = decode_with(&bytes[..], &mut recorder);
+
+        if let Err(ChunkError::DimensionMismatch) = result {
+        } else {
+            panic!()
+        }
+        assert!(recorder.pixels.is_empty());
+    }
+
+    #[test]
+    fn hostile_height() {
+        let bytes = Header::new(0, 1 << 62).as_bytes();
+
+        let mut recorder = Recorder::default();
+        decode_with(&bytes[..], &mut recorder).unwrap();
+
+        assert_eq!(recorder.dimensions, Some((0, 1 << 62)));
+        assert!(recorder.pixels.is_empty());
+    }
+
+    #[test]
+    fn compressed_file() {
+        let mut file = test_file();
+        file.set_compressed(true);
+
+        let mut recorder = Recorder::default();
+        decode_with(&file.as_bytes()[..], &mut recorder).unwrap();
+
+        assert_eq!(recorder.pixels.len(), 6);
+        assert_eq!(recorder.pixels[4], (1, 1, Chunk::new(4, 0, 0)));
+    }
+}
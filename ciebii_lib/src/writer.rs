@@ -0,0 +1,470 @@
+use std::io::{self, Write};
+
+use sha2::{Digest, Sha256};
+
+use super::{
+    checksum::ChecksumAlgorithm,
+    chunk::{self, Chunk},
+    error::ChunkError,
+    header::Header,
+    section::Section,
+};
+
+/// How the pixel data of a file is encoded
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Codec {
+    /// One chunk per pixel, as is
+    #[default]
+    Raw,
+
+    /// Runs of equal chunks, each run stored as its length and a single chunk
+    Rle,
+}
+
+/// Controls the on-disk flavor a file is written in, so one in-memory file can be written
+/// however a consumer needs it. The defaults write the same bytes as `CIEBIIFILE::as_bytes`.
+/// ```
+/// use ciebii_lib::{chunk::Chunk, file::CIEBIIFILE, writer::WriteOptions};
+/// let file = CIEBIIFILE::try_from_chunks(1, 1, vec![Chunk::new(255, 0, 0)]).unwrap();
+///
+/// let options = WriteOptions::default().footer(true);
+/// let bytes = file.as_bytes_with(&options).unwrap();
+/// assert_eq!(CIEBIIFILE::try_from(bytes).unwrap(), file);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct WriteOptions {
+    version: u8,
+    checksum: Option<ChecksumAlgorithm>,
+    codec: Option<Codec>,
+    thumbnail: Option<bool>,
+    footer: bool,
+}
+
+impl WriteOptions {
+    /// Sets the format version, `Header::VERSION` unless set
+    pub fn version(mut self, version: u8) -> Self {
+        self.version = version;
+        self
+    }
+
+    /// Sets how the pixels and sections are checksummed. Unless set the file keeps its own
+    /// checksums.
+    pub fn checksum(mut self, checksum: ChecksumAlgorithm) -> Self {
+        self.checksum = Some(checksum);
+        self
+    }
+
+    /// Sets how the pixel data is encoded. Unless set the file keeps its own encoding.
+    pub fn codec(mut self, codec: Codec) -> Self {
+        self.codec = Some(codec);
+        self
+    }
+
+    /// Embeds a thumbnail, generating one if the file has none, or leaves any thumbnail out.
+    /// Unless set the file's own thumbnail is written if it has one.
+    pub fn thumbnail(mut self, thumbnail: bool) -> Self {
+        self.thumbnail = Some(thumbnail);
+        self
+    }
+
+    /// Ends the file with a footer section holding a SHA-256 digest of every byte before it,
+    /// which readers verify
+    pub fn footer(mut self, footer: bool) -> Self {
+        self.footer = footer;
+        self
+    }
+
+    /// Returns whether a thumbnail is embedded or left out, `None` if the file decides
+    pub fn includes_thumbnail(&self) -> Option<bool> {
+        self.thumbnail
+    }
+
+    /// Returns how the pixels and sections are checksummed, `None` if the file decides
+    pub fn checksums_with(&self) -> Option<ChecksumAlgorithm> {
+        self.checksum
+    }
+
+    /// Returns how the pixel data is encoded, `None` if the file decides
+    pub fn encodes_with(&self) -> Option<Codec> {
+        self.codec
+    }
+
+    /// Returns whether a footer is written
+    pub fn includes_footer(&self) -> bool {
+        self.footer
+    }
+
+    /// Fails with `IllegalHeader` for a format version this library can't write
+    pub(crate) fn check(&self) -> Result<(), ChunkError> {
+        if self.version != Header::VERSION {
+            return Err(ChunkError::IllegalHeader);
+        }
+
+        Ok(())
+    }
+}
+
+/// Passes everything written on to `inner`, keeping a SHA-256 digest of it for the footer.
+pub(crate) struct DigestWriter<W: Write> {
+    inner: W,
+    hasher: Sha256,
+}
+
+impl<W: Write> DigestWriter<W> {
+    pub(crate) fn new(inner: W) -> Self {
+        Self {
+            inner,
+            hasher: Sha256::new(),
+        }
+    }
+
+    /// Writes the footer section holding the digest of everything written so far, its own
+    /// checksum worked out with `checksum`
+    pub(crate) fn write_footer(mut self, checksum: ChecksumAlgorithm) -> io::Result<()> {
+        let digest = self.hasher.finalize().to_vec();
+
+        Section::new(Section::FOOTER, digest).write_into(&mut self.inner, checksum)
+    }
+}
+
+impl<W: Write> Write for DigestWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        self.hasher.update(&buf[..written]);
+
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// Adds up the size of a file in every encoding as its chunks are fed in, without keeping
+/// them, so it can be told before anything is written. Sections aren't counted.
+/// ```
+/// use ciebii_lib::{chunk::Chunk, writer::{Codec, SizeEstimate}};
+/// let mut estimate = SizeEstimate::default();
+/// estimate.push(&[Chunk::new(255, 0, 0); 4]);
+///
+/// assert_eq!(estimate.size(Codec::Raw, false), 30 + 4 * 5);
+/// assert_eq!(estimate.size(Codec::Rle, false), 30 + 1 + 5);
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct SizeEstimate {
+    chunks: usize,
+    alpha: bool,
+
+    // The runs the chunks make up when stored without and with their alpha
+    rgb: Runs,
+    rgba: Runs,
+}
+
+impl SizeEstimate {
+
+    /// Counts `chunks` as the next ones of the file
+    pub fn push(&mut self, chunks: &[Chunk]) {
+        for chunk in chunks {
+            let (r, g, b) = chunk.rgb().color();
+
+            self.rgb.push(Chunk::new(r, g, b));
+            self.rgba.push(*chunk);
+            self.alpha |= !chunk.is_opaque();
+        }
+
+        self.chunks += chunks.len();
+    }
+
+    /// Returns whether any of the chunks isn't fully opaque, so storing the alpha matters
+    pub fn has_alpha(&self) -> bool {
+        self.alpha
+    }
+
+    /// Returns the size in bytes of the header and the chunks encoded with `codec`, with an
+    /// alpha byte in every chunk if `alpha` is set
+    pub fn size(&self, codec: Codec, alpha: bool) -> usize {
+        let runs = match alpha {
+            true => self.rgba.count,
+            false => self.rgb.count,
+        };
+
+        Header::LEN
+            + match codec {
+                Codec::Raw => self.chunks * chunk::len(alpha),
+                Codec::Rle => runs * (1 + chunk::len(alpha)),
+            }
+    }
+}
+
+/// Counts runs of equal chunks the way `Codec::Rle` stores them
+#[derive(Debug, Default, Clone)]
+struct Runs {
+    count: usize,
+
+    // The chunk of the current run and how long it is
+    last: Option<(Chunk, usize)>,
+}
+
+impl Runs {
+    fn push(&mut self, chunk: Chunk) {
+        match &mut self.last {
+            Some((last, len)) if *last == chunk && *len < chunk::MAX_RUN => *len += 1,
+            last => {
+                *last = Some((chunk, 1));
+                self.count += 1;
+            }
+        }
+    }
+}
+
+/// Writes a ciebii file chunk by chunk, so images too large for memory can be written
+/// row by row. Sections may follow once every chunk is written. The file has no alpha
+/// channel, so every chunk is written as if it were opaque.
+///
+/// ```
+/// use ciebii_lib::{chunk::Chunk, writer::CiebiiWriter};
+/// let mut writer = CiebiiWriter::new(Vec::new(), 2, 1).unwrap();
+/// writer.write_row(&[Chunk::new(255, 0, 0), Chunk::new(0, 0, 255)]).unwrap();
+/// let bytes = writer.finish().unwrap();
+/// ```
+pub struct CiebiiWriter<W: Write> {
+    inner: W,
+    header: Header,
+    written: usize,
+}
+
+impl<W: Write> CiebiiWriter<W> {
+    /// Writes the header of a `x` by `y` file to `inner`
+    pub fn new(mut inner: W, x: usize, y: usize) -> Result<Self, ChunkError> {
+        let header = Header::new(x, y);
+
+        inner.write_all(&header.as_bytes())?;
+
+        Ok(Self {
+            inner,
+            header,
+            written: 0,
+        })
+    }
+
+    /// Returns the number of chunks the file still needs
+    pub fn remaining(&self) -> usize {
+        let (x, y) = self.header.dimensions();
+
+        x * y - self.written
+    }
+
+    /// Writes a single chunk
+    pub fn write_chunk(&mut self, chunk: Chunk) -> Result<(), ChunkError> {
+        self.write_row(&[chunk])
+    }
+
+    /// Writes a run of chunks, usually a row of the image
+    pub fn write_row(&mut self, chunks: &[Chunk]) -> Result<(), ChunkError> {
+
+        // More chunks than the header promised
+        if chunks.len() > self.remaining() {
+            return Err(ChunkError::DimensionMismatch);
+        }
+
+        let mut bytes = Vec::new();
+        chunk::encode_into(chunks, false, self.header.checksum_algorithm(), &mut bytes);
+        self.inner.write_all(&bytes)?;
+
+        self.written += chunks.len();
+
+        Ok(())
+    }
+
+    /// Writes a section after the pixel data
+    pub fn write_section(&mut self, section: &Section) -> Result<(), ChunkError> {
+
+        // Sections can't be mixed in with the chunks
+        if self.remaining() != 0 {
+            return Err(ChunkError::DimensionMismatch);
+        }
+
+        section.write_into(&mut self.inner, self.header.checksum_algorithm())?;
+
+        Ok(())
+    }
+
+    /// Flushes the file and returns the inner writer
+    pub fn finish(mut self) -> Result<W, ChunkError> {
+        if self.remaining() != 0 {
+            return Err(ChunkError::DimensionMismatch);
+        }
+
+        self.inner.flush()?;
+
+        Ok(self.inner)
+    }
+}
+
+#[cfg(test)]
+mod writer_tests {
+    use crate::{
+        chunk::Chunk, error::ChunkError, file::CIEBIIFILE, header::Header, section::Section,
+    };
+
+    use super::{ChecksumAlgorithm, CiebiiWriter, Codec, SizeEstimate, WriteOptions};
+
+    #[test]
+    fn matches_file() {
+        let chunks = vec![
+            Chunk::new(0xAB, 0xCD, 0xEF),
+            Chunk::new(0x12, 0x34, 0x56),
+            Chunk::new(0x69, 0x42, 0x00),
+            Chunk::new(0xDE, 0xAD, 0xA5),
+        ];
+
+        let mut file = CIEBIIFILE::try_from_chunks(2, 2, chunks.clone()).unwrap();
+        file.set_metadata(Section::EXIF, vec![1, 2, 3]);
+
+        let mut writer = CiebiiWriter::new(Vec::new(), 2, 2).unwrap();
+        writer.write_row(&chunks[..2]).unwrap();
+        writer.write_row(&chunks[2..]).unwrap();
+        writer.write_section(&Section::new(Section::EXIF, vec![1, 2, 3])).unwrap();
+
+        assert_eq!(writer.finish().unwrap(), file.as_bytes());
+
+        let mut estimate = SizeEstimate::default();
+        estimate.push(&chunks);
+        assert_eq!(estimate.size(Codec::Raw, false) + 13, file.as_bytes().len());
+    }
+
+    #[test]
+    fn size_estimate() {
+        let mut chunks = vec![Chunk::new(1, 2, 3); 300];
+        chunks.extend([Chunk::with_alpha(1, 2, 3, 4), Chunk::new(5, 6, 7)]);
+
+        let mut estimate = SizeEstimate::default();
+        estimate.push(&chunks[..150]);
+        estimate.push(&chunks[150..]);
+        assert!(estimate.has_alpha());
+
+        // Every encoding matches what's written
+        let mut file = CIEBIIFILE::try_from_chunks(302, 1, chunks.clone()).unwrap();
+        for codec in [Codec::Raw, Codec::Rle] {
+            let options = WriteOptions::default().codec(codec);
+            assert_eq!(estimate.size(codec, true), file.as_bytes_with(&options).unwrap().len());
+        }
+
+        // Without the alpha the translucent chunk joins the first run
+        let opaque = chunks.iter().map(|chunk| {
+            let (r, g, b) = chunk.rgb().color();
+            Chunk::new(r, g, b)
+        });
+        file = CIEBIIFILE::try_from_chunks(302, 1, opaque.collect()).unwrap();
+        for codec in [Codec::Raw, Codec::Rle] {
+            let options = WriteOptions::default().codec(codec);
+            assert_eq!(estimate.size(codec, false), file.as_bytes_with(&options).unwrap().len());
+        }
+        assert_eq!(estimate.size(Codec::Rle, false), Header::LEN + 3 * 6);
+    }
+
+    #[test]
+    fn too_few_chunks() {
+        let mut writer = CiebiiWriter::new(Vec::new(), 2, 2).unwrap();
+        writer.write_chunk(Chunk::new(0, 0, 0)).unwrap();
+
+        let err = writer.finish().unwrap_err();
+
+        if let ChunkError::DimensionMismatch = err {
+        } else {
+            panic!()
+        }
+    }
+
+    #[test]
+    fn too_many_chunks() {
+        let mut writer = CiebiiWriter::new(Vec::new(), 1, 1).unwrap();
+
+        assert!(writer
+            .write_row(&[Chunk::new(0, 0, 0), Chunk::new(0, 0, 0)])
+            .is_err());
+    }
+
+    #[test]
+    fn default_options() {
+        let mut file = CIEBIIFILE::try_from_chunks(1, 1, vec![Chunk::new(1, 2, 3)]).unwrap();
+        file.set_metadata(Section::EXIF, vec![1, 2, 3]);
+
+        assert_eq!(file.as_bytes_with(&WriteOptions::default()).unwrap(), file.as_bytes());
+    }
+
+    #[test]
+    fn footer() {
+        let file = CIEBIIFILE::try_from_chunks(1, 1, vec![Chunk::new(1, 2, 3)]).unwrap();
+        let mut bytes = file.as_bytes_with(&WriteOptions::default().footer(true)).unwrap();
+
+        assert_eq!(bytes.len(), file.as_bytes().len() + 42);
+        assert_eq!(CIEBIIFILE::try_from(&bytes[..]).unwrap(), file);
+
+        // The section checksum still passes, the digest doesn't
+        bytes[Header::LEN] = 9;
+        bytes[Header::LEN + 3..Header::LEN + 5]
+            .copy_from_slice(&file.checksum_algorithm().checksum(&[9, 2, 3]).to_be_bytes());
+        if let Err(ChunkError::ChecksumFail) = CIEBIIFILE::try_from(&bytes[..]) {
+        } else {
+            panic!()
+        }
+    }
+
+    #[test]
+    fn thumbnail() {
+        let file = CIEBIIFILE::try_from_chunks(1, 1, vec![Chunk::new(1, 2, 3)]).unwrap();
+
+        let with = file.as_bytes_with(&WriteOptions::default().thumbnail(true)).unwrap();
+        let with = CIEBIIFILE::try_from(with).unwrap();
+        assert!(with.thumbnail().unwrap().is_some());
+
+        let without = with.as_bytes_with(&WriteOptions::default().thumbnail(false)).unwrap();
+        assert_eq!(without, file.as_bytes());
+    }
+
+    #[test]
+    fn codec() {
+        let file = CIEBIIFILE::try_from_chunks(4, 4, vec![Chunk::new(1, 2, 3); 16]).unwrap();
+
+        let compressed = file.as_bytes_with(&WriteOptions::default().codec(Codec::Rle)).unwrap();
+        assert_eq!(compressed.len(), Header::LEN + 1 + Chunk::LEN);
+
+        let compressed = CIEBIIFILE::try_from(compressed).unwrap();
+        assert!(compressed.is_compressed());
+        assert_eq!(compressed.chunks(), file.chunks());
+
+        // Files keep their own encoding unless told otherwise
+        let bytes = compressed.as_bytes_with(&WriteOptions::default()).unwrap();
+        assert_eq!(bytes, compressed.as_bytes());
+
+        let raw = compressed.as_bytes_with(&WriteOptions::default().codec(Codec::Raw)).unwrap();
+        assert_eq!(raw, file.as_bytes());
+    }
+
+    #[test]
+    fn checksum() {
+        let mut file = CIEBIIFILE::try_from_chunks(1, 1, vec![Chunk::new(1, 2, 3)]).unwrap();
+        file.set_checksum_algorithm(ChecksumAlgorithm::Ciebii);
+
+        // Files keep their own checksums unless told otherwise
+        assert_eq!(file.as_bytes_with(&WriteOptions::default()).unwrap(), file.as_bytes());
+
+        let options = WriteOptions::default().checksum(ChecksumAlgorithm::Crc32).footer(true);
+        let crc = CIEBIIFILE::try_from(file.as_bytes_with(&options).unwrap()).unwrap();
+        assert_eq!(crc.checksum_algorithm(), ChecksumAlgorithm::Crc32);
+        assert_eq!(crc.chunks(), file.chunks());
+    }
+
+    #[test]
+    fn unknown_version() {
+        let file = CIEBIIFILE::try_from_chunks(1, 1, vec![Chunk::new(1, 2, 3)]).unwrap();
+
+        let options = WriteOptions::default().version(1);
+
+        if let Err(ChunkError::IllegalHeader) = file.as_bytes_with(&options) {
+        } else {
+            panic!()
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use macroquad::{
+    color::{Color, WHITE},
+    input::{is_key_pressed, KeyCode},
+    shapes::draw_rectangle,
+    text::{draw_text, measure_text},
+    window::{screen_height, screen_width},
+};
+
+const FONT_SIZE: f32 = 18.0;
+const PADDING: f32 = 6.0;
+
+/// How much a key press changes the exposure, in stops
+const EXPOSURE_STEP: f32 = 0.5;
+
+/// How much a key press changes the gamma
+const GAMMA_STEP: f32 = 0.1;
+
+/// A view-only exposure/gamma adjustment of the displayed image.
+/// The file itself and exported images are never touched.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Adjustment {
+    /// Exposure in stops, each stop doubles the brightness
+    exposure: f32,
+    gamma: f32,
+}
+
+impl Default for Adjustment {
+    fn default() -> Self {
+        Self {
+            exposure: 0.0,
+            gamma: 1.0,
+        }
+    }
+}
+
+impl Adjustment {
+    /// Handles [ and ] for exposure, ; and ' for gamma and \ to reset.
+    /// Returns whether the adjustment changed.
+    pub fn handle_input(&mut self) -> bool {
+        let before = *self;
+
+        if is_key_pressed(KeyCode::LeftBracket) {
+            self.exposure -= EXPOSURE_STEP;
+        }
+        if is_key_pressed(KeyCode::RightBracket) {
+            self.exposure += EXPOSURE_STEP;
+        }
+        if is_key_pressed(KeyCode::Semicolon) {
+            self.gamma = (self.gamma - GAMMA_STEP).max(GAMMA_STEP);
+        }
+        if is_key_pressed(KeyCode::Apostrophe) {
+            self.gamma += GAMMA_STEP;
+        }
+        if is_key_pressed(KeyCode::Backslash) {
+            *self = Self::default();
+        }
+
+        *self != before
+    }
+
+    /// Returns whether the image is shown as is
+    pub fn is_neutral(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Builds a lookup table mapping every channel value to its adjusted value.
+    pub fn lut(&self) -> [u8; 256] {
+        let gain = 2f32.powf(self.exposure);
+
+        let mut lut = [0; 256];
+        for (i, value) in lut.iter_mut().enumerate() {
+            let adjusted = ((i as f32 / 255.0) * gain).min(1.0).powf(1.0 / self.gamma);
+            *value = (adjusted * 255.0).round() as u8;
+        }
+
+        lut
+    }
+
+    /// Draws the current exposure and gamma in the bottom right corner unless the image is unadjusted.
+    pub fn draw(&self) {
+        if self.is_neutral() {
+            return;
+        }
+
+        let text = format!("exposure {:+.1} EV  gamma {:.1}", self.exposure, self.gamma);
+        let size = measure_text(&text, None, FONT_SIZE as u16, 1.0);
+        let x = screen_width() - size.width - PADDING * 3.0;
+        let y = screen_height() - size.height - PADDING * 3.0;
+
+        draw_rectangle(
+            x,
+            y,
+            size.width + PADDING * 2.0,
+            size.height + PADDING * 2.0,
+            Color::new(0.0, 0.0, 0.0, 0.75),
+        );
+        draw_text(&text, x + PADDING, y + PADDING + size.offset_y, FONT_SIZE, WHITE);
+    }
+}
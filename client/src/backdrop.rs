@@ -0,0 +1,84 @@
+use macroquad::{
+    color::{Color, BLACK, WHITE},
+    input::{is_key_pressed, KeyCode},
+    math::Rect,
+    shapes::draw_rectangle,
+    window::{screen_height, screen_width},
+};
+
+/// The size of a checkerboard square in screen pixels
+const SQUARE: f32 = 8.0;
+
+const LIGHT: Color = Color::new(0.8, 0.8, 0.8, 1.0);
+const DARK: Color = Color::new(0.6, 0.6, 0.6, 1.0);
+
+/// What transparent pixels are composited over. Cycled with B.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Backdrop {
+    #[default]
+    Checkerboard,
+    Black,
+    White,
+}
+
+impl Backdrop {
+    /// Cycles to the next backdrop when B is pressed.
+    pub fn handle_input(&mut self) {
+        if is_key_pressed(KeyCode::B) {
+            *self = match self {
+                Backdrop::Checkerboard => Backdrop::Black,
+                Backdrop::Black => Backdrop::White,
+                Backdrop::White => Backdrop::Checkerboard,
+            };
+        }
+    }
+
+    /// Fills `area` with the backdrop. Must be drawn before the image itself.
+    pub fn draw(&self, area: Rect) {
+
+        // Clip to the window so huge zoomed images don't draw thousands of hidden squares
+        let left = area.x.max(0.0);
+        let top = area.y.max(0.0);
+        let right = (area.x + area.w).min(screen_width());
+        let bottom = (area.y + area.h).min(screen_height());
+
+        if right <= left || bottom <= top {
+            return;
+        }
+
+        match self {
+            Backdrop::Black => draw_rectangle(left, top, right - left, bottom - top, BLACK),
+            Backdrop::White => draw_rectangle(left, top, right - left, bottom - top, WHITE),
+            Backdrop::Checkerboard => {
+                draw_rectangle(left, top, right - left, bottom - top, LIGHT);
+
+                // Squares are anchored to the image so they scroll along with it
+                let first_column = ((left - area.x) / SQUARE) as usize;
+                let first_row = ((top - area.y) / SQUARE) as usize;
+
+                let mut row = first_row;
+                let mut y = area.y + row as f32 * SQUARE;
+                while y < bottom {
+                    let mut column = first_column;
+                    let mut x = area.x + column as f32 * SQUARE;
+                    while x < right {
+                        if (row + column) % 2 == 1 {
+                            let (square_x, square_y) = (x.max(left), y.max(top));
+                            draw_rectangle(
+                                square_x,
+                                square_y,
+                                (x + SQUARE).min(right) - square_x,
+                                (y + SQUARE).min(bottom) - square_y,
+                                DARK,
+                            );
+                        }
+                        column += 1;
+                        x += SQUARE;
+                    }
+                    row += 1;
+                    y += SQUARE;
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,140 @@
+use std::{
+    collections::HashSet,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::Context;
+use colored::*;
+
+use crate::{
+    cache::Cache,
+    convert::{self, ConvertOptions},
+    gallery::IMAGE_EXTENSIONS,
+};
+
+/// How many characters the progress bar takes
+const BAR_WIDTH: usize = 30;
+
+/// Converts every image in `src` and its subdirectories into a ciebii file in `out`, laid out
+/// like `src`. Images that fail don't stop the others, they're listed at the end and make the
+/// whole batch fail.
+pub fn convert_dir(
+    src: &Path,
+    out: &Path,
+    options: ConvertOptions,
+    cache: Option<&Cache>,
+) -> anyhow::Result<()> {
+    // The results may go inside the source directory, they aren't sources themselves
+    let skip = out.canonicalize().ok();
+
+    let mut images = Vec::new();
+    find_images(src, skip.as_deref(), &mut images)?;
+    images.sort();
+
+    let total = images.len();
+    println!(
+        "📁 {} {} {}",
+        "Converting".bold(),
+        format!("{total} images").white().bold(),
+        format!("from '{}' into '{}'", src.display(), out.display()).bold()
+    );
+
+    let mut written = HashSet::new();
+    let mut failures = Vec::new();
+
+    for (done, path) in images.iter().enumerate() {
+        let relative = path.strip_prefix(src).unwrap_or(path);
+        draw_progress(done, total, relative);
+
+        if let Err(err) = convert_one(path, relative, out, options, cache, &mut written) {
+            eprintln!(
+                "{} {}: {err:#}",
+                "Failed to convert".red().bold(),
+                format!("'{}'", path.display()).white().bold()
+            );
+            failures.push(path);
+        }
+    }
+
+    println!(
+        "✨ {} {}{}",
+        "Converted".green().bold(),
+        format!("{} of {total} images", total - failures.len()).white().bold(),
+        match failures.len() {
+            0 => String::new(),
+            failed => format!(", {failed} failed").red().bold().to_string(),
+        }
+    );
+
+    if !failures.is_empty() {
+        for path in &failures {
+            println!("  {}", format!("'{}'", path.display()).red());
+        }
+
+        anyhow::bail!("{} of {total} images failed to convert", failures.len());
+    }
+
+    Ok(())
+}
+
+/// Converts the image at `path` into the spot `relative` has in `out`. `written` holds every
+/// result so far, so two sources that only differ in their extension don't overwrite each
+/// other.
+fn convert_one(
+    path: &Path,
+    relative: &Path,
+    out: &Path,
+    options: ConvertOptions,
+    cache: Option<&Cache>,
+    written: &mut HashSet<PathBuf>,
+) -> anyhow::Result<()> {
+    let o = out.join(relative).with_extension(options.target.extension());
+
+    if !written.insert(o.clone()) {
+        anyhow::bail!("another image was already converted to '{}'", o.display());
+    }
+
+    if let Some(dir) = o.parent().filter(|_| !options.dry_run) {
+        fs::create_dir_all(dir).with_context(|| format!("Failed to create '{}'", dir.display()))?;
+    }
+
+    convert::convert_into(path, &o, options, cache)
+}
+
+/// Collects the convertible images in `dir` and its subdirectories, leaving out `skip`.
+fn find_images(dir: &Path, skip: Option<&Path>, images: &mut Vec<PathBuf>) -> anyhow::Result<()> {
+    let entries =
+        fs::read_dir(dir).with_context(|| format!("Failed to read directory '{}'", dir.display()))?;
+
+    for entry in entries {
+        let path = entry?.path();
+
+        if path.is_dir() {
+            if path.canonicalize().ok().as_deref() != skip {
+                find_images(&path, skip, images)?;
+            }
+        } else if IMAGE_EXTENSIONS
+            .iter()
+            .any(|extension| convert::has_extension(&path, extension))
+        {
+            images.push(path);
+        }
+    }
+
+    Ok(())
+}
+
+/// Prints a progress bar naming the image about to be converted, above the steps of its
+/// conversion.
+fn draw_progress(done: usize, total: usize, current: &Path) {
+    let filled = done * BAR_WIDTH / total.max(1);
+
+    println!(
+        "{}{} {} {}",
+        "█".repeat(filled).green(),
+        "░".repeat(BAR_WIDTH - filled).dimmed(),
+        format!("{}/{total}", done + 1).bold(),
+        format!("'{}'", current.display()).white().bold()
+    );
+}
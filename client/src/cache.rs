@@ -0,0 +1,85 @@
+use std::{
+    fs::{self, File},
+    io,
+    path::{Path, PathBuf},
+};
+
+use anyhow::Context;
+use ciebii_lib::io::write_atomic;
+use sha2::{Digest, Sha256};
+
+use crate::convert::ConvertOptions;
+
+/// A directory of converted files, each named after a hash of its source and the options it was
+/// converted with. Converting an unchanged file again just copies the earlier result.
+pub struct Cache {
+    dir: PathBuf,
+}
+
+impl Cache {
+
+    /// Opens the cache in `dir`, creating the directory if needed.
+    pub fn open(dir: &Path) -> anyhow::Result<Self> {
+        fs::create_dir_all(dir)
+            .with_context(|| format!("Failed to create the cache directory '{}'", dir.display()))?;
+
+        Ok(Self {
+            dir: dir.to_path_buf(),
+        })
+    }
+
+    /// Returns the key of converting `source` with `options`. It hashes the bytes of the source
+    /// rather than its decoded pixels, so a hit doesn't need to decode anything. Upgrading the
+    /// CLI starts over with fresh keys.
+    pub fn key(source: &Path, options: &ConvertOptions) -> anyhow::Result<String> {
+        let mut hasher = Sha256::new();
+        io::copy(&mut File::open(source)?, &mut hasher)?;
+
+        hasher.update(env!("CARGO_PKG_VERSION"));
+        hasher.update(format!(
+            "{:?} {} {} {} {:?}",
+            options.alpha,
+            options.keep_metadata,
+            options.thumbnail,
+            options.target.extension(),
+            options.write
+        ));
+
+        if let Some(palette) = options.palette {
+            hasher.update(b"palette");
+            for color in palette.colors() {
+                hasher.update(color.as_bytes());
+            }
+        }
+
+        Ok(hasher
+            .finalize()
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect())
+    }
+
+    /// Copies the cached result for `key` to `output`.
+    /// Returns `false` without touching `output` if there is none.
+    pub fn restore(&self, key: &str, output: &Path) -> anyhow::Result<bool> {
+        let cached = self.dir.join(key);
+        if !cached.exists() {
+            return Ok(false);
+        }
+
+        write_atomic(output, |file| -> anyhow::Result<()> {
+            io::copy(&mut File::open(&cached)?, file)?;
+            Ok(())
+        })?;
+
+        Ok(true)
+    }
+
+    /// Stores the freshly converted `output` as the result for `key`.
+    pub fn store(&self, key: &str, output: &Path) -> anyhow::Result<()> {
+        write_atomic(&self.dir.join(key), |file| -> anyhow::Result<()> {
+            io::copy(&mut File::open(output)?, file)?;
+            Ok(())
+        })
+    }
+}
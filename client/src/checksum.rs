@@ -0,0 +1,44 @@
+use std::{fs, path::Path};
+
+use anyhow::Context;
+use ciebii_lib::{file::CIEBIIFILE, header::Header};
+use sha2::{Digest, Sha256};
+
+/// Prints the checksum stored in the header of `file_name` and a SHA-256 digest of the whole
+/// file, along with a digest of every row and the offset it starts at with `rows`.
+/// Each value goes on its own line so sync tools can compare two copies without the pixels.
+pub fn checksum(file_name: &str, rows: bool) -> anyhow::Result<()> {
+    let path = Path::new(file_name);
+    let bytes = fs::read(path).with_context(|| format!("Failed to read '{}'", path.display()))?;
+
+    let header = bytes
+        .get(..Header::LEN)
+        .and_then(|header| Header::try_from(header.to_vec()).ok())
+        .with_context(|| format!("'{}' doesn't start with a ciebii header", path.display()))?;
+
+    println!("header {:08x}", header.checksum());
+    println!("sha256 {}", hex(&Sha256::digest(&bytes)));
+
+    if rows {
+        let file = CIEBIIFILE::try_from(&bytes[..])
+            .with_context(|| format!("Failed to decode '{}'", path.display()))?;
+        let (width, _) = file.dimensions();
+
+        anyhow::ensure!(
+            !file.is_compressed(),
+            "The rows of '{}' don't start at fixed offsets, it's compressed",
+            path.display()
+        );
+
+        for (y, digest) in file.row_hashes().iter().enumerate() {
+            let offset = Header::LEN + y * width * file.chunk_len();
+            println!("row {y} {offset} {}", hex(digest));
+        }
+    }
+
+    Ok(())
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
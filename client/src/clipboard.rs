@@ -0,0 +1,32 @@
+use std::borrow::Cow;
+
+use arboard::{Clipboard, ImageData};
+use ciebii_lib::file::CIEBIIFILE;
+use image::{DynamicImage, RgbaImage};
+
+use crate::decode;
+
+/// Places a frame of `file` on the clipboard as an image.
+pub fn copy_image(clipboard: &mut Clipboard, file: &CIEBIIFILE, frame: usize) -> anyhow::Result<()> {
+    let image = DynamicImage::ImageRgb8(decode::to_image(file, frame)).into_rgba8();
+
+    clipboard.set_image(ImageData {
+        width: image.width() as usize,
+        height: image.height() as usize,
+        bytes: Cow::Owned(image.into_raw()),
+    })?;
+
+    Ok(())
+}
+
+/// Reads the image currently on the clipboard.
+pub fn paste_image() -> anyhow::Result<DynamicImage> {
+    let image = Clipboard::new()?.get_image()?;
+
+    let (width, height) = (image.width as u32, image.height as u32);
+
+    let image = RgbaImage::from_raw(width, height, image.bytes.into_owned())
+        .ok_or_else(|| anyhow::anyhow!("The clipboard image has an invalid size"))?;
+
+    Ok(DynamicImage::ImageRgba8(image))
+}
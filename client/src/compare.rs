@@ -0,0 +1,223 @@
+use ciebii_lib::file::CIEBIIFILE;
+use macroquad::{
+    color::{Color, WHITE},
+    input::{
+        is_key_pressed, is_mouse_button_down, is_mouse_button_pressed, mouse_position, KeyCode,
+        MouseButton,
+    },
+    math::{vec2, Rect},
+    shapes::draw_rectangle,
+    text::{draw_text, measure_text},
+    texture::{FilterMode, Texture2D},
+    window::{screen_height, screen_width},
+};
+
+use crate::render;
+
+const FONT_SIZE: f32 = 18.0;
+const PADDING: f32 = 6.0;
+
+/// The size of the sensitivity slider's track
+const SLIDER_WIDTH: f32 = 200.0;
+const SLIDER_HEIGHT: f32 = 8.0;
+
+/// The range of the sensitivity, a factor the per-pixel distance is amplified by
+const MIN_SENSITIVITY: f32 = 1.0;
+const MAX_SENSITIVITY: f32 = 100.0;
+
+/// How much , and . change the sensitivity
+const SENSITIVITY_STEP: f32 = 1.25;
+
+/// The largest possible distance between two colors
+const MAX_DISTANCE: f32 = 441.673; // sqrt(3 * 255²)
+
+/// Compares two images of the same size. Besides flipping between them like tabs,
+/// D toggles a heatmap of how far apart every pixel is, where identical pixels stay black.
+/// The sensitivity is set with the slider at the bottom or , and .
+#[derive(Debug)]
+pub struct Compare {
+    // The distance between the two images at every pixel, from 0 to 1
+    distances: Vec<f32>,
+    dimensions: (u16, u16),
+
+    enabled: bool,
+    sensitivity: f32,
+    dragging: bool,
+
+    // The heatmap at the current sensitivity, uploaded while the view is shown
+    texture: Option<Texture2D>,
+}
+
+impl Compare {
+    /// Compares the first frames of `a` and `b`, which have to be the same size.
+    pub fn new(a: &CIEBIIFILE, b: &CIEBIIFILE) -> anyhow::Result<Self> {
+        let ((ax, ay), (bx, by)) = (a.dimensions(), b.dimensions());
+        if (ax, ay) != (bx, by) {
+            anyhow::bail!("Can't compare a {ax}x{ay} image to a {bx}x{by} one");
+        }
+
+        let dimensions = render::texture_size(a.dimensions())?;
+
+        let distances = a
+            .chunks()
+            .iter()
+            .zip(b.chunks())
+            .map(|(a, b)| {
+                let (a, b) = (a.rgb().color(), b.rgb().color());
+                let channels = [(a.0, b.0), (a.1, b.1), (a.2, b.2)];
+                let squared: f32 = channels
+                    .iter()
+                    .map(|(a, b)| (*a as f32 - *b as f32).powi(2))
+                    .sum();
+
+                squared.sqrt() / MAX_DISTANCE
+            })
+            .collect();
+
+        Ok(Self {
+            distances,
+            dimensions,
+            enabled: false,
+            sensitivity: 10.0,
+            dragging: false,
+            texture: None,
+        })
+    }
+
+    /// Toggles the difference view with D and changes the sensitivity with , and .
+    /// or by dragging the slider.
+    pub fn handle_input(&mut self) {
+        if is_key_pressed(KeyCode::D) {
+            self.enabled = !self.enabled;
+        }
+
+        if !self.enabled {
+            self.dragging = false;
+            return;
+        }
+
+        let before = self.sensitivity;
+
+        if is_key_pressed(KeyCode::Comma) {
+            self.sensitivity /= SENSITIVITY_STEP;
+        }
+        if is_key_pressed(KeyCode::Period) {
+            self.sensitivity *= SENSITIVITY_STEP;
+        }
+
+        let (mouse_x, mouse_y) = mouse_position();
+        let track = slider_track();
+
+        // Grabbing the slider anywhere around the track is good enough
+        let handle_area = Rect::new(track.x, track.y - PADDING, track.w, track.h + PADDING * 2.0);
+        if is_mouse_button_pressed(MouseButton::Left) && handle_area.contains(vec2(mouse_x, mouse_y)) {
+            self.dragging = true;
+        }
+        if !is_mouse_button_down(MouseButton::Left) {
+            self.dragging = false;
+        }
+
+        // The slider is logarithmic, so small sensitivities get as much room as large ones
+        if self.dragging {
+            let position = ((mouse_x - track.x) / track.w).clamp(0.0, 1.0);
+            self.sensitivity = MIN_SENSITIVITY * (MAX_SENSITIVITY / MIN_SENSITIVITY).powf(position);
+        }
+
+        self.sensitivity = self.sensitivity.clamp(MIN_SENSITIVITY, MAX_SENSITIVITY);
+
+        if self.sensitivity != before {
+            self.clear_texture();
+        }
+    }
+
+    /// Returns whether the slider is being dragged, so the image shouldn't be panned
+    pub fn is_dragging(&self) -> bool {
+        self.dragging
+    }
+
+    /// Returns the heatmap to show instead of the image while the difference view is on.
+    pub fn texture(&mut self) -> Option<Texture2D> {
+        if !self.enabled {
+            return None;
+        }
+
+        if self.texture.is_none() {
+            let bytes: Vec<u8> = self
+                .distances
+                .iter()
+                .flat_map(|distance| heat(distance * self.sensitivity))
+                .collect();
+
+            let (width, height) = self.dimensions;
+            let texture = Texture2D::from_rgba8(width, height, &bytes);
+            texture.set_filter(FilterMode::Nearest);
+
+            self.texture = Some(texture);
+        }
+
+        self.texture
+    }
+
+    /// Draws the sensitivity slider and how many pixels differ while the difference view is on.
+    pub fn draw(&self) {
+        if !self.enabled {
+            return;
+        }
+
+        let track = slider_track();
+        let differing = self.distances.iter().filter(|distance| **distance > 0.0).count();
+        let label = format!("{differing} pixels differ, sensitivity {:.1}x", self.sensitivity);
+        let label_width = measure_text(&label, None, FONT_SIZE as u16, 1.0).width;
+
+        let panel = Rect::new(
+            track.x - PADDING * 2.0,
+            track.y - FONT_SIZE - PADDING * 3.0,
+            SLIDER_WIDTH.max(label_width) + PADDING * 4.0,
+            FONT_SIZE + track.h + PADDING * 5.0,
+        );
+        draw_rectangle(panel.x, panel.y, panel.w, panel.h, Color::new(0.0, 0.0, 0.0, 0.75));
+        draw_text(&label, track.x, track.y - PADDING * 2.0, FONT_SIZE, WHITE);
+
+        let position = (self.sensitivity / MIN_SENSITIVITY).ln()
+            / (MAX_SENSITIVITY / MIN_SENSITIVITY).ln();
+        draw_rectangle(track.x, track.y, track.w, track.h, Color::new(0.3, 0.3, 0.3, 1.0));
+        draw_rectangle(track.x, track.y, track.w * position, track.h, Color::new(1.0, 0.6, 0.0, 1.0));
+        draw_rectangle(
+            track.x + track.w * position - 3.0,
+            track.y - 4.0,
+            6.0,
+            track.h + 8.0,
+            WHITE,
+        );
+    }
+
+    /// Frees the heatmap texture, it's uploaded again the next time it's shown.
+    fn clear_texture(&mut self) {
+        if let Some(texture) = self.texture.take() {
+            texture.delete();
+        }
+    }
+}
+
+impl Drop for Compare {
+    fn drop(&mut self) {
+        self.clear_texture();
+    }
+}
+
+/// Returns the screen rectangle of the slider's track, centered at the bottom of the window.
+fn slider_track() -> Rect {
+    Rect::new(
+        (screen_width() - SLIDER_WIDTH) / 2.0,
+        screen_height() - SLIDER_HEIGHT - PADDING * 3.0,
+        SLIDER_WIDTH,
+        SLIDER_HEIGHT,
+    )
+}
+
+/// Maps an amplified distance to a color going from black over red and yellow to white.
+fn heat(value: f32) -> [u8; 4] {
+    let channel = |offset: f32| ((value * 3.0 - offset).clamp(0.0, 1.0) * 255.0) as u8;
+
+    [channel(0.0), channel(1.0), channel(2.0), 255]
+}
@@ -0,0 +1,110 @@
+use std::{env, fs, path::PathBuf, str::FromStr};
+
+use anyhow::Context;
+use ciebii_lib::rgb::RGB;
+use serde::{de::Error, Deserialize, Deserializer, Serialize};
+
+/// Defaults for the CLI, loaded from `~/.config/ciebii/config.toml`.
+/// Every key is optional and flags passed on the command line take precedence.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct Config {
+    /// The viewer scale factor
+    pub scale: Option<f32>,
+
+    /// The viewer background color
+    #[serde(deserialize_with = "deserialize_color")]
+    pub background: Option<RGB>,
+
+    /// The color of the pixel grid overlay
+    #[serde(deserialize_with = "deserialize_color")]
+    pub grid_color: Option<RGB>,
+
+    /// A ciebii file to use as the viewer's window icon
+    pub icon: Option<PathBuf>,
+
+    /// The viewer frame rate cap
+    pub fps: Option<f32>,
+
+    /// Whether the viewer smooths the image when scaling
+    pub smooth: Option<bool>,
+
+    /// Whether terminal output is colored
+    pub color: Option<bool>,
+
+    /// The number of threads used for converting
+    pub jobs: Option<usize>,
+
+    /// Where converted files are cached, see `cib convert --cache`
+    pub cache_dir: Option<PathBuf>,
+}
+
+impl Config {
+
+    /// Loads the config file, falling back to the defaults if there is none.
+    pub fn load() -> anyhow::Result<Self> {
+        let path = match config_path() {
+            Some(path) if path.exists() => path,
+            _ => return Ok(Self::default()),
+        };
+
+        let contents = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read config file '{}'", path.display()))?;
+
+        toml::from_str(&contents)
+            .with_context(|| format!("Invalid config file '{}'", path.display()))
+    }
+}
+
+/// The viewer window as it was when it was last closed, kept next to the config file.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct WindowState {
+    pub width: f32,
+    pub height: f32,
+}
+
+impl WindowState {
+
+    /// Loads the last window size. A missing or broken file just means there is none.
+    pub fn load() -> Option<Self> {
+        let contents = fs::read_to_string(config_dir()?.join("window.toml")).ok()?;
+
+        toml::from_str(&contents).ok()
+    }
+
+    /// Saves the window size for the next session.
+    pub fn save(&self) -> anyhow::Result<()> {
+        let dir = config_dir().context("Couldn't find the config directory")?;
+
+        fs::create_dir_all(&dir)?;
+        fs::write(dir.join("window.toml"), toml::to_string(self)?)?;
+
+        Ok(())
+    }
+}
+
+/// Returns where the config file lives: `$XDG_CONFIG_HOME/ciebii/config.toml`,
+/// or `~/.config/ciebii/config.toml` if that isn't set.
+fn config_path() -> Option<PathBuf> {
+    Some(config_dir()?.join("config.toml"))
+}
+
+/// Returns the directory holding the config file, the saved window state and the REPL history
+pub fn config_dir() -> Option<PathBuf> {
+    let dir = match env::var_os("XDG_CONFIG_HOME") {
+        Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+        _ => {
+            let home = env::var_os("HOME").or_else(|| env::var_os("USERPROFILE"))?;
+            PathBuf::from(home).join(".config")
+        }
+    };
+
+    Some(dir.join("ciebii"))
+}
+
+/// Parses a hex code like `"#FF8800"` into a color.
+fn deserialize_color<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<RGB>, D::Error> {
+    let hex = String::deserialize(deserializer)?;
+
+    RGB::from_str(&hex).map(Some).map_err(D::Error::custom)
+}
@@ -1,46 +1,721 @@
-use std::{fs::File, path::Path};
+use std::{
+    fs::{self, File},
+    io::{BufReader, BufWriter, Read, Write},
+    path::Path,
+};
 
 use anyhow::Context;
-use ciebii_lib::{chunk::Chunk, file::CIEBIIFILE, io::write_file};
+use ciebii_lib::{
+    checksum::ChecksumAlgorithm,
+    chunk::Chunk,
+    compat,
+    error::ChunkError,
+    file::CIEBIIFILE,
+    format,
+    header::Header,
+    io::{read_file, read_palette, write_atomic},
+    netpbm::{self, Netpbm},
+    palette::{self, Palette},
+    qoi,
+    rgb::RGB,
+    section::Section,
+    thumbnail,
+    writer::{CiebiiWriter, Codec, SizeEstimate, WriteOptions},
+};
+use clap::{Args, ValueEnum};
 use colored::*;
-use image::GenericImageView;
+use image::{codecs::gif::GifDecoder, AnimationDecoder, DynamicImage, Frame};
+use rayon::prelude::*;
 
-pub fn convert(i: &str) -> anyhow::Result<()> {
+use crate::{cache::Cache, clipboard, gallery, info::format_size, metadata};
+
+/// What happens to the alpha channel of the source.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Alpha {
+    /// Keep the color channels as they are and drop the alpha
+    #[default]
+    Drop,
+
+    /// Keep the alpha, writing a file with an alpha channel if the source isn't opaque
+    Keep,
+
+    /// Composite the image over a background color
+    Background(RGB),
+
+    /// Multiply the color channels by the alpha
+    Premultiply,
+}
+
+/// Sources with more pixels than this are streamed row by row instead of loaded whole
+const STREAM_THRESHOLD: usize = 64 * 1024 * 1024;
+
+/// The pixel layout of a raw buffer, 8 bits per channel without any padding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum RawFormat {
+    /// Red, green, blue
+    Rgb,
+
+    /// Red, green, blue, alpha
+    Rgba,
+
+    /// Blue, green, red, as many frame grabbers produce
+    Bgr,
+}
+
+impl RawFormat {
+    /// Returns the number of bytes per pixel
+    pub fn bytes_per_pixel(self) -> usize {
+        match self {
+            RawFormat::Rgb | RawFormat::Bgr => 3,
+            RawFormat::Rgba => 4,
+        }
+    }
+}
+
+/// The format convert writes
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Target {
+    /// A ciebii file
+    #[default]
+    Cib,
+
+    /// The legacy SHITFILE format, for tools that still require it
+    Shf,
+}
+
+impl Target {
+    /// Returns the extension of files in this format
+    pub fn extension(self) -> &'static str {
+        match self {
+            Target::Cib => format::EXTENSION,
+            Target::Shf => compat::LEGACY_EXTENSION,
+        }
+    }
+}
+
+/// How the pixels and sections are checksummed, see `ChecksumAlgorithm`
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ChecksumArg {
+    /// The legacy 16 bit checksum, for readers that predate CRC-32
+    Ciebii,
+
+    /// The low 16 bits of a CRC-32
+    #[default]
+    Crc32,
+}
+
+impl From<ChecksumArg> for ChecksumAlgorithm {
+    fn from(checksum: ChecksumArg) -> Self {
+        match checksum {
+            ChecksumArg::Ciebii => ChecksumAlgorithm::Ciebii,
+            ChecksumArg::Crc32 => ChecksumAlgorithm::Crc32,
+        }
+    }
+}
+
+/// How the pixel data is encoded, see `Codec`
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum CodecArg {
+    /// One chunk per pixel
+    #[default]
+    Raw,
+
+    /// Runs of equal chunks
+    Rle,
+}
+
+impl From<CodecArg> for Codec {
+    fn from(codec: CodecArg) -> Self {
+        match codec {
+            CodecArg::Raw => Codec::Raw,
+            CodecArg::Rle => Codec::Rle,
+        }
+    }
+}
+
+/// The flags picking the on-disk flavor of the ciebii files a command writes
+#[derive(Debug, Clone, Args)]
+pub struct WriteArgs {
+    /// The format version to write
+    #[arg(
+        long,
+        value_name = "VERSION",
+        default_value_t = Header::VERSION,
+        value_parser = clap::value_parser!(u8).range(..=Header::VERSION as i64)
+    )]
+    format_version: u8,
+
+    /// How the pixels and sections are checksummed, new files use crc32 and others keep their
+    /// own checksums unless set
+    #[arg(long, value_enum)]
+    checksum: Option<ChecksumArg>,
+
+    /// How the pixel data is encoded, files keep their own encoding unless set
+    #[arg(long, value_enum)]
+    codec: Option<CodecArg>,
+
+    /// Stores the pixels as runs of equal colors, short for --codec rle
+    #[arg(long, conflicts_with = "codec")]
+    compress: bool,
+
+    /// Ends the file with a SHA-256 digest of everything before it, checked when reading
+    #[arg(long)]
+    footer: bool,
+
+    /// Leaves out any embedded thumbnail
+    #[arg(long)]
+    no_thumbnail: bool,
+}
+
+impl WriteArgs {
+    /// Returns the write options the flags ask for
+    pub fn options(&self) -> WriteOptions {
+        let mut options = WriteOptions::default()
+            .version(self.format_version)
+            .footer(self.footer);
+
+        if let Some(checksum) = self.checksum {
+            options = options.checksum(checksum.into());
+        }
+        if self.compress {
+            options = options.codec(Codec::Rle);
+        }
+        if let Some(codec) = self.codec {
+            options = options.codec(codec.into());
+        }
+
+        match self.no_thumbnail {
+            true => options.thumbnail(false),
+            false => options,
+        }
+    }
+}
+
+/// Options for converting images into ciebii files
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ConvertOptions<'a> {
+    /// Copy the EXIF and XMP metadata of the source
+    pub keep_metadata: bool,
+
+    /// How transparent pixels are flattened
+    pub alpha: Alpha,
+
+    /// Only report what the result would look like, without writing anything
+    pub dry_run: bool,
+
+    /// Embed a thumbnail, reusing the EXIF thumbnail of the source if it has one
+    pub thumbnail: bool,
+
+    /// The format to write
+    pub target: Target,
+
+    /// Quantize the colors to exactly the ones of this palette
+    pub palette: Option<&'a Palette>,
+
+    /// The on-disk flavor of the ciebii file
+    pub write: WriteOptions,
+}
+
+/// Converts the image at `i` into `<stem>.cib` (or `<stem>.shf`) in `out_dir`, or the current
+/// directory without one. With a `cache`, sources converted the same way before are copied
+/// from it instead.
+pub fn convert(
+    i: &str,
+    out_dir: Option<&Path>,
+    options: ConvertOptions,
+    cache: Option<&Cache>,
+) -> anyhow::Result<()> {
     let input_path = Path::new(i);
 
     let out_path = format!(
-        "{}.cib",
-        input_path.file_stem().unwrap().to_str().unwrap()
+        "{}.{}",
+        input_path.file_stem().unwrap().to_str().unwrap(),
+        options.target.extension()
     );
-    
-    let o = Path::new(&out_path);
 
-    File::create(o)?;
+    let Some(dir) = out_dir else {
+        return convert_into(input_path, Path::new(&out_path), options, cache);
+    };
+    if !options.dry_run {
+        fs::create_dir_all(dir).with_context(|| format!("Failed to create '{}'", dir.display()))?;
+    }
+
+    convert_into(input_path, &dir.join(out_path), options, cache)
+}
+
+/// Converts the image at `input_path` into `o`, going through `cache` like `convert`.
+pub fn convert_into(
+    input_path: &Path,
+    o: &Path,
+    options: ConvertOptions,
+    cache: Option<&Cache>,
+) -> anyhow::Result<()> {
+    // A dry run doesn't write anything to cache
+    let cache = match cache {
+        Some(cache) if !options.dry_run => Some((cache, Cache::key(input_path, &options)?)),
+        _ => None,
+    };
+
+    if let Some((cache, key)) = &cache {
+        if cache.restore(key, o)? {
+            println!("♻️ {}", "unchanged since it was last converted, using the cache".bold());
+            return Ok(());
+        }
+    }
+
+    convert_uncached(input_path, o, options)?;
+
+    if let Some((cache, key)) = &cache {
+        cache.store(key, o)?;
+    }
+
+    Ok(())
+}
+
+/// Converts the image at `input_path` into `o`, streaming huge PNGs.
+pub fn convert_uncached(input_path: &Path, o: &Path, options: ConvertOptions) -> anyhow::Result<()> {
+    let _span = tracing::info_span!(
+        "convert",
+        input = %input_path.display(),
+        output = %o.display()
+    )
+    .entered();
+
+    // Legacy files are small enough to never need streaming, and streamed files are always
+    // written in the default flavor, without an alpha channel
+    let streamable = options.target == Target::Cib
+        && options.write == WriteOptions::default()
+        && options.alpha != Alpha::Keep
+        && has_extension(input_path, "png");
+    if streamable && stream_png(input_path, o, options)? {
+        return Ok(());
+    }
+
+    println!("🌈 {}", "Converting colors...".bold());
+
+    println!("⚒️ {}", "constructing file...".bold());
+    let mut ciebii_file = tracing::info_span!("read", path = %input_path.display())
+        .in_scope(|| open(input_path, options.alpha))?;
+
+    if options.keep_metadata {
+        metadata::copy(input_path, &mut ciebii_file)?;
+    }
+
+    if options.thumbnail {
+        embed_thumbnail(Some(input_path), &mut ciebii_file)?;
+    }
+
+    tracing::info_span!("write", path = %o.display()).in_scope(|| write(&ciebii_file, o, options))
+}
 
-    let image = image::open(i).with_context(|| {
+/// Reads the image at `path` into a ciebii file. Ciebii, legacy, Netpbm and QOI images
+/// are read natively, everything else goes through the `image` crate. Every frame of an
+/// animated GIF is kept.
+pub fn open(path: &Path, alpha: Alpha) -> anyhow::Result<CIEBIIFILE> {
+    let failed = || {
         format!(
             "{} {}{}",
             "Failed to open".red().bold(),
-            format!("'{}'", i).white().bold(),
+            format!("'{}'", path.display()).white().bold(),
             "!".red().bold()
         )
+    };
+
+    let netpbm = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .and_then(Netpbm::from_extension);
+
+    if netpbm.is_some() {
+        let bytes = fs::read(path).with_context(failed)?;
+
+        return netpbm::decode(&bytes).with_context(failed);
+    }
+
+    if has_extension(path, "qoi") {
+        let bytes = fs::read(path).with_context(failed)?;
+
+        return qoi::decode(&bytes).with_context(failed);
+    }
+
+    if has_extension(path, format::EXTENSION) {
+        return read_file(path).with_context(failed);
+    }
+
+    if has_extension(path, compat::LEGACY_EXTENSION) {
+        let bytes = fs::read(path).with_context(failed)?;
+
+        return compat::decode(&bytes).with_context(failed);
+    }
+
+    if has_extension(path, "gif") {
+        let file = File::open(path).with_context(failed)?;
+
+        return open_gif(BufReader::new(file), alpha).with_context(failed);
+    }
+
+    let image = image::open(path).with_context(failed)?;
+
+    to_file(&image, alpha)
+}
+
+/// Converts the image on the clipboard into a ciebii file at `o`.
+pub fn convert_clipboard(o: &str, options: ConvertOptions) -> anyhow::Result<()> {
+    let image = clipboard::paste_image().with_context(|| {
+        format!("{}", "Failed to read an image from the clipboard!".red().bold())
     })?;
 
+    println!("🌈 {}", "Converting colors...".bold());
+
+    println!("⚒️ {}", "constructing file...".bold());
+    let mut ciebii_file = to_file(&image, options.alpha)?;
+
+    if options.thumbnail {
+        embed_thumbnail(None, &mut ciebii_file)?;
+    }
+
+    write(&ciebii_file, Path::new(o), options)
+}
+
+/// Converts a huge PNG a row at a time, so only one row is ever held in memory.
+/// Returns `false` without converting anything if the image is small enough to load whole.
+fn stream_png(input: &Path, o: &Path, options: ConvertOptions) -> anyhow::Result<bool> {
+    let mut decoder = png::Decoder::new(BufReader::new(File::open(input)?));
+    decoder.set_transformations(png::Transformations::normalize_to_color8());
+
+    let mut reader = decoder.read_info()?;
+    let info = reader.info();
+    let (width, height) = (info.width as usize, info.height as usize);
+
+    // Interlaced rows arrive in passes, so those are loaded whole no matter the size
+    if width * height <= STREAM_THRESHOLD || info.interlaced {
+        return Ok(false);
+    }
+
+    let mut sections = match options.keep_metadata {
+        true => metadata::read(input)?,
+        false => Vec::new(),
+    };
+
+    // Generating a thumbnail would need the whole image, so only an existing one is kept
+    if options.thumbnail {
+        match metadata::read_thumbnail(input) {
+            Some(image) => {
+                println!("🖼️ {}", "reusing the EXIF thumbnail".bold());
+
+                let thumbnail = to_file(&image, Alpha::Drop)?.as_bytes();
+                sections.push(Section::new(Section::THUMBNAIL, thumbnail));
+            }
+            None => println!("🖼️ {}", "no EXIF thumbnail to keep while streaming".yellow().bold()),
+        }
+    }
+
+    let samples = reader.output_color_type().0.samples();
+    let to_chunks = |row: &[u8]| {
+        let chunks: Vec<Chunk> = row
+            .par_chunks(samples)
+            .map(|pixel| {
+                let rgba = match *pixel {
+                    [l] => [l, l, l, 255],
+                    [l, a] => [l, l, l, a],
+                    [r, g, b] => [r, g, b, 255],
+                    [r, g, b, a] => [r, g, b, a],
+                    _ => unreachable!(),
+                };
+
+                flatten(rgba, options.alpha)
+            })
+            .collect();
+
+        match options.palette {
+            Some(palette) => palette.quantize_chunks(&chunks),
+            None => chunks,
+        }
+    };
+
+    // The runs are only known once every row has been read
+    if options.dry_run {
+        let mut estimate = SizeEstimate::default();
+        while let Some(row) = reader.next_row()? {
+            estimate.push(&to_chunks(row.data()));
+        }
+        let sections = sections.iter().map(Section::encoded_len).sum::<usize>();
+
+        report_dry_run(width, height, o);
+        report_sizes(estimate.has_alpha(), |codec, alpha| {
+            Ok(estimate.size(codec, alpha) + sections)
+        })?;
+
+        return Ok(true);
+    }
+
+    println!("🌊 {}", "streaming rows...".bold());
+    write_atomic(o, |file| -> anyhow::Result<()> {
+        let mut writer = CiebiiWriter::new(BufWriter::new(file), width, height)?;
+
+        while let Some(row) = reader.next_row()? {
+            writer.write_row(&to_chunks(row.data()))?;
+        }
+
+        for section in &sections {
+            writer.write_section(section)?;
+        }
+
+        writer.finish()?;
+
+        Ok(())
+    })?;
+    println!("💾 {}", "saving file...".bold());
+
+    Ok(true)
+}
+
+/// Converts a raw `width` by `height` pixel buffer at `i` into a ciebii file at `o`.
+pub fn convert_raw(
+    i: &str,
+    (width, height): (usize, usize),
+    format: RawFormat,
+    o: &str,
+    options: ConvertOptions,
+) -> anyhow::Result<()> {
+    let bytes = fs::read(i).with_context(|| {
+        format!(
+            "{} {}{}",
+            "Failed to open".red().bold(),
+            format!("'{i}'").white().bold(),
+            "!".red().bold()
+        )
+    })?;
+
+    // Without a header the size is all there is to check the buffer against
+    let expected = width * height * format.bytes_per_pixel();
+    if bytes.len() != expected {
+        return Err(ChunkError::InvalidImage(format!(
+            "a {width}x{height} {format:?} buffer is {expected} bytes, but '{i}' has {}",
+            bytes.len()
+        ))
+        .into());
+    }
+
+    println!("⚒️ {}", "constructing file...".bold());
+    let chunks = bytes
+        .par_chunks_exact(format.bytes_per_pixel())
+        .map(|pixel| match format {
+            RawFormat::Rgb => flatten([pixel[0], pixel[1], pixel[2], 255], options.alpha),
+            RawFormat::Rgba => flatten([pixel[0], pixel[1], pixel[2], pixel[3]], options.alpha),
+            RawFormat::Bgr => flatten([pixel[2], pixel[1], pixel[0], 255], options.alpha),
+        })
+        .collect();
+
+    let mut ciebii_file = CIEBIIFILE::try_from_chunks(width, height, chunks)?;
+
+    if options.thumbnail {
+        embed_thumbnail(None, &mut ciebii_file)?;
+    }
+
+    write(&ciebii_file, Path::new(o), options)
+}
+
+/// Embeds a thumbnail into `file`. The EXIF thumbnail of `source` is reused if it has one,
+/// which saves shrinking the whole image.
+fn embed_thumbnail(source: Option<&Path>, file: &mut CIEBIIFILE) -> anyhow::Result<()> {
+    let thumbnail = match source.and_then(metadata::read_thumbnail) {
+        Some(image) => {
+            println!("🖼️ {}", "reusing the EXIF thumbnail".bold());
+            to_file(&image, Alpha::Drop)?
+        }
+        None => {
+            println!("🖼️ {}", "generating a thumbnail".bold());
+            thumbnail::generate(file)
+        }
+    };
+
+    file.set_thumbnail(&thumbnail);
+
+    Ok(())
+}
+
+/// Writes `file` to `o` in the target format. In a dry run the size of the result
+/// is reported instead.
+fn write(file: &CIEBIIFILE, o: &Path, options: ConvertOptions) -> anyhow::Result<()> {
+    let quantized;
+    let file = match options.palette {
+        Some(palette) => {
+            let colors = palette.len();
+            println!("🎨 {}", format!("matching the {colors} colors of the palette...").bold());
+
+            quantized = palette.quantize(file)?;
+            &quantized
+        }
+        None => file,
+    };
+
+    let bytes = match options.target {
+        Target::Cib => file.as_bytes_with(&options.write)?,
+        Target::Shf => {
+            if file.is_animated() || !file.sections().is_empty() {
+                println!("✂️ {}", "legacy files only keep the pixels of the first frame".yellow().bold());
+            }
+            if file.has_alpha() {
+                println!("✂️ {}", "legacy files are opaque, the alpha is dropped".yellow().bold());
+            }
+            if options.write != WriteOptions::default() {
+                println!("✂️ {}", "legacy files ignore the write options".yellow().bold());
+            }
+
+            compat::encode(file)
+        }
+    };
+
+    if options.dry_run {
+        let (width, height) = file.dimensions();
+        report_dry_run(width, height, o);
+
+        // Legacy files only come in one encoding
+        if options.target == Target::Shf {
+            report_size("legacy", bytes.len());
+
+            return Ok(());
+        }
+
+        // Every encoding of the chunks the file has is exact, dropping the alpha only changes
+        // the size of the first frame
+        let mut estimate = SizeEstimate::default();
+        estimate.push(file.chunks());
+
+        return report_sizes(file.has_alpha(), |codec, alpha| {
+            let exact = file.as_bytes_with(&options.write.codec(codec))?.len();
+
+            Ok(exact - estimate.size(codec, file.has_alpha()) + estimate.size(codec, alpha))
+        });
+    }
+
+    // Only replace `o` once the whole file is written
+    write_atomic(o, |out| -> anyhow::Result<()> { Ok(out.write_all(&bytes)?) })?;
+    println!("💾 {}", "saving file...".bold());
+
+    Ok(())
+}
+
+/// Reads the palette to quantize to, either a `.cibpal` palette or any image the viewer
+/// opens, whose colors are taken in the order they first appear.
+pub fn load_palette(path: &Path) -> anyhow::Result<Palette> {
+    let failed = || format!("Failed to read the palette '{}'", path.display());
+
+    let mut magic = Vec::new();
+    File::open(path)
+        .and_then(|file| file.take(palette::MAGIC_BYTES.len() as u64).read_to_end(&mut magic))
+        .with_context(failed)?;
+
+    if Palette::sniff(&magic) {
+        return Ok(read_palette(path)?);
+    }
+
+    Ok(Palette::from_file(&gallery::load(path).with_context(failed)?))
+}
+
+/// Prints what a conversion would produce, the sizes follow on their own lines
+fn report_dry_run(width: usize, height: usize, o: &Path) {
+    println!(
+        "📏 {} {}, {} pixels as {}",
+        "dry run:".bold(),
+        format!("{width} x {height}").white().bold(),
+        width * height,
+        format!("'{}'", o.display()).white().bold()
+    );
+}
+
+/// Prints the size `size` gives for every encoding, with and without the alpha if `alpha`
+/// is set
+fn report_sizes(
+    alpha: bool,
+    size: impl Fn(Codec, bool) -> anyhow::Result<usize>,
+) -> anyhow::Result<()> {
+    let channels: &[(&str, bool)] = match alpha {
+        true => &[("RGB", false), ("RGBA", true)],
+        false => &[("RGB", false)],
+    };
+
+    for (name, codec) in [("raw", Codec::Raw), ("RLE", Codec::Rle)] {
+        for &(channel, alpha) in channels {
+            report_size(&format!("{name} {channel}"), size(codec, alpha)?);
+        }
+    }
+
+    Ok(())
+}
+
+/// Prints the size of one encoding of a dry run
+fn report_size(encoding: &str, size: usize) {
+    println!("   {:<9} {}", encoding, format_size(size as u64).white().bold());
+}
+
+/// Converts `image` into a ciebii file in memory, flattening transparency as `alpha` says.
+pub fn to_file(image: &DynamicImage, alpha: Alpha) -> anyhow::Result<CIEBIIFILE> {
     let width = image.width() as usize;
     let height = image.height() as usize;
 
+    // Checksumming every pixel is the slow part, so it's spread over all threads
     let chunks = image
-        .pixels()
-        .into_iter()
-        .map(|pixel| Chunk::new(pixel.2[0], pixel.2[1], pixel.2[2]))
+        .to_rgba8()
+        .par_chunks_exact(4)
+        .map(|pixel| flatten([pixel[0], pixel[1], pixel[2], pixel[3]], alpha))
         .collect();
-    println!("🌈 {}", "Converting colors...".bold());
 
-    println!("⚒️ {}", "constructing file...".bold());
-    let ciebii_file = CIEBIIFILE::try_from_chunks(width, height, chunks)?;
+    Ok(CIEBIIFILE::try_from_chunks(width, height, chunks)?)
+}
 
-    write_file(Path::new(o), &ciebii_file)?;
-    println!("💾 {}", "saving file...".bold());
+/// Decodes every frame of a GIF along with its delay. A GIF with a single frame becomes a
+/// still image.
+fn open_gif(reader: impl Read, alpha: Alpha) -> anyhow::Result<CIEBIIFILE> {
+    let mut frames = GifDecoder::new(reader)?.into_frames();
 
-    Ok(())
+    // Frames come out already drawn over the ones before them
+    let decode = |frame: Frame| -> anyhow::Result<(CIEBIIFILE, u32)> {
+        let (numerator, denominator) = frame.delay().numer_denom_ms();
+        let image = DynamicImage::ImageRgba8(frame.into_buffer());
+
+        Ok((to_file(&image, alpha)?, numerator / denominator.max(1)))
+    };
+
+    let (mut file, first_delay) = decode(frames.next().context("The GIF has no frames")??)?;
+    for frame in frames {
+        let (image, delay) = decode(frame?)?;
+
+        if !file.is_animated() {
+            file.set_frame_delay(0, first_delay)?;
+        }
+        file.push_frame(image.chunks().clone(), delay)?;
+    }
+
+    Ok(file)
+}
+
+/// Turns an RGBA pixel into a chunk, flattening transparency as `alpha` says.
+fn flatten([r, g, b, a]: [u8; 4], alpha: Alpha) -> Chunk {
+    let (r, g, b) = match alpha {
+        Alpha::Drop => (r, g, b),
+        Alpha::Keep => return Chunk::with_alpha(r, g, b, a),
+        Alpha::Background(background) => {
+            let (br, bg, bb) = background.color();
+            (blend(r, br, a), blend(g, bg, a), blend(b, bb, a))
+        }
+        Alpha::Premultiply => (blend(r, 0, a), blend(g, 0, a), blend(b, 0, a)),
+    };
+
+    Chunk::new(r, g, b)
+}
+
+/// Mixes `color` over `background` with the given alpha.
+fn blend(color: u8, background: u8, alpha: u8) -> u8 {
+    let (color, background, alpha) = (color as u32, background as u32, alpha as u32);
+
+    ((color * alpha + background * (255 - alpha) + 127) / 255) as u8
+}
+
+/// Returns whether `path` ends in `extension`, ignoring case.
+pub fn has_extension(path: &Path, extension: &str) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| ext.eq_ignore_ascii_case(extension))
 }
@@ -0,0 +1,147 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use ciebii_lib::{
+    file::CIEBIIFILE,
+    io::read_file,
+    netpbm::{self, Netpbm},
+    qoi,
+};
+use colored::*;
+
+use crate::convert::has_extension;
+
+/// The formats decode writes through the `image` crate
+const OUTPUT_FORMATS: [ImageFormat; 5] = [
+    ImageFormat::Png,
+    ImageFormat::Jpeg,
+    ImageFormat::Bmp,
+    ImageFormat::Tiff,
+    ImageFormat::WebP,
+];
+use image::{imageops::FilterType, DynamicImage, ImageFormat, RgbImage, RgbaImage};
+
+/// The formats decode keeps the alpha channel in
+const ALPHA_FORMATS: [ImageFormat; 3] = [ImageFormat::Png, ImageFormat::Tiff, ImageFormat::WebP];
+
+/// Decodes a frame of a ciebii file into an `image` buffer so it can be saved in any format the `image` crate supports.
+pub fn to_image(file: &CIEBIIFILE, frame: usize) -> RgbImage {
+    let (width, height) = file.dimensions();
+
+    let bytes: Vec<u8> = file
+        .frame(frame)
+        .unwrap_or_else(|| file.chunks())
+        .iter()
+        .flat_map(|chunk| chunk.rgb().as_bytes())
+        .collect();
+
+    // The chunk count always matches the header dimensions for a parsed file
+    RgbImage::from_raw(width as u32, height as u32, bytes).expect("chunk count matches dimensions")
+}
+
+/// Decodes a frame like `to_image`, keeping the alpha if the file has an alpha channel and
+/// `format` can hold it.
+pub fn to_dynamic_image(file: &CIEBIIFILE, frame: usize, format: ImageFormat) -> DynamicImage {
+    if !file.has_alpha() || !ALPHA_FORMATS.contains(&format) {
+        return DynamicImage::ImageRgb8(to_image(file, frame));
+    }
+
+    let (width, height) = file.dimensions();
+
+    let bytes: Vec<u8> = file
+        .frame(frame)
+        .unwrap_or_else(|| file.chunks())
+        .iter()
+        .flat_map(|chunk| chunk.rgba().as_bytes())
+        .collect();
+
+    let image = RgbaImage::from_raw(width as u32, height as u32, bytes)
+        .expect("chunk count matches dimensions");
+
+    DynamicImage::ImageRgba8(image)
+}
+
+/// Saves a frame of `file` as a PNG next to `source`, without overwriting existing files.
+/// Returns the path that was written.
+pub fn export_png(file: &CIEBIIFILE, frame: usize, source: &Path) -> anyhow::Result<PathBuf> {
+    let stem = source
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "ciebii".to_owned());
+
+    // Find a name that isn't taken yet: image.png, image-1.png, image-2.png, ...
+    let mut path = source.with_file_name(format!("{stem}.png"));
+    let mut n = 1;
+    while path.exists() {
+        path = source.with_file_name(format!("{stem}-{n}.png"));
+        n += 1;
+    }
+
+    to_dynamic_image(file, frame, ImageFormat::Png).save(&path)?;
+
+    Ok(path)
+}
+
+/// Rasterizes the first frame of `file` to `output` without opening a window, scaled
+/// up by `scale` with nearest-neighbour sampling. The format follows the extension of `output`.
+pub fn render_to_file(file: &CIEBIIFILE, output: &Path, scale: Option<f32>) -> anyhow::Result<()> {
+    let _span = tracing::info_span!("render", output = %output.display()).entered();
+    let image = to_image(file, 0);
+
+    let image = match scale {
+        Some(scale) => {
+            let width = ((image.width() as f32 * scale).round() as u32).max(1);
+            let height = ((image.height() as f32 * scale).round() as u32).max(1);
+
+            image::imageops::resize(&image, width, height, FilterType::Nearest)
+        }
+        None => image,
+    };
+
+    image.save(output)?;
+
+    Ok(())
+}
+
+/// Decodes the ciebii file at `file_name` into a regular image at `output`.
+/// The format follows the extension of `output`; Netpbm and QOI are written natively.
+pub fn decode(file_name: &str, output: &str) -> anyhow::Result<()> {
+    let file = tracing::info_span!("read", path = file_name)
+        .in_scope(|| read_file(Path::new(file_name)))?;
+    let output = Path::new(output);
+    let _span = tracing::info_span!("write", path = %output.display()).entered();
+
+    let netpbm = output
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .and_then(Netpbm::from_extension);
+
+    match netpbm {
+        Some(format) => fs::write(output, netpbm::encode(&file, format))?,
+        None if has_extension(output, "qoi") => fs::write(output, qoi::encode(&file)?)?,
+        None => {
+            let format = ImageFormat::from_path(output)
+                .ok()
+                .filter(|format| OUTPUT_FORMATS.contains(format))
+                .ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "Unsupported output format '{}', expected png, jpg, bmp, tiff, webp, ppm, pgm, pam or qoi",
+                        output.display()
+                    )
+                })?;
+
+            // WebP is always written losslessly
+            to_dynamic_image(&file, 0, format).save_with_format(output, format)?
+        }
+    }
+
+    println!(
+        "💾 {} {}",
+        "Saved".green().bold(),
+        format!("'{}'", output.display()).white().bold()
+    );
+
+    Ok(())
+}
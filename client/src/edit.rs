@@ -0,0 +1,350 @@
+use std::{fs::File, path::Path, str::FromStr};
+
+use ciebii_lib::{
+    chunk::Chunk,
+    crop,
+    draw::{fill_rect, stamp},
+    file::CIEBIIFILE,
+    io::{read_file, write_file},
+    resize::{self, Filter},
+    rgb::RGB,
+    rotate::{self, Axis, Rotation},
+    text::draw_text,
+};
+use clap::ValueEnum;
+use colored::*;
+
+/// A pixel to overwrite with a new color.
+#[derive(Debug, Clone, Copy)]
+pub struct Patch {
+    x: usize,
+    y: usize,
+    color: RGB,
+}
+
+impl Patch {
+    /// Parses the `X,Y` and `COLOR` values given to `--pixel`.
+    pub fn parse(point: &str, color: &str) -> anyhow::Result<Self> {
+        let (x, y) = point
+            .split_once(',')
+            .and_then(|(x, y)| Some((x.trim().parse().ok()?, y.trim().parse().ok()?)))
+            .ok_or_else(|| anyhow::anyhow!("'{point}' is not a valid position, expected X,Y"))?;
+
+        Ok(Self {
+            x,
+            y,
+            color: RGB::from_str(color)?,
+        })
+    }
+
+    /// Overwrites the pixel in `file`, recomputing its checksum.
+    pub fn apply(&self, file: &mut CIEBIIFILE) -> anyhow::Result<()> {
+        let (width, height) = file.dimensions();
+
+        if self.x >= width || self.y >= height {
+            anyhow::bail!(
+                "({}, {}) is outside of the {width} x {height} image",
+                self.x,
+                self.y
+            );
+        }
+
+        let (r, g, b) = self.color.color();
+        file.set_pixel(self.x, self.y, Chunk::new(r, g, b))?;
+
+        Ok(())
+    }
+}
+
+/// Overwrites pixels of the ciebii file at `file_name`, recomputing their checksums.
+/// The result is written to `output`, or back to the same file.
+pub fn set(file_name: &str, patches: &[Patch], output: Option<&str>) -> anyhow::Result<()> {
+    let mut file = read_file(Path::new(file_name))?;
+
+    for patch in patches {
+        patch.apply(&mut file)?;
+    }
+    println!("🖌️ {} {}", "Set".bold(), format!("{} pixels", patches.len()).bold());
+
+    save(&file, Path::new(output.unwrap_or(file_name)))
+}
+
+/// Something to stamp onto an image with the draw command.
+#[derive(Debug, Clone)]
+pub enum Annotation {
+    /// A filled rectangle, parsed from `X,Y,WxH,COLOR`
+    Rect {
+        x: usize,
+        y: usize,
+        width: usize,
+        height: usize,
+        color: RGB,
+    },
+
+    /// A line of text, parsed from `X,Y,TEXT,COLOR`
+    Text {
+        x: usize,
+        y: usize,
+        text: String,
+        color: RGB,
+    },
+}
+
+impl Annotation {
+    /// Parses a `--rect` value like `0,0,50x20,#FF0000`
+    pub fn parse_rect(s: &str) -> Result<Self, String> {
+        let parts: Vec<&str> = s.split(',').collect();
+
+        let [x, y, size, color] = parts[..] else {
+            return Err(format!("'{s}' is not a valid rectangle, expected X,Y,WxH,COLOR"));
+        };
+
+        let (width, height) = size
+            .split_once('x')
+            .ok_or_else(|| format!("'{size}' is not a valid size, expected WxH"))?;
+
+        Ok(Self::Rect {
+            x: parse_number(x)?,
+            y: parse_number(y)?,
+            width: parse_number(width)?,
+            height: parse_number(height)?,
+            color: RGB::from_str(color).map_err(|err| err.to_string())?,
+        })
+    }
+
+    /// Parses a `--text` value like `2,2,v1.2,#FFFFFF`. The text itself may contain commas.
+    pub fn parse_text(s: &str) -> Result<Self, String> {
+        let invalid = || format!("'{s}' is not a valid label, expected X,Y,TEXT,COLOR");
+
+        let mut parts = s.splitn(3, ',');
+        let (x, y, rest) = match (parts.next(), parts.next(), parts.next()) {
+            (Some(x), Some(y), Some(rest)) => (x, y, rest),
+            _ => return Err(invalid()),
+        };
+        let (text, color) = rest.rsplit_once(',').ok_or_else(invalid)?;
+
+        Ok(Self::Text {
+            x: parse_number(x)?,
+            y: parse_number(y)?,
+            text: text.to_owned(),
+            color: RGB::from_str(color).map_err(|err| err.to_string())?,
+        })
+    }
+}
+
+/// Stamps rectangles and text onto the ciebii file at `file_name` and writes the result to `output`.
+/// Text is drawn with every font pixel scaled up to `scale` x `scale` pixels.
+pub fn draw(file_name: &str, annotations: &[Annotation], scale: usize, output: &str) -> anyhow::Result<()> {
+    let mut file = read_file(Path::new(file_name))?;
+
+    for annotation in annotations {
+        match annotation {
+            Annotation::Rect {
+                x,
+                y,
+                width,
+                height,
+                color,
+            } => fill_rect(&mut file, *x, *y, *width, *height, *color),
+            Annotation::Text { x, y, text, color } => {
+                draw_text(&mut file, *x, *y, text, *color, scale)
+            }
+        }
+    }
+    println!("🖌️ {}", "drawing annotations...".bold());
+
+    save(&file, Path::new(output))
+}
+
+/// A rectangle to cut out of an image, parsed from `X,Y,WxH`.
+#[derive(Debug, Clone, Copy)]
+pub struct Region {
+    x: usize,
+    y: usize,
+    width: usize,
+    height: usize,
+}
+
+impl Region {
+    /// Parses a region like `10,20,64x48`
+    pub fn parse(s: &str) -> Result<Self, String> {
+        let parts: Vec<&str> = s.split(',').collect();
+
+        let [x, y, size] = parts[..] else {
+            return Err(format!("'{s}' is not a valid region, expected X,Y,WxH"));
+        };
+
+        let (width, height) = size
+            .split_once('x')
+            .ok_or_else(|| format!("'{size}' is not a valid size, expected WxH"))?;
+
+        Ok(Self {
+            x: parse_number(x)?,
+            y: parse_number(y)?,
+            width: parse_number(width)?,
+            height: parse_number(height)?,
+        })
+    }
+}
+
+/// Cuts `region` out of every frame of the ciebii file at `file_name` and writes it to `output`.
+pub fn crop(file_name: &str, region: Region, output: &str) -> anyhow::Result<()> {
+    let cropped = crop_file(&read_file(Path::new(file_name))?, region)?;
+    let (crop_width, crop_height) = cropped.dimensions();
+    println!(
+        "✂️ {} {}",
+        "Cropped to".bold(),
+        format!("{crop_width} x {crop_height}").white().bold()
+    );
+
+    save(&cropped, Path::new(output))
+}
+
+/// Cuts `region` out of every frame of `file`.
+pub fn crop_file(file: &CIEBIIFILE, region: Region) -> anyhow::Result<CIEBIIFILE> {
+    let (width, height) = file.dimensions();
+
+    let Region {
+        x,
+        y,
+        width: crop_width,
+        height: crop_height,
+    } = region;
+
+    crop::crop(file, x, y, crop_width, crop_height).map_err(|_| {
+        anyhow::anyhow!(
+            "{crop_width} x {crop_height} at ({x}, {y}) doesn't fit inside \
+             the {width} x {height} image"
+        )
+    })
+}
+
+/// How `resize` picks the new pixels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ResizeFilter {
+    /// The closest pixel, keeps pixel art sharp
+    Nearest,
+
+    /// A blend of the four closest pixels, smoother for photos
+    Bilinear,
+}
+
+impl From<ResizeFilter> for Filter {
+    fn from(filter: ResizeFilter) -> Self {
+        match filter {
+            ResizeFilter::Nearest => Filter::Nearest,
+            ResizeFilter::Bilinear => Filter::Bilinear,
+        }
+    }
+}
+
+/// Scales every frame of the ciebii file at `file_name` to `size` and writes it to `output`.
+/// With `fit` the image keeps its aspect ratio, becoming as large as fits inside `size`.
+pub fn resize(
+    file_name: &str,
+    size: (usize, usize),
+    filter: ResizeFilter,
+    fit: bool,
+    output: &str,
+) -> anyhow::Result<()> {
+    let file = read_file(Path::new(file_name))?;
+    let (width, height) = file.dimensions();
+
+    if width == 0 || height == 0 {
+        anyhow::bail!("'{file_name}' is empty, there is nothing to resize");
+    }
+
+    let (new_width, new_height) = match fit {
+        true => resize::fit(width, height, size.0, size.1),
+        false => size,
+    };
+
+    let resized = resize::resize(&file, new_width, new_height, filter.into())?;
+    println!(
+        "📐 {} {}",
+        "Resized to".bold(),
+        format!("{new_width} x {new_height}").white().bold()
+    );
+
+    save(&resized, Path::new(output))
+}
+
+/// Parses an `X,Y` position like the one given to `--at`
+pub fn parse_position(s: &str) -> Result<(usize, usize), String> {
+    let (x, y) = s
+        .split_once(',')
+        .ok_or_else(|| format!("'{s}' is not a valid position, expected X,Y"))?;
+
+    Ok((parse_number(x)?, parse_number(y)?))
+}
+
+/// Stamps the ciebii file at `overlay` onto the one at `base` with its top left corner at
+/// `position`, and writes the result to `output`. Parts outside of the base are clipped.
+pub fn composite(
+    base: &str,
+    overlay: &str,
+    position: (usize, usize),
+    output: &str,
+) -> anyhow::Result<()> {
+    let mut file = read_file(Path::new(base))?;
+    let overlay = read_file(Path::new(overlay))?;
+
+    let (width, height) = file.dimensions();
+    if position.0 >= width || position.1 >= height {
+        anyhow::bail!(
+            "({}, {}) is outside of the {width} x {height} image",
+            position.0,
+            position.1
+        );
+    }
+
+    stamp(&mut file, &overlay, position.0, position.1);
+    println!("🖌️ {}", "compositing...".bold());
+
+    save(&file, Path::new(output))
+}
+
+/// Parses a clockwise rotation in degrees: 90, 180 or 270.
+pub fn parse_rotation(s: &str) -> Result<Rotation, String> {
+    s.trim()
+        .parse()
+        .ok()
+        .and_then(Rotation::from_degrees)
+        .ok_or_else(|| format!("'{s}' is not a valid rotation, expected 90, 180 or 270"))
+}
+
+/// Rotates every frame of the ciebii file at `file_name` clockwise and writes it to `output`.
+pub fn rotate(file_name: &str, rotation: Rotation, output: &str) -> anyhow::Result<()> {
+    let file = read_file(Path::new(file_name))?;
+
+    let rotated = rotate::rotate(&file, rotation)?;
+    println!("🔄 {}", "rotating...".bold());
+
+    save(&rotated, Path::new(output))
+}
+
+/// Mirrors every frame of the ciebii file at `file_name` along `axis` and writes it to `output`.
+pub fn flip(file_name: &str, axis: Axis, output: &str) -> anyhow::Result<()> {
+    let file = read_file(Path::new(file_name))?;
+
+    let flipped = rotate::flip(&file, axis)?;
+    println!("🪞 {}", "flipping...".bold());
+
+    save(&flipped, Path::new(output))
+}
+
+/// Writes `file` to `o`, replacing whatever was there before.
+pub fn save(file: &CIEBIIFILE, o: &Path) -> anyhow::Result<()> {
+    File::create(o)?;
+
+    write_file(o, file)?;
+    println!("💾 {}", "saving file...".bold());
+
+    Ok(())
+}
+
+fn parse_number(s: &str) -> Result<usize, String> {
+    s.trim()
+        .parse()
+        .map_err(|_| format!("'{s}' is not a valid number"))
+}
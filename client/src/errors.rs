@@ -0,0 +1,119 @@
+use std::{io, process::ExitCode};
+
+use ciebii_lib::error::ChunkError;
+#[cfg(feature = "diagnostics")]
+use ciebii_lib::error::CiebiiError;
+use colored::*;
+use image::ImageError;
+#[cfg(feature = "diagnostics")]
+use miette::GraphicalReportHandler;
+use serde::Serialize;
+
+/// The kinds of failure `cib` reports, each with a stable exit code scripts can branch on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Failure {
+    /// Anything that doesn't fit the other kinds
+    Other = 1,
+
+    /// Unreadable or malformed input, same as clap's usage errors
+    BadInput = 2,
+
+    /// A chunk of a ciebii file didn't match its checksum
+    Checksum = 3,
+
+    /// Reading or writing a file failed
+    Io = 4,
+}
+
+impl Failure {
+    /// Finds the kind of `err` from the first cause in its chain that is known.
+    pub fn classify(err: &anyhow::Error) -> Failure {
+        err.chain()
+            .find_map(|cause| {
+                if let Some(err) = cause.downcast_ref::<ChunkError>() {
+                    return Some(match err {
+                        ChunkError::ChecksumFail => Failure::Checksum,
+                        ChunkError::Io(_) => Failure::Io,
+                        _ => Failure::BadInput,
+                    });
+                }
+
+                if cause.is::<io::Error>() {
+                    return Some(Failure::Io);
+                }
+
+                if let Some(err) = cause.downcast_ref::<ImageError>() {
+                    return Some(match err {
+                        ImageError::IoError(_) => Failure::Io,
+                        ImageError::Limits(_) | ImageError::Parameter(_) => Failure::Other,
+                        _ => Failure::BadInput,
+                    });
+                }
+
+                cause.is::<toml::de::Error>().then_some(Failure::BadInput)
+            })
+            .unwrap_or(Failure::Other)
+    }
+
+    /// The name used for the `error` field of JSON errors
+    pub fn name(self) -> &'static str {
+        match self {
+            Failure::Other => "other",
+            Failure::BadInput => "bad_input",
+            Failure::Checksum => "checksum",
+            Failure::Io => "io",
+        }
+    }
+}
+
+/// An error as printed by `--json-errors`
+#[derive(Serialize)]
+struct JsonError<'a> {
+    error: &'a str,
+    code: u8,
+    message: String,
+    causes: Vec<String>,
+}
+
+/// Prints `err` on stderr, as JSON if `json` is set, and returns the matching exit code.
+pub fn report(err: &anyhow::Error, json: bool) -> ExitCode {
+    let failure = Failure::classify(err);
+    let code = failure as u8;
+
+    if json {
+        let error = JsonError {
+            error: failure.name(),
+            code,
+            message: err.to_string(),
+            causes: err.chain().skip(1).map(|cause| cause.to_string()).collect(),
+        };
+
+        eprintln!("{}", serde_json::to_string(&error).unwrap());
+    } else {
+        for (i, cause) in err.chain().enumerate() {
+            if i == 0 {
+                eprintln!("{} {cause}", "Error:".red().bold());
+            } else {
+                eprintln!("  {} {cause}", "caused by:".dimmed());
+            }
+
+            // Decode errors point at the offending bytes and explain the rest of the chain
+            #[cfg(feature = "diagnostics")]
+            if let Some(diagnostic) = cause
+                .downcast_ref::<CiebiiError>()
+                .and_then(CiebiiError::diagnostic)
+            {
+                let mut rendered = String::new();
+                if GraphicalReportHandler::new()
+                    .render_report(&mut rendered, diagnostic)
+                    .is_ok()
+                {
+                    eprint!("{rendered}");
+                    break;
+                }
+            }
+        }
+    }
+
+    ExitCode::from(code)
+}
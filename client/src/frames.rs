@@ -0,0 +1,69 @@
+use std::path::Path;
+
+use ciebii_lib::{file::CIEBIIFILE, io::read_file};
+use colored::*;
+
+use crate::edit;
+
+/// How long appended frames are shown when the animation doesn't say, in milliseconds
+const DEFAULT_DELAY: u32 = 100;
+
+/// Writes frame `index` of the ciebii file at `file_name` to `output` as a still image.
+pub fn extract(file_name: &str, index: usize, output: &str) -> anyhow::Result<()> {
+    let file = read_file(Path::new(file_name))?;
+    let (width, height) = file.dimensions();
+
+    let chunks = file.frame(index).ok_or_else(|| {
+        anyhow::anyhow!(
+            "'{file_name}' has {} frames, there is no frame {index}",
+            file.frame_count()
+        )
+    })?;
+
+    let frame = CIEBIIFILE::try_from_chunks(width, height, chunks.clone())?;
+    println!(
+        "🎞️ {} {}",
+        "Extracting".bold(),
+        format!("frame {index} of {}", file.frame_count()).white().bold()
+    );
+
+    edit::save(&frame, Path::new(output))
+}
+
+/// Appends the first frame of `frame_name` to the animation at `file_name`, shown for `delay`
+/// milliseconds. Without a delay the last frame's is reused. The result is written to
+/// `output`, or back to the animation.
+pub fn append(
+    file_name: &str,
+    frame_name: &str,
+    delay: Option<u32>,
+    output: Option<&str>,
+) -> anyhow::Result<()> {
+    let mut file = read_file(Path::new(file_name))?;
+    let frame = read_file(Path::new(frame_name))?;
+
+    if frame.dimensions() != file.dimensions() {
+        let ((x, y), (frame_x, frame_y)) = (file.dimensions(), frame.dimensions());
+        anyhow::bail!("Can't append a {frame_x} x {frame_y} frame to a {x} x {y} animation");
+    }
+
+    // A still image doesn't have a meaningful delay yet
+    let last = file.frame_count() - 1;
+    let delay = delay.unwrap_or(match file.frame_delay(last) {
+        Some(delay) if file.is_animated() => delay,
+        _ => DEFAULT_DELAY,
+    });
+
+    if !file.is_animated() {
+        file.set_frame_delay(0, delay)?;
+    }
+
+    file.push_frame(frame.chunks().clone(), delay)?;
+    println!(
+        "🎞️ {} {}",
+        "Appended".bold(),
+        format!("frame {}, shown for {delay} ms", last + 1).white().bold()
+    );
+
+    edit::save(&file, Path::new(output.unwrap_or(file_name)))
+}
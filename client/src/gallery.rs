@@ -0,0 +1,249 @@
+use std::{
+    fs::{self, File},
+    io::Read,
+    path::{Path, PathBuf},
+};
+
+use anyhow::Context;
+use ciebii_lib::{compat, file::CIEBIIFILE, format, io::read_file, FormatKind};
+use colored::*;
+
+use crate::{
+    convert::{self, Alpha},
+    render,
+};
+
+/// Extensions of the regular image formats the viewer can open next to `.cib` files
+pub const IMAGE_EXTENSIONS: [&str; 8] = ["png", "jpg", "jpeg", "bmp", "ppm", "pgm", "pam", "qoi"];
+
+/// The files the viewer steps through, either the contents of a directory or
+/// a set of files opened as tabs.
+#[derive(Debug)]
+pub struct Gallery {
+    paths: Vec<PathBuf>,
+    current: usize,
+    tabbed: bool,
+
+    // Whether a directory was opened directly, which shows a strip of thumbnails
+    browsing: bool,
+}
+
+impl Gallery {
+
+    /// Lists the `.cib` and legacy `.shf` files in `dir`, along with PNG/JPG/BMP files if
+    /// `images` is set.
+    pub fn in_dir(dir: &Path, images: bool) -> anyhow::Result<Self> {
+        let mut paths: Vec<PathBuf> = fs::read_dir(dir)
+            .with_context(|| format!("Failed to read directory '{}'", dir.display()))?
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| is_viewable(path, images))
+            .collect();
+        paths.sort();
+
+        if paths.is_empty() {
+            anyhow::bail!("No viewable files found in '{}'", dir.display());
+        }
+
+        Ok(Self {
+            paths,
+            current: 0,
+            tabbed: false,
+            browsing: false,
+        })
+    }
+
+    /// Lists the files in `dir` like `in_dir`, to be browsed with a strip of thumbnails.
+    pub fn browse(dir: &Path, images: bool) -> anyhow::Result<Self> {
+        Ok(Self {
+            browsing: true,
+            ..Self::in_dir(dir, images)?
+        })
+    }
+
+    /// Opens the given files as tabs, starting at the first one.
+    pub fn tabs(paths: Vec<PathBuf>) -> Self {
+        Self {
+            paths,
+            current: 0,
+            tabbed: true,
+            browsing: false,
+        }
+    }
+
+    /// Shows just `path`, without any other files to step through.
+    pub fn single(path: PathBuf) -> Self {
+        Self {
+            paths: vec![path],
+            current: 0,
+            tabbed: false,
+            browsing: false,
+        }
+    }
+
+    /// Lists the files in the same directory as `path`, starting at `path`.
+    pub fn around(path: &Path, images: bool) -> anyhow::Result<Self> {
+        let dir = match path.parent() {
+            Some(dir) if !dir.as_os_str().is_empty() => dir,
+            _ => Path::new("."),
+        };
+
+        let mut gallery = Self::in_dir(dir, images).unwrap_or(Self {
+            paths: Vec::new(),
+            current: 0,
+            tabbed: false,
+            browsing: false,
+        });
+
+        // The file itself might not have a viewable extension, keep it in the list regardless
+        let canonical = path.canonicalize().ok();
+        gallery.current = match gallery
+            .paths
+            .iter()
+            .position(|other| other.canonicalize().ok() == canonical)
+        {
+            Some(index) => index,
+            None => {
+                gallery.paths.insert(0, path.to_owned());
+                0
+            }
+        };
+
+        Ok(gallery)
+    }
+
+    /// Returns the path of the current file
+    pub fn path(&self) -> &Path {
+        &self.paths[self.current]
+    }
+
+    /// Returns whether the files were opened as tabs
+    pub fn is_tabbed(&self) -> bool {
+        self.tabbed
+    }
+
+    /// Returns whether a directory was opened to browse
+    pub fn is_browsing(&self) -> bool {
+        self.browsing
+    }
+
+    /// Returns the paths of every file in the gallery
+    pub fn paths(&self) -> &[PathBuf] {
+        &self.paths
+    }
+
+    /// Returns the index of the current file
+    pub fn index(&self) -> usize {
+        self.current
+    }
+
+    /// Returns the file names of every file in the gallery
+    pub fn names(&self) -> Vec<String> {
+        self.paths
+            .iter()
+            .map(|path| match path.file_name() {
+                Some(name) => name.to_string_lossy().into_owned(),
+                None => path.display().to_string(),
+            })
+            .collect()
+    }
+
+    /// Switches to the file at `index` and returns it.
+    /// Returns `None` and stays put if there's no such file or it fails to load.
+    pub fn select(&mut self, index: usize) -> Option<CIEBIIFILE> {
+        if index >= self.paths.len() || index == self.current {
+            return None;
+        }
+
+        match load_viewable(&self.paths[index]) {
+            Ok(file) => {
+                self.current = index;
+                Some(file)
+            }
+            Err(err) => {
+                warn_skipped(&self.paths[index], &err);
+                None
+            }
+        }
+    }
+
+    /// Loads the current file, moving on to the next one that loads if it fails.
+    pub fn load_current(&mut self) -> Option<CIEBIIFILE> {
+        match load_viewable(self.path()) {
+            Ok(file) => Some(file),
+            Err(err) => {
+                warn_skipped(self.path(), &err);
+                self.advance(true)
+            }
+        }
+    }
+
+    /// Moves to the next (or previous) file that loads successfully and returns it.
+    /// Files that fail to load are reported and skipped.
+    /// Returns `None` and stays put when no other file could be loaded.
+    pub fn advance(&mut self, forward: bool) -> Option<CIEBIIFILE> {
+        let start = self.current;
+
+        // Try every other file at most once
+        for _ in 1..self.paths.len() {
+            self.current = match forward {
+                true => (self.current + 1) % self.paths.len(),
+                false => (self.current + self.paths.len() - 1) % self.paths.len(),
+            };
+
+            match load_viewable(self.path()) {
+                Ok(file) => return Some(file),
+                Err(err) => warn_skipped(self.path(), &err),
+            }
+        }
+
+        self.current = start;
+        None
+    }
+}
+
+/// Loads a file for viewing, converting regular images in memory. Ciebii and legacy files
+/// are told apart by their magic bytes, so old archives open no matter how they're named.
+pub fn load(path: &Path) -> anyhow::Result<CIEBIIFILE> {
+    let _span = tracing::info_span!("read", path = %path.display()).entered();
+
+    let mut magic = Vec::new();
+    File::open(path)
+        .and_then(|file| file.take(CIEBIIFILE::MAGIC_BYTES.len() as u64).read_to_end(&mut magic))
+        .with_context(|| format!("Failed to open file '{}'", path.display()))?;
+
+    match ciebii_lib::sniff(&magic) {
+        Some(FormatKind::Ciebii) => Ok(read_file(path)?),
+        Some(FormatKind::Legacy) => compat::decode(&fs::read(path)?)
+            .with_context(|| format!("'{}' is not a valid legacy file", path.display())),
+        None => convert::open(path, Alpha::Drop),
+    }
+}
+
+/// Loads a file like `load`, failing if it's too large for the viewer to show.
+fn load_viewable(path: &Path) -> anyhow::Result<CIEBIIFILE> {
+    let file = load(path)?;
+    render::texture_size(file.dimensions())?;
+
+    Ok(file)
+}
+
+/// Returns whether the viewer can open the file at `path`
+fn is_viewable(path: &Path, images: bool) -> bool {
+    has_extension(path, &[format::EXTENSION, compat::LEGACY_EXTENSION])
+        || (images && has_extension(path, &IMAGE_EXTENSIONS))
+}
+
+fn has_extension(path: &Path, extensions: &[&str]) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| extensions.iter().any(|other| ext.eq_ignore_ascii_case(other)))
+}
+
+fn warn_skipped(path: &Path, err: &anyhow::Error) {
+    eprintln!(
+        "{} {}: {}",
+        "Skipping".yellow().bold(),
+        format!("'{}'", path.display()).white().bold(),
+        err
+    );
+}
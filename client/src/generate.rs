@@ -0,0 +1,92 @@
+use std::path::Path;
+
+use ciebii_lib::{chunk::Chunk, file::CIEBIIFILE, rgb::RGB};
+use clap::ValueEnum;
+use colored::*;
+
+use crate::edit;
+
+/// The size of a checkerboard square in pixels
+const SQUARE: usize = 8;
+
+/// The colors of the color bars, from left to right
+const BARS: [(u8, u8, u8); 7] = [
+    (255, 255, 255),
+    (255, 255, 0),
+    (0, 255, 255),
+    (0, 255, 0),
+    (255, 0, 255),
+    (255, 0, 0),
+    (0, 0, 255),
+];
+
+/// A test pattern the generate command can produce.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Pattern {
+    /// A single color, set with --color
+    Solid,
+
+    /// Red increasing to the right, green increasing downwards
+    Gradient,
+
+    /// Black and white squares
+    Checkerboard,
+
+    /// Random colors, reproducible with --seed
+    Noise,
+
+    /// Vertical bars of the primary and secondary colors
+    ColorBars,
+}
+
+/// Generates a `width` x `height` test pattern and writes it to `output`.
+pub fn generate(
+    pattern: Pattern,
+    (width, height): (usize, usize),
+    color: RGB,
+    seed: u64,
+    output: &str,
+) -> anyhow::Result<()> {
+    println!("🎨 {}", "generating pattern...".bold());
+
+    // xorshift needs a non-zero state
+    let mut state = seed.wrapping_mul(0x9E37_79B9_7F4A_7C15) | 1;
+
+    let chunks = (0..width * height)
+        .map(|i| {
+            let (x, y) = (i % width, i / width);
+
+            let (r, g, b) = match pattern {
+                Pattern::Solid => color.color(),
+                Pattern::Gradient => (ramp(x, width), ramp(y, height), 128),
+                Pattern::Checkerboard => match (x / SQUARE + y / SQUARE) % 2 {
+                    0 => (255, 255, 255),
+                    _ => (0, 0, 0),
+                },
+                Pattern::Noise => {
+                    state ^= state << 13;
+                    state ^= state >> 7;
+                    state ^= state << 17;
+
+                    let [r, g, b, ..] = state.to_le_bytes();
+                    (r, g, b)
+                }
+                Pattern::ColorBars => BARS[x * BARS.len() / width],
+            };
+
+            Chunk::new(r, g, b)
+        })
+        .collect();
+
+    let file = CIEBIIFILE::try_from_chunks(width, height, chunks)?;
+
+    edit::save(&file, Path::new(output))
+}
+
+/// Maps `position` onto 0 to 255 across `length` pixels.
+fn ramp(position: usize, length: usize) -> u8 {
+    match length {
+        0 | 1 => 0,
+        _ => (position * 255 / (length - 1)) as u8,
+    }
+}
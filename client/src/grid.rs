@@ -0,0 +1,64 @@
+use ciebii_lib::rgb::RGB;
+use macroquad::{
+    color::Color,
+    input::{is_key_pressed, KeyCode},
+    math::Rect,
+    shapes::draw_line,
+    window::{screen_height, screen_width},
+};
+
+/// The minimum amount of screen pixels per image pixel before the grid is drawn.
+/// Below this the lines would cover the image itself.
+const ZOOM_THRESHOLD: f32 = 8.0;
+
+/// A pixel grid drawn over the image, toggled with G.
+#[derive(Debug)]
+pub struct Grid {
+    enabled: bool,
+    color: Color,
+}
+
+impl Grid {
+    pub fn new(color: RGB) -> Self {
+        let (r, g, b) = color.color();
+
+        Self {
+            enabled: false,
+            color: Color::from_rgba(r, g, b, 255),
+        }
+    }
+
+    /// Toggles the grid when G is pressed.
+    pub fn handle_input(&mut self) {
+        if is_key_pressed(KeyCode::G) {
+            self.enabled = !self.enabled;
+        }
+    }
+
+    /// Draws the grid over `area`, which displays an image of the given dimensions.
+    pub fn draw(&self, (width, height): (usize, usize), area: Rect) {
+        let zoom = area.w / width as f32;
+
+        if !self.enabled || zoom < ZOOM_THRESHOLD {
+            return;
+        }
+
+        // Only draw the lines that are actually on screen
+        let visible_x = area.x.max(0.0)..=(area.x + area.w).min(screen_width());
+        let visible_y = area.y.max(0.0)..=(area.y + area.h).min(screen_height());
+
+        for column in 0..=width {
+            let x = area.x + column as f32 * zoom;
+            if visible_x.contains(&x) {
+                draw_line(x, *visible_y.start(), x, *visible_y.end(), 1.0, self.color);
+            }
+        }
+
+        for row in 0..=height {
+            let y = area.y + row as f32 * zoom;
+            if visible_y.contains(&y) {
+                draw_line(*visible_x.start(), y, *visible_x.end(), y, 1.0, self.color);
+            }
+        }
+    }
+}
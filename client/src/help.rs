@@ -0,0 +1,96 @@
+use macroquad::{
+    color::{Color, GRAY, WHITE},
+    input::{is_key_pressed, KeyCode},
+    shapes::draw_rectangle,
+    text::{draw_text, measure_text},
+    window::{screen_height, screen_width},
+};
+
+const FONT_SIZE: f32 = 18.0;
+const LINE_HEIGHT: f32 = 20.0;
+const PADDING: f32 = 12.0;
+
+/// The width reserved for the key column
+const KEY_COLUMN: f32 = 110.0;
+
+/// Every viewer shortcut along with what it does, in the order they are listed.
+const SHORTCUTS: [(&str, &str); 27] = [
+    ("?", "show/hide this help"),
+    ("Esc / Q", "quit"),
+    ("Left / Right", "previous/next file"),
+    ("1-9", "switch tab"),
+    ("Click", "open thumbnail (directory)"),
+    ("Wheel / + -", "zoom"),
+    ("Drag", "pan"),
+    ("0", "reset zoom and pan"),
+    ("F11 / F", "toggle fullscreen"),
+    ("S", "save as PNG"),
+    ("Ctrl+C", "copy to clipboard"),
+    ("I", "file info"),
+    ("P", "pixel inspector"),
+    ("H", "histograms"),
+    ("Click", "copy hovered color (inspector)"),
+    ("G", "pixel grid"),
+    ("N", "smooth/crisp scaling"),
+    ("B", "cycle background"),
+    ("[ / ]", "exposure down/up"),
+    ("; / '", "gamma down/up"),
+    ("\\", "reset exposure/gamma"),
+    ("V", "simulate color blindness"),
+    ("Space", "pause/resume animation"),
+    ("Left / Right", "step frames (paused)"),
+    ("L", "toggle looping"),
+    ("D", "difference view (--compare)"),
+    (", / .", "difference sensitivity"),
+];
+
+/// An overlay listing every viewer shortcut. Toggled with ? (or F1).
+#[derive(Debug, Default)]
+pub struct Help {
+    enabled: bool,
+}
+
+impl Help {
+    /// Toggles the overlay when ? or F1 is pressed. Escape closes it.
+    pub fn handle_input(&mut self) {
+
+        // ? shares its key with / on most layouts, so don't insist on shift
+        if is_key_pressed(KeyCode::Slash) || is_key_pressed(KeyCode::F1) {
+            self.enabled = !self.enabled;
+        } else if is_key_pressed(KeyCode::Escape) {
+            self.enabled = false;
+        }
+    }
+
+    /// Returns whether the overlay is shown
+    pub fn is_open(&self) -> bool {
+        self.enabled
+    }
+
+    /// Draws the overlay centered in the window.
+    pub fn draw(&self) {
+        if !self.enabled {
+            return;
+        }
+
+        let description_width = SHORTCUTS
+            .iter()
+            .map(|(_, description)| measure_text(description, None, FONT_SIZE as u16, 1.0).width)
+            .fold(0.0, f32::max);
+
+        let width = KEY_COLUMN + description_width + PADDING * 2.0;
+        let height = (SHORTCUTS.len() + 1) as f32 * LINE_HEIGHT + PADDING * 2.0;
+        let x = ((screen_width() - width) / 2.0).max(0.0);
+        let y = ((screen_height() - height) / 2.0).max(0.0);
+
+        draw_rectangle(x, y, width, height, Color::new(0.0, 0.0, 0.0, 0.85));
+        draw_text("Shortcuts", x + PADDING, y + PADDING + 0.8 * LINE_HEIGHT, FONT_SIZE, WHITE);
+
+        for (i, (key, description)) in SHORTCUTS.iter().enumerate() {
+            let line_y = y + PADDING + (i as f32 + 1.8) * LINE_HEIGHT;
+
+            draw_text(key, x + PADDING, line_y, FONT_SIZE, WHITE);
+            draw_text(description, x + PADDING + KEY_COLUMN, line_y, FONT_SIZE, GRAY);
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use ciebii_lib::histogram::Histogram;
+use macroquad::{
+    color::{Color, BLUE, GREEN, RED, WHITE},
+    input::{is_key_pressed, KeyCode},
+    shapes::{draw_line, draw_rectangle},
+    text::draw_text,
+    window::{screen_height, screen_width},
+};
+
+const FONT_SIZE: f32 = 18.0;
+const PADDING: f32 = 6.0;
+
+/// The size of the plot, one pixel per level
+const PLOT_WIDTH: f32 = 256.0;
+const PLOT_HEIGHT: f32 = 100.0;
+
+/// A corner panel plotting the red, green, blue and luminance histograms of the frame on screen.
+/// Toggled with H.
+#[derive(Debug, Default)]
+pub struct Histograms {
+    enabled: bool,
+}
+
+impl Histograms {
+    /// Toggles the panel when H is pressed.
+    pub fn handle_input(&mut self) {
+        if is_key_pressed(KeyCode::H) {
+            self.enabled = !self.enabled;
+        }
+    }
+
+    /// Returns whether the panel is shown, the histogram only has to be worked out if so
+    pub fn is_open(&self) -> bool {
+        self.enabled
+    }
+
+    /// Draws the panel for `histogram` in the bottom right corner.
+    pub fn draw(&self, histogram: Option<&Histogram>) {
+        let Some(histogram) = histogram.filter(|_| self.enabled) else {
+            return;
+        };
+
+        let left = screen_width() - PLOT_WIDTH - PADDING * 3.0;
+        let top = screen_height() - PLOT_HEIGHT - FONT_SIZE - PADDING * 4.0;
+
+        draw_rectangle(
+            left,
+            top,
+            PLOT_WIDTH + PADDING * 2.0,
+            PLOT_HEIGHT + FONT_SIZE + PADDING * 3.0,
+            Color::new(0.0, 0.0, 0.0, 0.75),
+        );
+        draw_text("histogram", left + PADDING, top + FONT_SIZE, FONT_SIZE, WHITE);
+
+        let bottom = top + FONT_SIZE + PADDING * 2.0 + PLOT_HEIGHT;
+        let peak = histogram.peak().max(1) as f32;
+
+        let channels = [
+            (&histogram.red, RED),
+            (&histogram.green, GREEN),
+            (&histogram.blue, BLUE),
+            (&histogram.luminance, WHITE),
+        ];
+
+        for (counts, color) in channels {
+            let color = Color::new(color.r, color.g, color.b, 0.8);
+            let height = |level: usize| counts[level] as f32 / peak * PLOT_HEIGHT;
+
+            for level in 1..counts.len() {
+                let x = left + PADDING + level as f32;
+                draw_line(x - 1.0, bottom - height(level - 1), x, bottom - height(level), 1.0, color);
+            }
+        }
+    }
+}
@@ -1,984 +1,47 @@
-pub const SMALL_ICON: [u8; 1024] = [
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 31, 33, 56, 255, 29, 30, 49, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 33, 35,
-    60, 255, 61, 68, 147, 255, 85, 97, 225, 255, 68, 76, 170, 255, 37, 39, 72, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 39, 41, 79, 255, 85, 91, 214,
-    255, 91, 101, 238, 255, 90, 101, 238, 255, 91, 101, 238, 255, 88, 96, 226, 255, 49, 51, 106,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 45, 47, 95, 255, 93, 99, 237,
-    255, 92, 100, 238, 255, 91, 100, 238, 255, 92, 100, 238, 255, 94, 99, 238, 255, 58, 61, 133,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 45, 47, 95, 255, 94, 98, 237,
-    255, 93, 99, 238, 255, 93, 99, 238, 255, 94, 98, 239, 255, 96, 97, 239, 255, 59, 60, 133, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 47, 48, 100, 255, 95, 98, 238, 255,
-    95, 98, 239, 255, 95, 98, 239, 255, 96, 97, 239, 255, 97, 97, 238, 255, 57, 57, 128, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 33, 34, 59, 255, 48, 51, 106, 255, 80, 83, 197, 255, 95, 98, 239, 255,
-    96, 97, 239, 255, 96, 97, 239, 255, 89, 90, 221, 255, 54, 54, 133, 255, 15, 15, 33, 255, 16,
-    16, 26, 255, 27, 28, 44, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 38, 41, 76, 255, 75, 85, 194, 255, 88, 98, 230, 255, 93, 99, 238, 255, 95, 98, 239, 255,
-    97, 97, 239, 255, 78, 77, 191, 255, 22, 22, 53, 255, 1, 1, 3, 255, 0, 0, 0, 255, 0, 0, 0, 255,
-    6, 6, 9, 255, 23, 23, 38, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 48, 55, 111,
-    255, 87, 104, 237, 255, 89, 102, 238, 255, 91, 100, 238, 255, 94, 98, 238, 255, 97, 97, 239,
-    255, 58, 58, 142, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255,
-    15, 16, 25, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 47, 56, 112, 255, 85, 105,
-    237, 255, 87, 103, 237, 255, 90, 102, 238, 255, 93, 99, 238, 255, 96, 97, 239, 255, 58, 58,
-    142, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 15, 16, 25,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 45, 53, 105, 255, 82, 106, 236, 255,
-    84, 105, 237, 255, 88, 103, 238, 255, 91, 101, 238, 255, 95, 98, 238, 255, 56, 56, 136, 255, 0,
-    0, 1, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 16, 17, 27, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 34, 38, 66, 255, 64, 81, 174, 255, 80, 102, 228, 255,
-    86, 104, 237, 255, 90, 101, 238, 255, 81, 85, 200, 255, 41, 42, 86, 255, 9, 9, 15, 255, 0, 0,
-    1, 255, 0, 0, 0, 255, 0, 0, 0, 255, 7, 7, 12, 255, 25, 25, 40, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 37, 42, 77, 255, 63, 76, 163, 255,
-    53, 60, 124, 255, 31, 32, 54, 255, 29, 30, 48, 255, 29, 30, 48, 255, 21, 21, 34, 255, 11, 11,
-    18, 255, 20, 20, 32, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-];
+use std::path::Path;
 
-pub const MEDIUM_ICON: [u8; 4096] = [
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 31, 33, 54, 255, 37, 40, 75, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    39, 43, 80, 255, 67, 76, 169, 255, 77, 88, 200, 255, 50, 55, 114, 255, 31, 33, 55, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 35, 37, 66, 255, 60, 66, 143, 255, 84, 95, 220, 255,
-    89, 102, 238, 255, 89, 102, 238, 255, 88, 100, 234, 255, 72, 81, 183, 255, 50, 55, 114, 255,
-    32, 33, 56, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    36, 37, 67, 255, 52, 56, 117, 255, 81, 88, 206, 255, 90, 101, 238, 255, 90, 101, 238, 255, 89,
-    102, 238, 255, 89, 102, 238, 255, 90, 101, 238, 255, 90, 101, 238, 255, 89, 98, 232, 255, 73,
-    79, 180, 255, 44, 46, 92, 255, 30, 31, 51, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 49, 255, 70,
-    74, 169, 255, 92, 98, 235, 255, 92, 100, 238, 255, 91, 100, 238, 255, 90, 101, 238, 255, 90,
-    101, 238, 255, 90, 101, 238, 255, 91, 101, 238, 255, 91, 100, 238, 255, 92, 100, 238, 255, 93,
-    99, 238, 255, 86, 91, 216, 255, 39, 41, 79, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 49, 255, 76,
-    80, 185, 255, 94, 99, 238, 255, 93, 99, 238, 255, 92, 100, 238, 255, 91, 100, 238, 255, 91,
-    100, 238, 255, 91, 100, 238, 255, 92, 100, 238, 255, 92, 100, 238, 255, 93, 99, 238, 255, 94,
-    99, 238, 255, 91, 95, 228, 255, 42, 43, 85, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 49, 255, 76,
-    80, 185, 255, 94, 98, 238, 255, 93, 99, 238, 255, 93, 99, 238, 255, 92, 100, 238, 255, 92, 100,
-    238, 255, 92, 100, 238, 255, 93, 100, 238, 255, 94, 99, 238, 255, 94, 98, 238, 255, 95, 98,
-    239, 255, 92, 94, 229, 255, 42, 43, 86, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 49, 255, 77, 80,
-    186, 255, 94, 98, 239, 255, 94, 99, 238, 255, 93, 99, 238, 255, 93, 99, 238, 255, 93, 99, 238,
-    255, 93, 99, 238, 255, 94, 99, 238, 255, 94, 98, 239, 255, 95, 98, 239, 255, 96, 97, 239, 255,
-    93, 93, 229, 255, 42, 43, 85, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 49, 255, 77, 80, 186, 255,
-    95, 98, 239, 255, 94, 98, 238, 255, 94, 98, 238, 255, 94, 99, 238, 255, 94, 99, 238, 255, 94,
-    99, 238, 255, 94, 98, 239, 255, 95, 98, 239, 255, 96, 97, 239, 255, 97, 97, 239, 255, 94, 93,
-    229, 255, 42, 43, 85, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 49, 255, 77, 80, 186, 255, 95, 98,
-    239, 255, 95, 98, 239, 255, 95, 98, 239, 255, 94, 98, 239, 255, 94, 98, 239, 255, 95, 98, 239,
-    255, 95, 98, 239, 255, 96, 97, 239, 255, 96, 97, 239, 255, 97, 96, 239, 255, 94, 93, 229, 255,
-    43, 43, 86, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 49, 255, 77, 80, 186, 255, 95, 98, 239, 255,
-    95, 98, 239, 255, 95, 98, 239, 255, 95, 98, 239, 255, 95, 98, 239, 255, 95, 98, 239, 255, 95,
-    97, 239, 255, 96, 97, 239, 255, 96, 97, 239, 255, 97, 96, 239, 255, 94, 93, 229, 255, 43, 43,
-    86, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 33, 35, 61, 255, 64, 66, 149, 255, 92, 95, 230, 255, 95, 98, 239, 255, 95, 97,
-    239, 255, 96, 97, 239, 255, 96, 97, 239, 255, 96, 97, 239, 255, 96, 97, 239, 255, 96, 97, 239,
-    255, 96, 97, 239, 255, 97, 97, 239, 255, 88, 87, 216, 255, 41, 41, 100, 255, 13, 13, 24, 255,
-    25, 26, 42, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 31, 32, 53, 255, 53, 56,
-    120, 255, 86, 91, 216, 255, 94, 99, 238, 255, 95, 98, 239, 255, 95, 98, 239, 255, 95, 97, 239,
-    255, 96, 97, 239, 255, 96, 97, 239, 255, 96, 97, 239, 255, 96, 97, 239, 255, 96, 97, 239, 255,
-    89, 90, 221, 255, 55, 56, 136, 255, 9, 9, 21, 255, 0, 0, 0, 255, 0, 0, 0, 255, 2, 2, 3, 255,
-    15, 15, 24, 255, 28, 28, 45, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 49, 255, 43, 48, 94, 255, 76, 85, 195, 255, 91, 100, 238, 255, 92,
-    100, 238, 255, 93, 99, 238, 255, 94, 98, 238, 255, 95, 98, 239, 255, 96, 97, 239, 255, 96, 97,
-    239, 255, 97, 97, 239, 255, 97, 97, 239, 255, 95, 95, 235, 255, 56, 57, 138, 255, 8, 8, 18,
-    255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 4, 4,
-    7, 255, 19, 20, 32, 255, 28, 29, 46, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 49, 56,
-    115, 255, 86, 102, 234, 255, 89, 102, 238, 255, 90, 101, 238, 255, 91, 100, 238, 255, 92, 100,
-    238, 255, 94, 99, 238, 255, 95, 98, 239, 255, 96, 98, 239, 255, 96, 97, 239, 255, 97, 97, 239,
-    255, 97, 96, 239, 255, 29, 28, 69, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255,
-    0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 4, 5, 7,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 53, 61, 127, 255, 86, 104, 237, 255, 87, 103,
-    238, 255, 89, 102, 238, 255, 90, 101, 238, 255, 92, 100, 238, 255, 93, 99, 238, 255, 94, 99,
-    238, 255, 95, 98, 239, 255, 96, 97, 239, 255, 97, 97, 239, 255, 97, 96, 239, 255, 19, 19, 46,
-    255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0,
-    0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 1, 1, 2, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 52, 62, 127, 255, 85, 105, 237, 255, 86, 104, 237, 255, 88, 103, 238, 255, 89,
-    102, 238, 255, 90, 101, 238, 255, 92, 100, 238, 255, 93, 99, 238, 255, 95, 98, 239, 255, 96,
-    97, 239, 255, 97, 97, 239, 255, 97, 96, 239, 255, 19, 19, 46, 255, 0, 0, 0, 255, 0, 0, 0, 255,
-    0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0,
-    255, 0, 0, 0, 255, 1, 1, 2, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 52, 62, 128, 255, 84,
-    105, 237, 255, 86, 104, 237, 255, 87, 103, 237, 255, 88, 102, 238, 255, 90, 101, 238, 255, 91,
-    100, 238, 255, 93, 99, 238, 255, 94, 98, 238, 255, 96, 97, 239, 255, 97, 97, 239, 255, 97, 96,
-    239, 255, 19, 19, 45, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0,
-    255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 1, 1, 2, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 52, 62, 127, 255, 83, 106, 237, 255, 85, 105, 237, 255,
-    86, 104, 237, 255, 87, 103, 237, 255, 89, 102, 238, 255, 90, 101, 238, 255, 92, 100, 238, 255,
-    94, 99, 238, 255, 95, 98, 239, 255, 97, 97, 239, 255, 97, 96, 239, 255, 19, 19, 45, 255, 0, 0,
-    0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0,
-    0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 1, 1, 2, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    51, 63, 127, 255, 82, 106, 237, 255, 83, 105, 237, 255, 85, 105, 237, 255, 86, 104, 237, 255,
-    88, 103, 238, 255, 90, 102, 238, 255, 91, 101, 238, 255, 93, 99, 238, 255, 95, 98, 239, 255,
-    96, 97, 239, 255, 97, 97, 239, 255, 19, 19, 45, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255,
-    0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0,
-    255, 1, 1, 2, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 51, 62, 126, 255, 82, 107, 237,
-    255, 83, 106, 237, 255, 84, 105, 237, 255, 85, 104, 237, 255, 87, 103, 237, 255, 89, 102, 238,
-    255, 90, 101, 238, 255, 92, 100, 238, 255, 94, 99, 238, 255, 95, 98, 239, 255, 97, 97, 239,
-    255, 18, 18, 44, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0,
-    0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 1, 1, 2, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 36, 40, 72, 255, 62, 79, 166, 255, 79, 103, 227, 255, 83, 106,
-    237, 255, 84, 105, 237, 255, 86, 104, 237, 255, 88, 103, 238, 255, 89, 102, 238, 255, 91, 101,
-    238, 255, 93, 99, 238, 255, 89, 93, 224, 255, 65, 66, 149, 255, 24, 24, 42, 255, 6, 6, 10, 255,
-    0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0,
-    255, 0, 1, 1, 255, 7, 7, 11, 255, 17, 18, 28, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 30, 32, 53, 255, 44, 52, 101, 255, 69, 87, 189, 255, 82, 104, 233, 255, 85,
-    105, 237, 255, 87, 103, 237, 255, 88, 103, 238, 255, 88, 99, 232, 255, 71, 77, 174, 255, 41,
-    43, 84, 255, 29, 30, 49, 255, 29, 30, 48, 255, 27, 28, 45, 255, 16, 17, 27, 255, 6, 6, 10, 255,
-    0, 0, 1, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 4, 4, 7, 255, 17, 17, 28, 255, 27, 28,
-    45, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 32, 35, 60, 255, 51, 61, 124, 255, 75, 93, 207, 255, 83, 101, 229,
-    255, 74, 87, 195, 255, 47, 51, 105, 255, 30, 32, 52, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 26, 27, 44, 255, 16, 16, 26, 255, 4, 4, 6, 255,
-    2, 3, 4, 255, 13, 13, 22, 255, 25, 26, 42, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 36, 39, 71, 255, 43, 48, 93, 255, 33, 35, 60, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 25, 26, 41, 255, 24, 25, 39, 255, 29,
-    30, 47, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-];
+use anyhow::Context;
+use ciebii_lib::{file::CIEBIIFILE, io::read_file};
+use image::{imageops::FilterType, DynamicImage, RgbaImage};
+use macroquad::miniquad::conf::Icon;
 
-pub const LARGE_ICON: [u8; 16384] = [
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 49, 255, 37, 40, 73, 255, 41, 45, 88, 255, 30, 32, 52, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 33, 35, 62, 255, 54, 61,
-    129, 255, 79, 91, 210, 255, 84, 97, 225, 255, 65, 73, 162, 255, 39, 42, 79, 255, 30, 31, 51,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 31, 32, 54, 255, 47, 52, 106, 255, 75, 85, 193, 255, 87, 101, 234, 255, 88, 102, 238, 255,
-    88, 102, 238, 255, 88, 102, 238, 255, 81, 93, 214, 255, 65, 73, 162, 255, 38, 41, 76, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 30, 31, 50, 255, 41, 44, 87, 255, 69, 77, 172, 255, 86,
-    99, 229, 255, 89, 102, 238, 255, 89, 102, 238, 255, 89, 102, 238, 255, 89, 102, 238, 255, 89,
-    102, 238, 255, 89, 102, 238, 255, 89, 102, 238, 255, 82, 93, 216, 255, 57, 63, 135, 255, 34,
-    35, 63, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 49,
-    255, 41, 43, 84, 255, 61, 68, 149, 255, 84, 95, 220, 255, 90, 101, 238, 255, 89, 101, 238, 255,
-    89, 102, 238, 255, 89, 102, 238, 255, 89, 102, 238, 255, 89, 102, 238, 255, 89, 102, 238, 255,
-    89, 102, 238, 255, 89, 101, 238, 255, 90, 101, 238, 255, 90, 101, 237, 255, 78, 87, 200, 255,
-    49, 53, 110, 255, 31, 32, 54, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 36, 37, 68, 255, 62, 67, 148, 255, 86, 94,
-    221, 255, 91, 100, 237, 255, 90, 101, 238, 255, 90, 101, 238, 255, 89, 101, 238, 255, 89, 101,
-    238, 255, 89, 102, 238, 255, 89, 102, 238, 255, 89, 102, 238, 255, 89, 101, 238, 255, 90, 101,
-    238, 255, 90, 101, 238, 255, 90, 101, 238, 255, 90, 101, 238, 255, 91, 101, 238, 255, 89, 98,
-    232, 255, 72, 79, 179, 255, 43, 45, 89, 255, 30, 31, 50, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 32, 33, 57, 255, 54, 57, 121, 255, 82, 88, 207, 255, 92, 100, 238, 255, 91, 100, 238,
-    255, 91, 100, 238, 255, 91, 101, 238, 255, 90, 101, 238, 255, 90, 101, 238, 255, 90, 101, 238,
-    255, 90, 101, 238, 255, 89, 101, 238, 255, 90, 101, 238, 255, 90, 101, 238, 255, 90, 101, 238,
-    255, 90, 101, 238, 255, 90, 101, 238, 255, 91, 101, 238, 255, 91, 100, 238, 255, 92, 100, 238,
-    255, 92, 100, 238, 255, 88, 95, 224, 255, 64, 69, 153, 255, 37, 39, 71, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 54, 56, 120, 255, 92, 98, 235, 255, 93, 99, 238, 255, 92, 100, 238, 255, 92, 100, 238,
-    255, 91, 100, 238, 255, 91, 100, 238, 255, 91, 101, 238, 255, 90, 101, 238, 255, 90, 101, 238,
-    255, 90, 101, 238, 255, 90, 101, 238, 255, 90, 101, 238, 255, 90, 101, 238, 255, 90, 101, 238,
-    255, 91, 101, 238, 255, 91, 100, 238, 255, 91, 100, 238, 255, 92, 100, 238, 255, 92, 100, 238,
-    255, 92, 100, 238, 255, 93, 99, 238, 255, 93, 99, 238, 255, 74, 79, 182, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 59, 61, 133, 255, 94, 99, 238, 255, 93, 99, 238, 255, 93, 99, 238, 255, 92, 100, 238,
-    255, 92, 100, 238, 255, 91, 100, 238, 255, 91, 100, 238, 255, 91, 101, 238, 255, 90, 101, 238,
-    255, 90, 101, 238, 255, 90, 101, 238, 255, 90, 101, 238, 255, 91, 101, 238, 255, 91, 100, 238,
-    255, 91, 100, 238, 255, 91, 100, 238, 255, 92, 100, 238, 255, 92, 100, 238, 255, 93, 99, 238,
-    255, 93, 99, 238, 255, 93, 99, 238, 255, 93, 99, 238, 255, 80, 84, 197, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 59, 61, 133, 255, 94, 99, 238, 255, 93, 99, 238, 255, 93, 99, 238, 255, 92, 99, 238,
-    255, 92, 100, 238, 255, 92, 100, 238, 255, 91, 100, 238, 255, 91, 100, 238, 255, 91, 100, 238,
-    255, 91, 101, 238, 255, 91, 101, 238, 255, 91, 101, 238, 255, 91, 100, 238, 255, 91, 100, 238,
-    255, 91, 100, 238, 255, 92, 100, 238, 255, 92, 100, 238, 255, 93, 99, 238, 255, 93, 99, 238,
-    255, 93, 99, 238, 255, 94, 99, 238, 255, 94, 99, 239, 255, 80, 84, 197, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 59, 61, 133, 255, 94, 98, 238, 255, 94, 99, 238, 255, 93, 99, 238, 255, 93, 99, 238,
-    255, 92, 99, 238, 255, 92, 100, 238, 255, 92, 100, 238, 255, 91, 100, 238, 255, 91, 100, 238,
-    255, 91, 100, 238, 255, 91, 100, 238, 255, 91, 100, 238, 255, 91, 100, 238, 255, 92, 100, 238,
-    255, 92, 100, 238, 255, 92, 100, 238, 255, 93, 99, 238, 255, 93, 99, 238, 255, 94, 99, 238,
-    255, 94, 98, 238, 255, 94, 98, 238, 255, 95, 98, 239, 255, 80, 83, 197, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 59, 61, 133, 255, 94, 98, 238, 255, 94, 99, 238, 255, 94, 99, 238, 255, 93, 99, 238,
-    255, 93, 99, 238, 255, 92, 99, 238, 255, 92, 100, 238, 255, 92, 100, 238, 255, 92, 100, 238,
-    255, 92, 100, 238, 255, 92, 100, 238, 255, 92, 100, 238, 255, 92, 100, 238, 255, 92, 100, 238,
-    255, 92, 99, 238, 255, 93, 99, 238, 255, 93, 99, 238, 255, 94, 99, 238, 255, 94, 98, 238, 255,
-    95, 98, 239, 255, 95, 98, 239, 255, 95, 98, 239, 255, 81, 83, 198, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 59, 61, 133, 255, 95, 98, 238, 255, 94, 98, 238, 255, 94, 99, 238, 255, 93, 99, 238, 255,
-    93, 99, 238, 255, 93, 99, 238, 255, 93, 99, 238, 255, 92, 100, 238, 255, 92, 100, 238, 255, 92,
-    100, 238, 255, 92, 100, 238, 255, 92, 100, 238, 255, 92, 100, 238, 255, 93, 99, 238, 255, 93,
-    99, 238, 255, 93, 99, 238, 255, 94, 99, 238, 255, 94, 98, 238, 255, 95, 98, 239, 255, 95, 98,
-    239, 255, 95, 97, 239, 255, 96, 97, 239, 255, 82, 83, 198, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 59,
-    61, 134, 255, 95, 98, 239, 255, 94, 98, 238, 255, 94, 98, 238, 255, 94, 99, 238, 255, 93, 99,
-    238, 255, 93, 99, 238, 255, 93, 99, 238, 255, 93, 99, 238, 255, 93, 99, 238, 255, 93, 99, 238,
-    255, 93, 99, 238, 255, 93, 99, 238, 255, 93, 99, 238, 255, 93, 99, 238, 255, 93, 99, 238, 255,
-    94, 99, 238, 255, 94, 98, 239, 255, 95, 98, 239, 255, 95, 98, 239, 255, 96, 97, 239, 255, 96,
-    97, 239, 255, 96, 97, 239, 255, 82, 82, 198, 255, 29, 30, 49, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 59, 61, 134, 255, 95,
-    98, 239, 255, 95, 98, 239, 255, 94, 98, 238, 255, 94, 98, 238, 255, 94, 99, 238, 255, 94, 99,
-    238, 255, 93, 99, 238, 255, 93, 99, 238, 255, 93, 99, 238, 255, 93, 99, 238, 255, 93, 99, 238,
-    255, 93, 99, 238, 255, 93, 99, 238, 255, 94, 99, 238, 255, 94, 99, 239, 255, 94, 98, 239, 255,
-    95, 98, 239, 255, 95, 98, 239, 255, 96, 97, 239, 255, 96, 97, 239, 255, 96, 97, 239, 255, 97,
-    97, 239, 255, 82, 82, 197, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 59, 61, 134, 255, 95, 98, 239, 255, 95,
-    98, 239, 255, 94, 98, 238, 255, 94, 98, 238, 255, 94, 98, 238, 255, 94, 98, 238, 255, 94, 99,
-    238, 255, 93, 99, 238, 255, 93, 99, 238, 255, 93, 99, 238, 255, 93, 99, 238, 255, 93, 99, 238,
-    255, 94, 99, 238, 255, 94, 98, 239, 255, 94, 98, 238, 255, 95, 98, 239, 255, 95, 98, 239, 255,
-    96, 97, 239, 255, 96, 97, 239, 255, 96, 97, 239, 255, 97, 96, 239, 255, 97, 96, 239, 255, 82,
-    82, 197, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 59, 61, 134, 255, 95, 98, 239, 255, 95, 98, 239, 255,
-    95, 98, 239, 255, 95, 98, 239, 255, 94, 98, 238, 255, 94, 98, 238, 255, 94, 98, 239, 255, 94,
-    98, 238, 255, 94, 99, 238, 255, 94, 99, 239, 255, 94, 99, 238, 255, 94, 98, 238, 255, 94, 98,
-    239, 255, 94, 98, 239, 255, 95, 98, 239, 255, 95, 98, 239, 255, 95, 97, 239, 255, 96, 97, 239,
-    255, 96, 97, 239, 255, 97, 97, 239, 255, 97, 96, 239, 255, 97, 96, 239, 255, 83, 82, 197, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 60, 62, 134, 255, 95, 98, 239, 255, 95, 98, 239, 255, 95, 98, 239,
-    255, 95, 98, 239, 255, 95, 98, 239, 255, 94, 98, 239, 255, 94, 98, 238, 255, 94, 98, 238, 255,
-    94, 98, 238, 255, 94, 98, 238, 255, 94, 98, 238, 255, 94, 98, 238, 255, 94, 98, 239, 255, 95,
-    98, 239, 255, 95, 98, 239, 255, 95, 98, 239, 255, 96, 97, 239, 255, 96, 97, 239, 255, 97, 97,
-    239, 255, 97, 96, 239, 255, 97, 96, 239, 255, 98, 96, 239, 255, 83, 82, 198, 255, 29, 30, 49,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 59, 61, 134, 255, 95, 98, 239, 255, 95, 98, 239, 255, 95, 98, 239, 255, 95, 98,
-    239, 255, 95, 98, 239, 255, 95, 98, 239, 255, 95, 98, 239, 255, 95, 98, 239, 255, 95, 98, 239,
-    255, 95, 98, 239, 255, 95, 98, 239, 255, 95, 98, 239, 255, 95, 98, 239, 255, 95, 98, 239, 255,
-    95, 98, 239, 255, 96, 97, 239, 255, 96, 97, 239, 255, 96, 97, 239, 255, 97, 97, 239, 255, 97,
-    96, 239, 255, 97, 96, 239, 255, 98, 96, 239, 255, 83, 82, 198, 255, 29, 30, 49, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    59, 62, 134, 255, 95, 98, 239, 255, 95, 98, 239, 255, 95, 98, 239, 255, 95, 98, 239, 255, 95,
-    98, 239, 255, 95, 98, 239, 255, 95, 98, 239, 255, 95, 98, 239, 255, 95, 98, 239, 255, 95, 98,
-    239, 255, 95, 98, 239, 255, 95, 98, 239, 255, 95, 98, 239, 255, 95, 97, 239, 255, 96, 97, 239,
-    255, 96, 97, 239, 255, 96, 97, 239, 255, 96, 97, 239, 255, 97, 97, 239, 255, 97, 96, 239, 255,
-    97, 96, 239, 255, 98, 96, 239, 255, 83, 82, 197, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 30, 31, 50, 255, 64, 67, 149, 255,
-    95, 98, 239, 255, 95, 98, 239, 255, 95, 98, 239, 255, 95, 98, 239, 255, 95, 98, 239, 255, 95,
-    98, 239, 255, 95, 98, 239, 255, 95, 98, 239, 255, 95, 98, 239, 255, 95, 98, 239, 255, 95, 98,
-    239, 255, 95, 98, 239, 255, 95, 97, 239, 255, 95, 97, 239, 255, 96, 97, 239, 255, 96, 97, 239,
-    255, 96, 97, 239, 255, 96, 97, 239, 255, 97, 97, 239, 255, 97, 96, 239, 255, 97, 96, 239, 255,
-    98, 96, 239, 255, 75, 74, 178, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 38, 39, 74, 255, 68, 70, 159, 255, 91, 94, 228, 255, 95, 98, 238,
-    255, 95, 98, 238, 255, 95, 97, 239, 255, 95, 97, 239, 255, 95, 97, 239, 255, 95, 97, 239, 255,
-    95, 97, 239, 255, 95, 97, 239, 255, 95, 97, 239, 255, 96, 97, 239, 255, 96, 97, 239, 255, 96,
-    97, 239, 255, 96, 97, 239, 255, 96, 97, 239, 255, 96, 97, 239, 255, 96, 97, 239, 255, 96, 97,
-    239, 255, 96, 97, 239, 255, 97, 97, 239, 255, 97, 96, 239, 255, 91, 90, 223, 255, 57, 56, 135,
-    255, 14, 14, 32, 255, 13, 14, 22, 255, 25, 26, 42, 255, 29, 30, 47, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 34, 35, 62, 255, 58,
-    61, 132, 255, 86, 90, 215, 255, 94, 98, 238, 255, 95, 98, 239, 255, 95, 98, 239, 255, 95, 98,
-    239, 255, 95, 97, 238, 255, 95, 97, 238, 255, 95, 97, 239, 255, 96, 97, 239, 255, 96, 97, 239,
-    255, 96, 97, 239, 255, 96, 97, 239, 255, 96, 97, 239, 255, 96, 97, 239, 255, 96, 97, 239, 255,
-    96, 97, 239, 255, 96, 97, 239, 255, 96, 97, 239, 255, 96, 97, 239, 255, 96, 97, 239, 255, 96,
-    97, 239, 255, 95, 95, 233, 255, 68, 68, 166, 255, 23, 23, 54, 255, 1, 1, 3, 255, 0, 0, 0, 255,
-    0, 0, 0, 255, 4, 4, 7, 255, 13, 14, 22, 255, 25, 26, 42, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 31, 32, 53, 255, 49, 52, 109, 255, 80, 85, 198, 255, 93, 99, 237, 255, 94, 99, 238,
-    255, 94, 98, 238, 255, 95, 98, 238, 255, 95, 98, 239, 255, 95, 98, 239, 255, 95, 98, 239, 255,
-    95, 97, 238, 255, 96, 97, 238, 255, 96, 97, 239, 255, 96, 97, 239, 255, 96, 97, 239, 255, 96,
-    97, 239, 255, 96, 97, 239, 255, 96, 97, 239, 255, 96, 97, 239, 255, 96, 97, 239, 255, 96, 97,
-    239, 255, 96, 97, 239, 255, 96, 97, 239, 255, 96, 97, 238, 255, 78, 79, 192, 255, 33, 33, 80,
-    255, 4, 4, 9, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0,
-    0, 255, 5, 5, 8, 255, 17, 18, 28, 255, 27, 28, 45, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 31, 33, 55, 255, 49, 52, 109, 255, 72, 78, 178, 255, 90, 98, 232,
-    255, 92, 100, 238, 255, 93, 99, 238, 255, 93, 99, 238, 255, 94, 99, 238, 255, 94, 98, 238, 255,
-    95, 98, 239, 255, 95, 98, 239, 255, 95, 98, 239, 255, 96, 97, 239, 255, 96, 97, 239, 255, 96,
-    97, 238, 255, 96, 97, 238, 255, 96, 97, 239, 255, 96, 97, 239, 255, 96, 97, 239, 255, 96, 97,
-    239, 255, 97, 97, 239, 255, 96, 97, 239, 255, 96, 97, 239, 255, 96, 97, 239, 255, 86, 87, 212,
-    255, 46, 46, 111, 255, 9, 9, 20, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255,
-    0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 1, 1, 1, 255, 8, 8, 12,
-    255, 21, 21, 34, 255, 28, 29, 47, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 30, 31, 50, 255, 42, 45, 88, 255, 69, 77, 174, 255, 88,
-    98, 230, 255, 91, 101, 238, 255, 91, 100, 238, 255, 92, 100, 238, 255, 93, 99, 238, 255, 93,
-    99, 238, 255, 94, 99, 238, 255, 94, 98, 238, 255, 94, 98, 238, 255, 95, 98, 239, 255, 95, 98,
-    239, 255, 96, 97, 239, 255, 96, 97, 239, 255, 96, 97, 239, 255, 96, 97, 239, 255, 96, 97, 238,
-    255, 96, 97, 238, 255, 97, 97, 239, 255, 97, 97, 239, 255, 97, 97, 239, 255, 97, 97, 239, 255,
-    86, 86, 210, 255, 59, 59, 143, 255, 15, 16, 36, 255, 0, 0, 1, 255, 0, 0, 0, 255, 0, 0, 0, 255,
-    0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0,
-    255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 2, 2, 3, 255, 11, 11, 18, 255, 24, 24, 39, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 36, 39, 72, 255, 61, 70, 151, 255,
-    83, 96, 222, 255, 89, 102, 238, 255, 90, 101, 238, 255, 90, 101, 238, 255, 91, 100, 238, 255,
-    92, 100, 238, 255, 92, 100, 238, 255, 93, 99, 238, 255, 93, 99, 238, 255, 94, 99, 238, 255, 94,
-    98, 238, 255, 95, 98, 239, 255, 95, 98, 239, 255, 95, 97, 239, 255, 96, 97, 239, 255, 96, 97,
-    239, 255, 96, 97, 239, 255, 97, 97, 239, 255, 97, 96, 239, 255, 97, 96, 238, 255, 97, 96, 238,
-    255, 91, 91, 224, 255, 59, 59, 143, 255, 17, 17, 40, 255, 2, 2, 4, 255, 0, 0, 0, 255, 0, 0, 0,
-    255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0,
-    0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0,
-    0, 0, 255, 3, 3, 5, 255, 14, 15, 24, 255, 26, 27, 43, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 36, 39, 71, 255, 75, 89,
-    199, 255, 87, 103, 236, 255, 88, 103, 238, 255, 88, 102, 238, 255, 89, 102, 238, 255, 90, 101,
-    238, 255, 90, 101, 238, 255, 91, 100, 238, 255, 92, 100, 238, 255, 92, 100, 238, 255, 93, 99,
-    238, 255, 93, 99, 238, 255, 94, 98, 238, 255, 94, 98, 239, 255, 95, 98, 239, 255, 95, 98, 239,
-    255, 96, 97, 239, 255, 96, 97, 239, 255, 96, 97, 239, 255, 97, 97, 239, 255, 97, 96, 239, 255,
-    97, 96, 239, 255, 93, 92, 229, 255, 35, 35, 85, 255, 3, 3, 6, 255, 0, 0, 0, 255, 0, 0, 0, 255,
-    0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0,
-    255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0,
-    0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 6, 6, 9, 255, 25, 25, 41, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 39, 43,
-    80, 255, 81, 98, 221, 255, 87, 103, 237, 255, 87, 103, 238, 255, 88, 102, 238, 255, 89, 102,
-    238, 255, 89, 102, 238, 255, 90, 101, 238, 255, 90, 101, 238, 255, 91, 100, 238, 255, 92, 100,
-    238, 255, 92, 99, 238, 255, 93, 99, 238, 255, 94, 99, 238, 255, 94, 98, 238, 255, 95, 98, 239,
-    255, 95, 98, 239, 255, 96, 97, 239, 255, 96, 97, 239, 255, 96, 97, 239, 255, 97, 96, 239, 255,
-    97, 96, 239, 255, 97, 96, 239, 255, 91, 90, 224, 255, 19, 19, 46, 255, 0, 0, 0, 255, 0, 0, 0,
-    255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0,
-    0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0,
-    0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 1, 1, 1, 255,
-    22, 23, 36, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 39, 43, 80, 255, 81, 98, 221, 255, 86, 104, 237, 255, 87, 103, 237, 255, 87, 103, 238,
-    255, 88, 102, 238, 255, 89, 102, 238, 255, 89, 102, 238, 255, 90, 101, 238, 255, 91, 101, 238,
-    255, 91, 100, 238, 255, 92, 100, 238, 255, 93, 99, 238, 255, 93, 99, 238, 255, 94, 99, 238,
-    255, 95, 98, 239, 255, 95, 98, 239, 255, 95, 97, 239, 255, 96, 97, 239, 255, 96, 97, 239, 255,
-    97, 96, 239, 255, 97, 96, 239, 255, 97, 96, 239, 255, 91, 90, 224, 255, 19, 19, 46, 255, 0, 0,
-    0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0,
-    0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255,
-    0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0,
-    255, 1, 1, 1, 255, 22, 23, 36, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 39, 43, 80, 255, 81, 99, 222, 255, 86, 104, 237, 255, 86, 104, 237,
-    255, 87, 103, 237, 255, 88, 103, 238, 255, 88, 102, 238, 255, 89, 102, 238, 255, 90, 101, 238,
-    255, 90, 101, 238, 255, 91, 100, 238, 255, 92, 100, 238, 255, 92, 100, 238, 255, 93, 99, 238,
-    255, 94, 99, 238, 255, 94, 98, 238, 255, 95, 98, 239, 255, 95, 98, 239, 255, 96, 97, 239, 255,
-    96, 97, 239, 255, 97, 96, 239, 255, 97, 96, 239, 255, 97, 96, 239, 255, 91, 90, 224, 255, 19,
-    19, 46, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0,
-    255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0,
-    0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0,
-    0, 0, 255, 0, 0, 0, 255, 1, 1, 1, 255, 22, 23, 36, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 39, 43, 80, 255, 80, 99, 222, 255, 85, 104, 237,
-    255, 86, 104, 237, 255, 87, 103, 237, 255, 87, 103, 237, 255, 88, 103, 238, 255, 88, 102, 238,
-    255, 89, 102, 238, 255, 90, 101, 238, 255, 90, 101, 238, 255, 91, 100, 238, 255, 92, 100, 238,
-    255, 93, 99, 238, 255, 93, 99, 238, 255, 94, 98, 238, 255, 95, 98, 239, 255, 95, 98, 239, 255,
-    96, 97, 239, 255, 96, 97, 239, 255, 97, 97, 239, 255, 97, 96, 239, 255, 97, 96, 239, 255, 91,
-    90, 224, 255, 19, 19, 46, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0,
-    255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0,
-    0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0,
-    0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 1, 1, 1, 255, 22, 23, 36, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 38, 43, 80, 255, 79, 99, 222, 255,
-    85, 105, 237, 255, 85, 104, 237, 255, 86, 104, 237, 255, 87, 103, 237, 255, 87, 103, 237, 255,
-    88, 102, 238, 255, 88, 102, 238, 255, 89, 102, 238, 255, 90, 101, 238, 255, 91, 101, 238, 255,
-    91, 100, 238, 255, 92, 100, 238, 255, 93, 99, 238, 255, 94, 99, 238, 255, 95, 98, 238, 255, 95,
-    98, 239, 255, 96, 97, 239, 255, 96, 97, 239, 255, 97, 97, 239, 255, 97, 96, 239, 255, 97, 96,
-    239, 255, 91, 90, 224, 255, 19, 19, 46, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0,
-    255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0,
-    0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0,
-    0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 1, 1, 1, 255, 22, 23, 36, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 38, 43, 81, 255, 79,
-    100, 222, 255, 84, 105, 237, 255, 85, 105, 237, 255, 86, 104, 237, 255, 86, 104, 237, 255, 87,
-    103, 237, 255, 87, 103, 237, 255, 88, 102, 238, 255, 89, 102, 238, 255, 89, 101, 238, 255, 90,
-    101, 238, 255, 91, 100, 238, 255, 92, 100, 238, 255, 93, 99, 238, 255, 93, 99, 238, 255, 94,
-    98, 238, 255, 95, 98, 239, 255, 95, 97, 239, 255, 96, 97, 239, 255, 97, 97, 239, 255, 97, 96,
-    239, 255, 97, 96, 239, 255, 92, 90, 224, 255, 19, 19, 46, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0,
-    0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255,
-    0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0,
-    255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 1, 1, 1, 255, 22,
-    23, 36, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 38, 43, 80, 255, 79, 100, 222, 255, 84, 105, 237, 255, 84, 105, 237, 255, 85, 105, 237,
-    255, 86, 104, 237, 255, 86, 104, 237, 255, 87, 103, 237, 255, 87, 103, 238, 255, 88, 102, 238,
-    255, 89, 102, 238, 255, 90, 101, 238, 255, 91, 101, 238, 255, 91, 100, 238, 255, 92, 100, 238,
-    255, 93, 99, 238, 255, 94, 99, 238, 255, 94, 98, 239, 255, 95, 98, 239, 255, 96, 97, 239, 255,
-    96, 97, 239, 255, 97, 96, 239, 255, 97, 96, 239, 255, 91, 90, 223, 255, 19, 19, 46, 255, 0, 0,
-    0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0,
-    0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255,
-    0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0,
-    255, 1, 1, 1, 255, 22, 23, 36, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 38, 43, 80, 255, 78, 100, 222, 255, 83, 106, 237, 255, 84, 105, 237,
-    255, 84, 105, 237, 255, 85, 104, 237, 255, 86, 104, 237, 255, 86, 104, 237, 255, 87, 103, 237,
-    255, 88, 103, 238, 255, 88, 102, 238, 255, 89, 102, 238, 255, 90, 101, 238, 255, 91, 101, 238,
-    255, 92, 100, 238, 255, 93, 99, 238, 255, 94, 99, 238, 255, 94, 98, 238, 255, 95, 98, 239, 255,
-    96, 97, 239, 255, 96, 97, 239, 255, 97, 97, 239, 255, 97, 96, 239, 255, 91, 90, 223, 255, 19,
-    19, 46, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0,
-    255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0,
-    0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0,
-    0, 0, 255, 0, 0, 0, 255, 1, 1, 1, 255, 22, 23, 36, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 38, 43, 80, 255, 78, 100, 222, 255, 83, 106,
-    237, 255, 83, 106, 237, 255, 84, 105, 237, 255, 85, 105, 237, 255, 85, 104, 237, 255, 86, 104,
-    237, 255, 86, 104, 237, 255, 87, 103, 237, 255, 88, 103, 238, 255, 89, 102, 238, 255, 90, 101,
-    238, 255, 91, 101, 238, 255, 91, 100, 238, 255, 92, 100, 238, 255, 93, 99, 238, 255, 94, 99,
-    238, 255, 95, 98, 239, 255, 95, 98, 239, 255, 96, 97, 239, 255, 97, 97, 239, 255, 97, 96, 239,
-    255, 91, 90, 223, 255, 19, 19, 46, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255,
-    0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0,
-    255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0,
-    0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 1, 1, 1, 255, 22, 23, 36, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 38, 43, 80, 255, 78,
-    101, 222, 255, 82, 106, 237, 255, 83, 106, 237, 255, 83, 105, 237, 255, 84, 105, 237, 255, 85,
-    105, 237, 255, 85, 104, 237, 255, 86, 104, 237, 255, 87, 103, 237, 255, 87, 103, 238, 255, 88,
-    102, 238, 255, 89, 102, 238, 255, 90, 101, 238, 255, 91, 101, 238, 255, 92, 100, 238, 255, 93,
-    99, 238, 255, 94, 99, 238, 255, 94, 98, 238, 255, 95, 98, 239, 255, 96, 97, 239, 255, 96, 97,
-    239, 255, 97, 97, 239, 255, 91, 90, 223, 255, 19, 19, 46, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0,
-    0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255,
-    0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0,
-    255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 1, 1, 1, 255, 22,
-    23, 36, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 38, 43, 80, 255, 77, 101, 222, 255, 82, 107, 237, 255, 82, 106, 237, 255, 83, 106, 237,
-    255, 84, 105, 237, 255, 84, 105, 237, 255, 85, 105, 237, 255, 85, 104, 237, 255, 86, 104, 237,
-    255, 87, 103, 237, 255, 88, 103, 238, 255, 89, 102, 238, 255, 89, 102, 238, 255, 90, 101, 238,
-    255, 92, 100, 238, 255, 92, 100, 238, 255, 93, 99, 238, 255, 94, 99, 239, 255, 95, 98, 239,
-    255, 96, 97, 239, 255, 96, 97, 239, 255, 97, 97, 239, 255, 91, 90, 223, 255, 19, 19, 46, 255,
-    0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0,
-    255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0,
-    0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0,
-    0, 0, 255, 1, 1, 1, 255, 22, 23, 36, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 38, 43, 80, 255, 77, 101, 221, 255, 82, 107, 237, 255, 82,
-    106, 237, 255, 83, 106, 237, 255, 83, 106, 237, 255, 83, 105, 237, 255, 84, 105, 237, 255, 85,
-    105, 237, 255, 85, 104, 237, 255, 86, 104, 237, 255, 87, 103, 237, 255, 88, 102, 238, 255, 89,
-    102, 238, 255, 90, 101, 238, 255, 91, 101, 238, 255, 92, 100, 238, 255, 93, 99, 238, 255, 94,
-    99, 238, 255, 94, 98, 239, 255, 95, 98, 239, 255, 96, 97, 239, 255, 96, 97, 239, 255, 91, 91,
-    223, 255, 19, 19, 46, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0,
-    255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0,
-    0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0,
-    0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 1, 1, 1, 255, 22, 23, 36, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 37, 41, 75, 255, 75, 99, 215, 255,
-    81, 107, 237, 255, 82, 107, 237, 255, 82, 106, 237, 255, 83, 106, 237, 255, 83, 106, 237, 255,
-    84, 105, 237, 255, 84, 105, 237, 255, 85, 104, 237, 255, 86, 104, 237, 255, 87, 103, 237, 255,
-    88, 103, 238, 255, 89, 102, 238, 255, 89, 102, 238, 255, 91, 101, 238, 255, 92, 100, 238, 255,
-    92, 100, 238, 255, 93, 99, 238, 255, 94, 98, 238, 255, 95, 98, 239, 255, 95, 97, 239, 255, 96,
-    97, 239, 255, 89, 90, 219, 255, 19, 19, 44, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0,
-    0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255,
-    0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0,
-    255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 1, 2, 3, 255, 23, 24, 38, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 31, 32, 54,
-    255, 47, 57, 114, 255, 71, 93, 201, 255, 81, 106, 235, 255, 82, 106, 237, 255, 82, 106, 237,
-    255, 83, 106, 237, 255, 83, 105, 237, 255, 84, 105, 237, 255, 85, 105, 237, 255, 85, 104, 237,
-    255, 86, 104, 237, 255, 87, 103, 237, 255, 88, 102, 238, 255, 89, 102, 238, 255, 90, 101, 238,
-    255, 91, 100, 238, 255, 92, 100, 238, 255, 93, 99, 238, 255, 94, 99, 238, 255, 94, 98, 239,
-    255, 95, 97, 238, 255, 85, 86, 208, 255, 55, 56, 123, 255, 24, 25, 44, 255, 8, 8, 14, 255, 1,
-    1, 1, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255,
-    0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0,
-    255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 4, 4, 7, 255, 17,
-    17, 27, 255, 28, 29, 46, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 34, 38, 67, 255, 54, 68, 140, 255, 76,
-    99, 216, 255, 82, 106, 237, 255, 82, 106, 237, 255, 83, 106, 237, 255, 83, 105, 237, 255, 84,
-    105, 237, 255, 85, 104, 237, 255, 86, 104, 237, 255, 87, 103, 237, 255, 88, 103, 238, 255, 89,
-    102, 238, 255, 90, 101, 238, 255, 91, 101, 238, 255, 91, 100, 238, 255, 92, 100, 238, 255, 93,
-    99, 238, 255, 88, 92, 221, 255, 64, 66, 149, 255, 37, 38, 71, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 28, 28, 46, 255, 18, 19, 30, 255, 8, 8, 13, 255, 1, 1, 1, 255, 0, 0, 0, 255, 0, 0, 0, 255,
-    0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0,
-    255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 2, 2, 4, 255, 13,
-    13, 21, 255, 25, 26, 41, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 49, 255, 38, 43, 81, 255, 62, 78, 166, 255, 77, 99, 219, 255, 82, 106,
-    237, 255, 83, 106, 237, 255, 84, 105, 237, 255, 84, 105, 237, 255, 85, 104, 237, 255, 86, 104,
-    237, 255, 87, 103, 237, 255, 88, 102, 238, 255, 89, 102, 238, 255, 90, 101, 238, 255, 91, 100,
-    238, 255, 89, 97, 230, 255, 71, 76, 174, 255, 42, 44, 88, 255, 30, 31, 50, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 28, 29, 46, 255, 18,
-    19, 30, 255, 5, 5, 8, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0,
-    255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 1, 1,
-    2, 255, 9, 9, 15, 255, 22, 23, 37, 255, 29, 30, 47, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 30,
-    32, 53, 255, 38, 43, 80, 255, 63, 79, 169, 255, 81, 103, 229, 255, 83, 105, 237, 255, 84, 105,
-    237, 255, 85, 105, 237, 255, 86, 104, 237, 255, 87, 103, 237, 255, 88, 103, 238, 255, 89, 102,
-    238, 255, 88, 99, 232, 255, 76, 85, 194, 255, 48, 52, 108, 255, 31, 32, 54, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 26, 27, 43, 255, 14, 15, 23, 255, 3, 3, 4, 255,
-    0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0,
-    255, 0, 0, 1, 255, 6, 6, 10, 255, 19, 20, 32, 255, 28, 29, 46, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 30, 31, 51, 255,
-    44, 51, 100, 255, 70, 87, 191, 255, 83, 104, 235, 255, 84, 105, 237, 255, 85, 104, 237, 255,
-    86, 104, 237, 255, 87, 103, 236, 255, 76, 87, 197, 255, 48, 53, 107, 255, 34, 35, 62, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 24, 25, 39, 255, 10, 10, 17, 255, 1, 1, 2, 255, 0, 0, 0, 255, 0,
-    0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 4, 4, 6, 255, 16, 16, 26, 255, 26, 27, 44, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30,
-    48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255,
-    29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 32, 34, 58, 255, 51, 61,
-    124, 255, 76, 94, 209, 255, 85, 105, 237, 255, 78, 95, 213, 255, 55, 62, 132, 255, 33, 35, 61,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 28,
-    29, 47, 255, 21, 22, 34, 255, 7, 7, 11, 255, 0, 0, 1, 255, 3, 3, 6, 255, 16, 16, 25, 255, 24,
-    25, 40, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 35, 39, 70, 255, 52, 62, 126,
-    255, 36, 40, 73, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 27, 28, 45,
-    255, 19, 19, 31, 255, 24, 25, 41, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29,
-    30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48, 255, 29, 30, 48,
-    255, 29, 30, 48, 255,
-];
+use crate::decode;
+
+/// The window icon in the sizes miniquad wants, stored as ciebii files
+const SMALL_ICON: &[u8] = include_bytes!("../assets/icon-16.cib");
+const MEDIUM_ICON: &[u8] = include_bytes!("../assets/icon-32.cib");
+const LARGE_ICON: &[u8] = include_bytes!("../assets/icon-64.cib");
+
+/// Returns the bundled window icon.
+pub fn bundled() -> Icon {
+    let decode = |bytes: &[u8]| {
+        let file = CIEBIIFILE::try_from(bytes.to_vec()).expect("the bundled icons are valid");
+        DynamicImage::ImageRgb8(decode::to_image(&file, 0)).to_rgba8()
+    };
+
+    Icon {
+        small: pixels(&decode(SMALL_ICON)),
+        medium: pixels(&decode(MEDIUM_ICON)),
+        big: pixels(&decode(LARGE_ICON)),
+    }
+}
+
+/// Builds a window icon from the ciebii file at `path`, scaled to every size miniquad wants.
+pub fn load(path: &Path) -> anyhow::Result<Icon> {
+    let file = read_file(path)
+        .with_context(|| format!("Failed to load the icon '{}'", path.display()))?;
+    let image = DynamicImage::ImageRgb8(decode::to_image(&file, 0));
+
+    let scaled = |size| image.resize_exact(size, size, FilterType::Triangle).to_rgba8();
+
+    Ok(Icon {
+        small: pixels(&scaled(16)),
+        medium: pixels(&scaled(32)),
+        big: pixels(&scaled(64)),
+    })
+}
+
+/// Copies the pixels of a square icon into an array of its exact size.
+fn pixels<const N: usize>(image: &RgbaImage) -> [u8; N] {
+    image.as_raw()[..].try_into().expect("the icon has the expected size")
+}
@@ -0,0 +1,135 @@
+use std::{fmt::Display, fs::File, io::Read, path::Path};
+
+use anyhow::Context;
+use ciebii_lib::{header::Header, FormatKind};
+use colored::*;
+use image::ImageFormat;
+
+/// Enough bytes to recognize every supported format
+const SNIFF_LEN: u64 = 64;
+
+/// What a file turned out to be.
+#[derive(Debug)]
+pub enum Kind {
+    /// A ciebii file. The dimensions are `None` if the header is damaged.
+    Ciebii {
+        version: u8,
+        dimensions: Option<(usize, usize)>,
+    },
+
+    /// A file in the legacy SHITFILE format
+    Legacy,
+
+    /// A Netpbm image, named after its variant
+    Netpbm(&'static str),
+
+    /// A QOI image
+    Qoi,
+
+    /// A regular image format
+    Raster(ImageFormat),
+
+    Unknown,
+}
+
+impl Kind {
+    /// Returns the MIME type of this kind of file
+    pub fn mime_type(&self) -> &'static str {
+        match self {
+            Kind::Ciebii { .. } => FormatKind::Ciebii.mime_type(),
+            Kind::Legacy => FormatKind::Legacy.mime_type(),
+            Kind::Netpbm("PBM") => "image/x-portable-bitmap",
+            Kind::Netpbm("PGM") => "image/x-portable-graymap",
+            Kind::Netpbm("PAM") => "image/x-portable-arbitrarymap",
+            Kind::Netpbm(_) => "image/x-portable-pixmap",
+            Kind::Qoi => "image/qoi",
+            Kind::Raster(format) => format.to_mime_type(),
+            Kind::Unknown => "application/octet-stream",
+        }
+    }
+}
+
+impl Display for Kind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Kind::Ciebii {
+                version,
+                dimensions: Some((width, height)),
+            } => write!(f, "CIEBII image, version {version}, {width} x {height}"),
+            Kind::Ciebii {
+                version,
+                dimensions: None,
+            } => write!(f, "CIEBII image, version {version}, damaged header"),
+            Kind::Legacy => write!(f, "legacy SHITFILE image"),
+            Kind::Netpbm(variant) => write!(f, "{variant} image"),
+            Kind::Qoi => write!(f, "QOI image"),
+            Kind::Raster(format) => match format.extensions_str().first() {
+                Some(extension) => write!(f, "{} image", extension.to_uppercase()),
+                None => write!(f, "{format:?} image"),
+            },
+            Kind::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+/// Prints what each of the given files is, or just its MIME type with `mime`.
+pub fn identify(file_names: &[String], mime: bool) {
+    for file_name in file_names {
+        match sniff(Path::new(file_name)) {
+            Ok(kind) if mime => println!("{}: {}", file_name.white().bold(), kind.mime_type()),
+            Ok(kind) => println!("{}: {}", file_name.white().bold(), kind),
+            Err(err) => {
+                eprintln!("{}: {} {:#}", file_name.white().bold(), "error:".red().bold(), err)
+            }
+        }
+    }
+}
+
+/// Works out the format of the file at `path` from its magic bytes.
+pub fn sniff(path: &Path) -> anyhow::Result<Kind> {
+    let mut bytes = Vec::new();
+    File::open(path)
+        .and_then(|file| file.take(SNIFF_LEN).read_to_end(&mut bytes))
+        .with_context(|| format!("Failed to read '{}'", path.display()))?;
+
+    match ciebii_lib::sniff(&bytes) {
+        Some(FormatKind::Ciebii) => {
+            let header = bytes
+                .get(..Header::LEN)
+                .and_then(|header| Header::try_from(header.to_vec()).ok());
+
+            // The version byte follows the flags, and is still there in a damaged header
+            let version = header.map_or(bytes.get(27).copied().unwrap_or(0), |header| {
+                header.version()
+            });
+
+            return Ok(Kind::Ciebii {
+                version,
+                dimensions: header.map(|header| header.dimensions()),
+            });
+        }
+        Some(FormatKind::Legacy) => return Ok(Kind::Legacy),
+        None => {}
+    }
+
+    // The image crate lumps every Netpbm variant together
+    let netpbm = match bytes.get(..2) {
+        Some(b"P1" | b"P4") => Some("PBM"),
+        Some(b"P2" | b"P5") => Some("PGM"),
+        Some(b"P3" | b"P6") => Some("PPM"),
+        Some(b"P7") => Some("PAM"),
+        _ => None,
+    };
+    if let Some(variant) = netpbm {
+        return Ok(Kind::Netpbm(variant));
+    }
+
+    if bytes.starts_with(b"qoif") {
+        return Ok(Kind::Qoi);
+    }
+
+    Ok(match image::guess_format(&bytes) {
+        Ok(format) => Kind::Raster(format),
+        Err(_) => Kind::Unknown,
+    })
+}
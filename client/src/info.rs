@@ -0,0 +1,230 @@
+use std::{
+    fs::{self, File},
+    io::Read,
+    path::Path,
+};
+
+use anyhow::Context;
+use ciebii_lib::{
+    checksum::ChecksumAlgorithm, compat, file::CIEBIIFILE, header::Header, io::read_file,
+    FormatKind,
+};
+use macroquad::{
+    color::{Color, GREEN, RED, WHITE},
+    input::{is_key_pressed, KeyCode},
+    math::Rect,
+    shapes::draw_rectangle,
+    text::{draw_text, measure_text},
+};
+
+use crate::errors::Failure;
+
+const FONT_SIZE: f32 = 18.0;
+const LINE_HEIGHT: f32 = 18.0;
+const PADDING: f32 = 6.0;
+
+/// Where the panel starts, below the tab bar
+const TOP: f32 = FONT_SIZE + PADDING * 3.0;
+
+/// Facts about a file that are too slow to work out every frame.
+#[derive(Debug)]
+pub struct Details {
+    name: String,
+    size: Option<u64>,
+
+    // Chunks over every frame whose checksum doesn't match their color
+    corrupt: usize,
+
+    // Whether the file was opened from the legacy SHITFILE format
+    legacy: bool,
+}
+
+impl Details {
+    /// Gathers the details of `file`, which was loaded from `path`.
+    pub fn new(path: &Path, file: &CIEBIIFILE) -> Self {
+        let name = match path.file_name() {
+            Some(name) => name.to_string_lossy().into_owned(),
+            None => path.display().to_string(),
+        };
+
+        let corrupt = (0..file.frame_count())
+            .filter_map(|i| file.frame(i))
+            .flatten()
+            .filter(|chunk| !chunk.is_valid())
+            .count();
+
+        let mut magic = Vec::new();
+        let magic_len = compat::LEGACY_MAGIC_BYTES.len() as u64;
+        let legacy = File::open(path)
+            .and_then(|file| file.take(magic_len).read_to_end(&mut magic))
+            .is_ok_and(|_| ciebii_lib::sniff(&magic) == Some(FormatKind::Legacy));
+
+        Self {
+            name,
+            size: fs::metadata(path).ok().map(|metadata| metadata.len()),
+            corrupt,
+            legacy,
+        }
+    }
+}
+
+/// Prints the dimensions, chunk count, size and header checksum of the ciebii file at
+/// `file_name`, one value per line, then whether every chunk passes its checksum. Works
+/// without a window, so scripts can check files. Files that fail to decode still get what
+/// their header says printed before the error.
+pub fn info(file_name: &str) -> anyhow::Result<()> {
+    let path = Path::new(file_name);
+    let failed = || format!("Failed to read '{}'", path.display());
+
+    let size = fs::metadata(path).with_context(failed)?.len();
+    let mut header = Vec::new();
+    File::open(path)
+        .and_then(|file| file.take(Header::LEN as u64).read_to_end(&mut header))
+        .with_context(failed)?;
+
+    let header = Header::try_from(header)
+        .with_context(|| format!("'{}' doesn't start with a ciebii header", path.display()))?;
+    let (width, height) = header.dimensions();
+
+    let mut format = format!("version {}", header.version());
+    if header.has_alpha() {
+        format.push_str(", alpha");
+    }
+    if header.is_compressed() {
+        format.push_str(", compressed");
+    }
+    format.push_str(checksum_name(header.checksum_algorithm()));
+
+    println!("dimensions {width} x {height}");
+    println!("size {size} ({})", format_size(size));
+    println!("header {:08x}", header.checksum());
+    println!("format {format}");
+
+    let file = match read_file(path) {
+        Ok(file) => file,
+        Err(err) => {
+            let err = anyhow::Error::from(err);
+            let status = match Failure::classify(&err) {
+                Failure::Checksum => "failed",
+                _ => "unknown, the file doesn't decode",
+            };
+
+            println!("chunks {}", width.saturating_mul(height));
+            println!("checksums {status}");
+
+            return Err(err);
+        }
+    };
+
+    println!("frames {}", file.frame_count());
+    println!("chunks {}", width * height * file.frame_count());
+    println!("checksums ok");
+
+    Ok(())
+}
+
+/// A corner overlay with the file name, dimensions, zoom level, file size,
+/// format version and checksum status. Toggled with I.
+#[derive(Debug, Default)]
+pub struct Info {
+    enabled: bool,
+}
+
+impl Info {
+    /// Toggles the overlay when I is pressed.
+    pub fn handle_input(&mut self) {
+        if is_key_pressed(KeyCode::I) {
+            self.enabled = !self.enabled;
+        }
+    }
+
+    /// Draws the overlay for `file` in the top left corner.
+    /// `area` is the screen rectangle the image was drawn to.
+    pub fn draw(&self, file: &CIEBIIFILE, details: &Details, area: Rect) {
+        if !self.enabled {
+            return;
+        }
+
+        let (width, height) = file.dimensions();
+
+        let size = match details.size {
+            Some(size) => format_size(size),
+            None => "unknown size".to_owned(),
+        };
+
+        let (status, status_color) = match details.corrupt {
+            0 => ("checksums ok".to_owned(), GREEN),
+            n => (format!("{n} corrupt chunks"), RED),
+        };
+
+        let version = match details.legacy {
+            true => "legacy SHITFILE format".to_owned(),
+            false => {
+                let mut version = format!("format version {}", file.version());
+                if file.has_alpha() {
+                    version.push_str(", alpha");
+                }
+                if file.is_compressed() {
+                    version.push_str(", compressed");
+                }
+                version.push_str(checksum_name(file.checksum_algorithm()));
+
+                version
+            }
+        };
+
+        let lines = [
+            (details.name.clone(), WHITE),
+            (format!("{width} x {height}"), WHITE),
+            (format!("zoom {:.0}%", area.w / width.max(1) as f32 * 100.0), WHITE),
+            (size, WHITE),
+            (version, WHITE),
+            (status, status_color),
+        ];
+
+        let panel_width = lines
+            .iter()
+            .map(|(line, _)| measure_text(line, None, FONT_SIZE as u16, 1.0).width)
+            .fold(0.0, f32::max)
+            + PADDING * 2.0;
+        let panel_height = lines.len() as f32 * LINE_HEIGHT + PADDING * 2.0;
+
+        draw_rectangle(PADDING, TOP, panel_width, panel_height, Color::new(0.0, 0.0, 0.0, 0.75));
+
+        for (i, (line, color)) in lines.iter().enumerate() {
+            draw_text(
+                line,
+                PADDING * 2.0,
+                TOP + PADDING + (i as f32 + 0.8) * LINE_HEIGHT,
+                FONT_SIZE,
+                *color,
+            );
+        }
+    }
+}
+
+/// Names the checksums of a file for the end of its format line
+fn checksum_name(algorithm: ChecksumAlgorithm) -> &'static str {
+    match algorithm {
+        ChecksumAlgorithm::Ciebii => ", ciebii checksums",
+        ChecksumAlgorithm::Crc32 => ", crc32 checksums",
+    }
+}
+
+/// Formats a byte count with a binary unit, e.g. `1.5 MiB`.
+pub fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+
+    format!("{size:.1} {}", UNITS[unit])
+}
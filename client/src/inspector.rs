@@ -0,0 +1,136 @@
+use ciebii_lib::{chunk::Chunk, file::CIEBIIFILE};
+use macroquad::{
+    color::{Color, GREEN, RED, WHITE},
+    input::{is_key_pressed, is_mouse_button_pressed, mouse_position, KeyCode, MouseButton},
+    math::{vec2, Rect},
+    shapes::draw_rectangle,
+    text::draw_text,
+    window::{get_internal_gl, screen_height, screen_width},
+};
+
+use colored::*;
+
+const FONT_SIZE: f32 = 18.0;
+const LINE_HEIGHT: f32 = 18.0;
+const PADDING: f32 = 6.0;
+const PANEL_WIDTH: f32 = 190.0;
+
+/// A hover overlay showing the coordinates, color and checksum status of the pixel under the cursor.
+/// Toggled with P; clicking a pixel copies its hex code to the clipboard.
+#[derive(Debug, Default)]
+pub struct Inspector {
+    enabled: bool,
+}
+
+impl Inspector {
+    /// Toggles the inspector when P is pressed.
+    pub fn handle_input(&mut self) {
+        if is_key_pressed(KeyCode::P) {
+            self.enabled = !self.enabled;
+        }
+    }
+
+    /// Draws the overlay for the pixel under the cursor in the given frame, if any.
+    /// `area` is the screen rectangle the image was drawn to.
+    pub fn draw(&self, file: &CIEBIIFILE, frame: usize, area: Rect) {
+        if !self.enabled {
+            return;
+        }
+
+        let (mouse_x, mouse_y) = mouse_position();
+
+        // Nothing to inspect outside of the image
+        if !area.contains(vec2(mouse_x, mouse_y)) {
+            return;
+        }
+
+        let (width, height) = file.dimensions();
+
+        // Map the cursor back onto image coordinates
+        let x = (((mouse_x - area.x) / area.w * width as f32) as usize).min(width - 1);
+        let y = (((mouse_y - area.y) / area.h * height as f32) as usize).min(height - 1);
+
+        let chunk = match file.frame(frame).and_then(|chunks| chunks.get(y * width + x)) {
+            Some(chunk) => chunk,
+            None => return,
+        };
+
+        let hex = chunk.rgb().to_string();
+
+        if is_mouse_button_pressed(MouseButton::Left) {
+            copy_to_clipboard(&hex);
+        }
+
+        let checksum = file.stored_checksum(chunk);
+        draw_panel(x, y, chunk, checksum, &hex, mouse_x, mouse_y);
+    }
+}
+
+/// Draws the info panel next to the cursor, keeping it on screen. `checksum` is the one the
+/// chunk is stored with.
+fn draw_panel(
+    x: usize,
+    y: usize,
+    chunk: &Chunk,
+    checksum: u16,
+    hex: &str,
+    mouse_x: f32,
+    mouse_y: f32,
+) {
+    let (r, g, b) = chunk.rgb().color();
+
+    let (status, status_color) = if chunk.is_valid() {
+        ("ok", GREEN)
+    } else {
+        ("MISMATCH", RED)
+    };
+
+    let lines = [
+        (format!("({x}, {y})"), WHITE),
+        (format!("rgb({r}, {g}, {b})"), WHITE),
+        (hex.to_owned(), WHITE),
+        (format!("checksum {checksum:#06x} {status}"), status_color),
+    ];
+
+    let panel_height = lines.len() as f32 * LINE_HEIGHT + PADDING * 2.0;
+
+    // Flip the panel to the other side of the cursor near the window edges
+    let mut panel_x = mouse_x + 16.0;
+    let mut panel_y = mouse_y + 16.0;
+    if panel_x + PANEL_WIDTH > screen_width() {
+        panel_x = mouse_x - PANEL_WIDTH - 16.0;
+    }
+    if panel_y + panel_height > screen_height() {
+        panel_y = mouse_y - panel_height - 16.0;
+    }
+
+    draw_rectangle(panel_x, panel_y, PANEL_WIDTH, panel_height, Color::new(0.0, 0.0, 0.0, 0.75));
+
+    // A swatch of the inspected color
+    draw_rectangle(
+        panel_x + PANEL_WIDTH - PADDING - LINE_HEIGHT,
+        panel_y + PADDING,
+        LINE_HEIGHT,
+        LINE_HEIGHT,
+        Color::from_rgba(r, g, b, 255),
+    );
+
+    for (i, (line, color)) in lines.iter().enumerate() {
+        draw_text(
+            line,
+            panel_x + PADDING,
+            panel_y + PADDING + (i as f32 + 0.8) * LINE_HEIGHT,
+            FONT_SIZE,
+            *color,
+        );
+    }
+}
+
+/// Copies `text` to the system clipboard.
+fn copy_to_clipboard(text: &str) {
+
+    // macroquad doesn't expose the clipboard, so go through miniquad directly.
+    unsafe { get_internal_gl().quad_context.clipboard_set(text) };
+
+    println!("📋 {} {}", "Copied".green().bold(), text.white().bold());
+}
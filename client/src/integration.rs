@@ -0,0 +1,177 @@
+use std::{env, path::Path, process::Command};
+
+use anyhow::Context;
+use ciebii_lib::format;
+use colored::*;
+
+/// The name the viewer is registered under, as the desktop entry file on Linux and the
+/// ProgID on Windows
+const APP_ID: &str = "ciebii";
+
+/// Registers the `.cib` extension and MIME type with the desktop, with `cib render` as the
+/// program that opens them, so double-clicking a ciebii file opens the viewer.
+/// The executable that's running is the one registered.
+pub fn install() -> anyhow::Result<()> {
+    let exe = env::current_exe().context("Failed to find the cib executable")?;
+
+    register(&exe)?;
+
+    println!(
+        "🖼️ {} {} {}",
+        "Registered".green().bold(),
+        format!("'{}'", exe.display()).white().bold(),
+        format!("as the viewer of .{} files", format::EXTENSION).bold()
+    );
+
+    Ok(())
+}
+
+/// Installs a shared-mime-info package and a desktop entry into `$XDG_DATA_HOME`, or
+/// `~/.local/share` if that isn't set, then refreshes the desktop's databases.
+#[cfg(all(unix, not(target_os = "macos")))]
+fn register(exe: &Path) -> anyhow::Result<()> {
+    use std::{fs, path::PathBuf};
+
+    let data_dir = match env::var_os("XDG_DATA_HOME") {
+        Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+        _ => {
+            let home = env::var_os("HOME").context("Neither XDG_DATA_HOME nor HOME is set")?;
+            PathBuf::from(home).join(".local/share")
+        }
+    };
+
+    let packages = data_dir.join("mime/packages");
+    let applications = data_dir.join("applications");
+    fs::create_dir_all(&packages)?;
+    fs::create_dir_all(&applications)?;
+
+    let magic = String::from_utf8_lossy(&ciebii_lib::file::CIEBIIFILE::MAGIC_BYTES);
+    let package = format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<mime-info xmlns="http://www.freedesktop.org/standards/shared-mime-info">
+  <mime-type type="{}">
+    <comment>Ciebii image</comment>
+    <magic priority="50">
+      <match type="string" offset="0" value="{magic}"/>
+    </magic>
+    <glob pattern="*.{}"/>
+  </mime-type>
+</mime-info>
+"#,
+        format::MIME_TYPE,
+        format::EXTENSION
+    );
+
+    let entry = format!(
+        "[Desktop Entry]
+Type=Application
+Name=Ciebii viewer
+Comment=Views ciebii files
+Exec={} render %F
+MimeType={};
+Categories=Graphics;Viewer;
+Terminal=false
+NoDisplay=true
+",
+        quote_exec(&exe.to_string_lossy()),
+        format::MIME_TYPE
+    );
+
+    let package_path = packages.join(format!("{APP_ID}.xml"));
+    let entry_path = applications.join(format!("{APP_ID}.desktop"));
+    fs::write(&package_path, package)
+        .with_context(|| format!("Failed to write '{}'", package_path.display()))?;
+    fs::write(&entry_path, entry)
+        .with_context(|| format!("Failed to write '{}'", entry_path.display()))?;
+
+    // The files are already in place, these just make the desktop notice them sooner
+    let mime_dir = data_dir.join("mime");
+    refresh(Command::new("update-mime-database").arg(&mime_dir));
+    refresh(Command::new("update-desktop-database").arg(&applications));
+    refresh(
+        Command::new("xdg-mime")
+            .arg("default")
+            .arg(format!("{APP_ID}.desktop"))
+            .arg(format::MIME_TYPE),
+    );
+
+    Ok(())
+}
+
+/// Writes the extension, its ProgID and the open command into `HKEY_CURRENT_USER`,
+/// which doesn't need administrator rights.
+#[cfg(windows)]
+fn register(exe: &Path) -> anyhow::Result<()> {
+    let extension_key = format!(r"HKCU\Software\Classes\.{}", format::EXTENSION);
+    let prog_id = format!("{APP_ID}.image");
+    let prog_id_key = format!(r"HKCU\Software\Classes\{prog_id}");
+    let command = format!(r#""{}" render "%1""#, exe.display());
+
+    reg_add(&extension_key, None, &prog_id)?;
+    reg_add(&extension_key, Some("Content Type"), format::MIME_TYPE)?;
+    reg_add(&prog_id_key, None, "Ciebii image")?;
+    reg_add(&format!(r"{prog_id_key}\shell\open\command"), None, &command)?;
+
+    Ok(())
+}
+
+#[cfg(not(any(windows, all(unix, not(target_os = "macos")))))]
+fn register(_exe: &Path) -> anyhow::Result<()> {
+    anyhow::bail!("Desktop integration is only supported on Linux and Windows")
+}
+
+/// Sets `value` of `key` in the registry, or its default value if `value` is `None`
+#[cfg(windows)]
+fn reg_add(key: &str, value: Option<&str>, data: &str) -> anyhow::Result<()> {
+    let mut command = Command::new("reg");
+    command.args(["add", key]);
+
+    match value {
+        Some(value) => command.args(["/v", value]),
+        None => command.arg("/ve"),
+    };
+
+    let status = command
+        .args(["/d", data, "/f"])
+        .output()
+        .context("Failed to run reg")?
+        .status;
+
+    anyhow::ensure!(status.success(), "Failed to write the registry key '{key}'");
+
+    Ok(())
+}
+
+/// Runs a command that refreshes a desktop database, only warning if it's missing or fails
+#[cfg(all(unix, not(target_os = "macos")))]
+fn refresh(command: &mut Command) {
+    let program = command.get_program().to_string_lossy().into_owned();
+
+    match command.output() {
+        Ok(output) if output.status.success() => {}
+        _ => eprintln!(
+            "{} {}",
+            "⚠️ Couldn't run".yellow().bold(),
+            format!("{program}, the desktop may only pick up the changes after logging in again")
+                .yellow()
+        ),
+    }
+}
+
+/// Quotes a path for the `Exec` key of a desktop entry, escaping the characters the
+/// desktop entry spec reserves inside quotes.
+#[cfg(all(unix, not(target_os = "macos")))]
+fn quote_exec(path: &str) -> String {
+    let mut quoted = String::from("\"");
+
+    for c in path.chars() {
+        if matches!(c, '"' | '`' | '$' | '\\') {
+            quoted.push('\\');
+        }
+
+        quoted.push(c);
+    }
+
+    quoted.push('"');
+    quoted
+}
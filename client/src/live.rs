@@ -0,0 +1,199 @@
+use std::{
+    fs,
+    io::Write,
+    net::{TcpListener, TcpStream},
+    path::{Path, PathBuf},
+    sync::{Arc, Condvar, Mutex},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use anyhow::Context;
+use ciebii_lib::file::CIEBIIFILE;
+use colored::*;
+use tungstenite::{Message, WebSocket};
+
+/// The page showing the preview, it draws the pixels pushed over the WebSocket
+const PAGE: &str = include_str!("../assets/live.html");
+
+/// How often the file is checked for changes
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// The longest request head read to tell WebSocket upgrades from page requests
+const HEAD_LEN: usize = 4096;
+
+/// How long a browser gets to take an update before it's dropped
+const WRITE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// The latest decoded file, shared by the watcher and the thread of every browser
+struct Latest {
+    // The file, along with how many updates came before it
+    update: Mutex<(u64, Arc<Vec<u8>>)>,
+    changed: Condvar,
+}
+
+impl Latest {
+    fn new(bytes: Vec<u8>) -> Self {
+        Self {
+            update: Mutex::new((0, Arc::new(bytes))),
+            changed: Condvar::new(),
+        }
+    }
+
+    /// Replaces the file and wakes every browser waiting for it
+    fn publish(&self, bytes: Vec<u8>) {
+        let mut update = self.update.lock().unwrap();
+        *update = (update.0 + 1, Arc::new(bytes));
+        self.changed.notify_all();
+    }
+
+    /// Waits until the file is newer than update `seen`, then returns it along with its update.
+    /// Browsers that fall behind skip straight to the newest file.
+    fn next(&self, seen: Option<u64>) -> (u64, Arc<Vec<u8>>) {
+        let update = self.update.lock().unwrap();
+        let update = self.changed.wait_while(update, |(count, _)| Some(*count) == seen).unwrap();
+
+        update.clone()
+    }
+}
+
+/// Serves a page on `address` showing `file_name`, and pushes the file to every open page
+/// over a WebSocket whenever it changes.
+pub fn live(address: &str, file_name: &str) -> anyhow::Result<()> {
+    let path = PathBuf::from(file_name);
+    let latest = Arc::new(Latest::new(read(&path)?));
+
+    let listener =
+        TcpListener::bind(address).with_context(|| format!("Failed to listen on '{address}'"))?;
+    println!(
+        "📡 {} {}",
+        "Live preview on".bold(),
+        format!("http://{}", listener.local_addr()?).white().bold()
+    );
+
+    {
+        let latest = latest.clone();
+        thread::spawn(move || watch(&path, &latest));
+    }
+
+    for stream in listener.incoming().flatten() {
+        let latest = latest.clone();
+
+        // Every browser is written to from its own thread, so a slow one holds up no one else
+        thread::spawn(move || {
+            if let Err(err) = handle(stream, &latest) {
+                eprintln!("{} {err:#}", "Failed to serve a request:".red().bold());
+            }
+        });
+    }
+
+    Ok(())
+}
+
+/// Reads the file at `path` and decodes its first frame into what the page draws: the width
+/// and height as big-endian u32s, then every pixel row by row as RGBA. Decoding here keeps
+/// the page in step with the format, whatever flags the file has.
+fn read(path: &Path) -> anyhow::Result<Vec<u8>> {
+    let bytes = fs::read(path).with_context(|| format!("Failed to read '{}'", path.display()))?;
+    let file = CIEBIIFILE::try_from(bytes)?;
+
+    let (width, height) = file.dimensions();
+    let (Ok(width), Ok(height)) = (u32::try_from(width), u32::try_from(height)) else {
+        anyhow::bail!("{width} x {height} is too large to preview");
+    };
+
+    let mut message = [width.to_be_bytes(), height.to_be_bytes()].concat();
+    for chunk in file.chunks() {
+        let (r, g, b, a) = chunk.rgba().color();
+        message.extend([r, g, b, a]);
+    }
+
+    Ok(message)
+}
+
+/// Publishes the file at `path` to every client whenever it changes.
+fn watch(path: &Path, latest: &Latest) {
+    let modified = || fs::metadata(path).and_then(|metadata| metadata.modified()).ok();
+    let mut seen: Option<SystemTime> = modified();
+
+    loop {
+        thread::sleep(POLL_INTERVAL);
+
+        let now = modified();
+        if now == seen {
+            continue;
+        }
+
+        // A file that is still being written won't parse yet, so it's tried again next time
+        let Ok(bytes) = read(path) else {
+            continue;
+        };
+        seen = now;
+
+        println!("🔄 {}", "pushing an update".bold());
+        latest.publish(bytes);
+    }
+}
+
+/// Answers a connection: WebSocket upgrades get the current file and every update after it,
+/// anything else gets the page.
+fn handle(mut stream: TcpStream, latest: &Latest) -> anyhow::Result<()> {
+    let head = peek_head(&stream)?.to_ascii_lowercase();
+
+    if head.contains("upgrade: websocket") {
+        stream.set_write_timeout(Some(WRITE_TIMEOUT))?;
+        let client = tungstenite::accept(stream)
+            .map_err(|err| anyhow::anyhow!("WebSocket handshake failed: {err}"))?;
+
+        push(client, latest);
+
+        return Ok(());
+    }
+
+    let response = match head.starts_with("get / ") {
+        true => format!(
+            "HTTP/1.1 200 OK\r\nContent-Type: text/html; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{PAGE}",
+            PAGE.len()
+        ),
+        false => "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n".to_owned(),
+    };
+
+    stream.write_all(response.as_bytes())?;
+
+    Ok(())
+}
+
+/// Sends `client` the file every time it changes, until the browser goes away or stops
+/// taking updates in time.
+fn push(mut client: WebSocket<TcpStream>, latest: &Latest) {
+    let mut seen = None;
+
+    loop {
+        let (count, bytes) = latest.next(seen);
+        if client.send(Message::binary(bytes.to_vec())).is_err() {
+            return;
+        }
+
+        seen = Some(count);
+    }
+}
+
+/// Returns the head of the request on `stream` without consuming it,
+/// so the WebSocket handshake can still read it.
+fn peek_head(stream: &TcpStream) -> anyhow::Result<String> {
+    let mut buffer = [0; HEAD_LEN];
+
+    // The head may arrive in pieces, give it a second at most
+    for _ in 0..100 {
+        let len = stream.peek(&mut buffer)?;
+        let head = &buffer[..len];
+
+        if len == 0 || len == HEAD_LEN || head.windows(4).any(|window| window == b"\r\n\r\n") {
+            return Ok(String::from_utf8_lossy(head).into_owned());
+        }
+
+        thread::sleep(Duration::from_millis(10));
+    }
+
+    anyhow::bail!("The request never finished")
+}
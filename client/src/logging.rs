@@ -0,0 +1,66 @@
+use std::{fs::OpenOptions, path::Path, sync::Mutex};
+
+use anyhow::Context;
+use clap::ValueEnum;
+use tracing_subscriber::fmt::format::FmtSpan;
+
+/// How much `--log-level` lets through, from least to most
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum LogLevel {
+    /// Only failures
+    Error,
+
+    /// Failures and anything that looks off
+    Warn,
+
+    /// How long reading, converting and rendering took
+    Info,
+
+    /// Details logged by the libraries cib uses too
+    Debug,
+
+    /// Everything
+    Trace,
+}
+
+impl LogLevel {
+    fn filter(self) -> tracing::Level {
+        match self {
+            LogLevel::Error => tracing::Level::ERROR,
+            LogLevel::Warn => tracing::Level::WARN,
+            LogLevel::Info => tracing::Level::INFO,
+            LogLevel::Debug => tracing::Level::DEBUG,
+            LogLevel::Trace => tracing::Level::TRACE,
+        }
+    }
+}
+
+/// Starts logging at `level` to `file`, or to stderr without one. Every phase is logged when
+/// it ends along with how long it took. Logging stays off unless one of them is given, and
+/// only a file defaults to `info`.
+pub fn init(level: Option<LogLevel>, file: Option<&Path>) -> anyhow::Result<()> {
+    let level = match (level, file) {
+        (Some(level), _) => level,
+        (None, Some(_)) => LogLevel::Info,
+        (None, None) => return Ok(()),
+    };
+
+    let builder = tracing_subscriber::fmt()
+        .with_max_level(level.filter())
+        .with_span_events(FmtSpan::CLOSE);
+
+    match file {
+        Some(path) => {
+            let file = OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(path)
+                .with_context(|| format!("Failed to open the log file '{}'", path.display()))?;
+
+            builder.with_ansi(false).with_writer(Mutex::new(file)).init();
+        }
+        None => builder.with_writer(std::io::stderr).init(),
+    }
+
+    Ok(())
+}
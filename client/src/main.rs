@@ -1,13 +1,66 @@
+mod adjust;
+mod backdrop;
+mod batch;
+mod cache;
+mod checksum;
+mod clipboard;
+mod compare;
+mod config;
 mod convert;
+mod decode;
+mod edit;
+mod errors;
+mod frames;
+mod gallery;
+mod generate;
+mod grid;
+mod help;
+mod histogram;
 mod icons;
+mod identify;
+mod info;
+mod integration;
+mod live;
+mod logging;
+mod inspector;
+mod metadata;
+mod palette;
+mod player;
+mod print;
 mod render;
+mod repl;
+mod serve;
+mod steg;
+mod strip;
+mod tabs;
+mod tile;
+mod upgrade;
+mod vectors;
+mod view;
+mod vision;
+mod watch;
 
-use std::path::Path;
+use std::{
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
 
+use ciebii_lib::{
+    rgb::RGB,
+    rotate::{Axis, Rotation},
+};
+use anyhow::Context;
+use cache::Cache;
 use clap::{Parser, Subcommand};
 use colored::*;
+use config::Config;
+use convert::{Alpha, ConvertOptions, RawFormat, Target, WriteArgs};
+use edit::{Annotation, Patch, Region, ResizeFilter};
+use generate::Pattern;
+use logging::LogLevel;
 use macroquad::prelude::*;
-use render::render;
+use print::PrintMode;
+use render::{render, RenderOptions};
 
 /// ✨ Ciebii file viewer ✨
 #[derive(Parser, Debug)]
@@ -15,47 +68,878 @@ use render::render;
 struct Args {
     #[command(subcommand)]
     command: Commands,
+
+    /// Prints errors as JSON objects on stderr instead of colored text
+    #[arg(long, global = true)]
+    json_errors: bool,
+
+    /// Logs how long reading, converting and rendering take, to stderr or `--log-file`
+    #[arg(long, global = true, value_name = "LEVEL")]
+    log_level: Option<LogLevel>,
+
+    /// Appends the log to this file instead, at the `info` level unless `--log-level` is given
+    #[arg(long, global = true, value_name = "PATH")]
+    log_file: Option<PathBuf>,
 }
 
 #[derive(Debug, Subcommand)]
 enum Commands {
     /// Renders a ciebii file
-    Render { file_name: String },
+    Render {
+        /// The files to open, several files are opened as tabs
+        #[arg(required_unless_present = "slideshow", conflicts_with = "slideshow")]
+        file_names: Vec<String>,
+
+        /// Cycles through every ciebii file in a directory
+        #[arg(long, value_name = "DIR")]
+        slideshow: Option<String>,
+
+        /// Seconds between slides in slideshow mode
+        #[arg(long, default_value_t = 3.0, value_parser = parse_interval)]
+        interval: f64,
+
+        /// Starts the viewer in fullscreen mode (toggle with F11 or F)
+        #[arg(long)]
+        fullscreen: bool,
+
+        /// Saves the rendered image as a PNG next to the source file (also bound to S)
+        #[arg(long)]
+        screenshot: bool,
+
+        /// The color of the pixel grid overlay (toggle with G) [default: #404040]
+        #[arg(long, value_name = "COLOR")]
+        grid_color: Option<RGB>,
+
+        /// The window background, also used for the bars around the image [default: #222222]
+        #[arg(long, value_name = "COLOR")]
+        background: Option<RGB>,
+
+        /// Opens the window at this scale instead of fitting the image to the screen
+        #[arg(long, value_name = "N", value_parser = parse_scale)]
+        scale: Option<f32>,
+
+        /// Also browses PNG/JPG/BMP files next to the ciebii files
+        #[arg(long)]
+        images: bool,
+
+        /// Caps the viewer at this many frames per second
+        #[arg(long, value_parser = parse_scale)]
+        fps: Option<f32>,
+
+        /// Draws frames as fast as possible instead of waiting for the display
+        #[arg(long)]
+        no_vsync: bool,
+
+        /// Smooths the image when scaling instead of keeping pixels crisp (toggle with N)
+        #[arg(long)]
+        smooth: bool,
+
+        /// Compares two files of the same size, D shows where they differ
+        #[arg(long, conflicts_with_all = ["slideshow", "output"])]
+        compare: bool,
+
+        /// Writes the rendered image to this file (PNG, JPG, ...) instead of opening a window
+        #[arg(long, value_name = "FILE", conflicts_with = "slideshow")]
+        output: Option<String>,
+    },
+
+    /// Converts a PNG/JPG/PPM/PGM/PAM/QOI/SHF file into a ciebii file, or a legacy SHITFILE
+    Convert {
+        /// The image to convert, a directory to convert every image in, or the ciebii file to
+        /// write with --raw
+        #[arg(required_unless_present = "from_clipboard")]
+        i: Option<String>,
+
+        /// Writes the results into this directory instead of the current one
+        #[arg(short, long, value_name = "DIR", conflicts_with_all = ["from_clipboard", "raw"])]
+        out_dir: Option<PathBuf>,
+
+        /// Converts the image on the clipboard into the given ciebii file instead
+        #[arg(long, value_name = "OUT", conflicts_with = "i")]
+        from_clipboard: Option<String>,
+
+        /// Reads a raw pixel buffer without any header, writing it to the given ciebii file
+        #[arg(long, value_name = "INPUT", requires = "size", conflicts_with = "from_clipboard")]
+        raw: Option<String>,
+
+        /// The dimensions of the raw buffer
+        #[arg(long, value_name = "WxH", value_parser = parse_size, requires = "raw")]
+        size: Option<(usize, usize)>,
+
+        /// The pixel layout of the raw buffer
+        #[arg(long, value_enum, default_value_t = RawFormat::Rgb, requires = "raw")]
+        format: RawFormat,
+
+        /// Copies EXIF and XMP metadata from the source image
+        #[arg(long, conflicts_with_all = ["from_clipboard", "raw"])]
+        keep_metadata: bool,
+
+        /// Composites transparent images over this color instead of dropping the alpha
+        #[arg(long, value_name = "COLOR")]
+        background: Option<RGB>,
+
+        /// Multiplies the colors by the alpha instead of dropping it
+        #[arg(long, conflicts_with = "background")]
+        premultiply: bool,
+
+        /// Keeps the alpha, writing a file with an alpha channel if the image isn't opaque
+        #[arg(long, conflicts_with_all = ["background", "premultiply"])]
+        keep_alpha: bool,
+
+        /// Reports the dimensions and size of the result without writing anything
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Embeds a thumbnail, reusing the EXIF thumbnail of the source if it has one
+        #[arg(long, conflicts_with = "no_thumbnail")]
+        thumbnail: bool,
+
+        /// The format to write, `shf` writes the legacy SHITFILE format
+        #[arg(long, value_enum, default_value_t = Target::Cib)]
+        to: Target,
+
+        /// The number of threads to convert with, defaults to one per core
+        #[arg(short, long, value_parser = clap::value_parser!(u16).range(1..))]
+        jobs: Option<u16>,
+
+        /// Keeps converted files in this directory, so unchanged sources aren't converted again
+        #[arg(long, value_name = "DIR", conflicts_with_all = ["from_clipboard", "raw"])]
+        cache: Option<PathBuf>,
+
+        /// Quantizes the colors to exactly the ones used by this file, so a set of sprites
+        /// can share one palette
+        #[arg(long, value_name = "FILE")]
+        palette: Option<PathBuf>,
+
+        #[command(flatten)]
+        write: WriteArgs,
+    },
+
+    /// Overwrites single pixels of a ciebii file, updating their checksums
+    Set {
+        file_name: String,
+
+        /// A pixel to overwrite and its new color, e.g. --pixel 10,20 '#FF8800'. Can be repeated.
+        #[arg(long, required = true, num_args = 2, value_names = ["X,Y", "COLOR"])]
+        pixel: Vec<String>,
+
+        /// Writes the result here instead of overwriting the file
+        #[arg(short, long, value_name = "OUT")]
+        output: Option<String>,
+    },
+
+    /// Draws rectangles and text onto a ciebii file
+    Draw {
+        file_name: String,
+
+        /// Where to write the annotated file
+        output: String,
+
+        /// A filled rectangle, e.g. --rect 0,0,50x20,#FF0000. Can be repeated.
+        #[arg(long, value_name = "X,Y,WxH,COLOR", value_parser = Annotation::parse_rect)]
+        rect: Vec<Annotation>,
+
+        /// A text label, e.g. --text 2,2,v1.2,#FFFFFF. Can be repeated.
+        #[arg(long, value_name = "X,Y,TEXT,COLOR", value_parser = Annotation::parse_text)]
+        text: Vec<Annotation>,
+
+        /// Scales the 8x8 font up by this factor
+        #[arg(long, default_value_t = 1)]
+        text_scale: usize,
+    },
+
+    /// Cuts a rectangle out of a ciebii file
+    Crop {
+        file_name: String,
+
+        /// The rectangle to keep, e.g. 10,20,64x48
+        #[arg(value_name = "X,Y,WxH", value_parser = Region::parse)]
+        region: Region,
+
+        /// Where to write the cropped file
+        output: String,
+    },
+
+    /// Scales a ciebii file to a new size
+    Resize {
+        file_name: String,
+
+        /// The new size, or the box to fit the image into with --fit
+        #[arg(value_name = "WxH", value_parser = parse_size)]
+        size: (usize, usize),
+
+        /// Where to write the resized file
+        output: String,
+
+        /// How the new pixels are picked
+        #[arg(long, value_enum, default_value_t = ResizeFilter::Nearest)]
+        filter: ResizeFilter,
+
+        /// Keeps the aspect ratio, making the image as large as fits inside WxH
+        #[arg(long)]
+        fit: bool,
+    },
+
+    /// Rotates a ciebii file clockwise
+    Rotate {
+        file_name: String,
+
+        /// The angle in degrees: 90, 180 or 270
+        #[arg(value_name = "DEGREES", value_parser = edit::parse_rotation)]
+        rotation: Rotation,
+
+        /// Where to write the rotated file
+        output: String,
+    },
+
+    /// Mirrors a ciebii file
+    #[command(group = clap::ArgGroup::new("axis").required(true))]
+    Flip {
+        file_name: String,
+
+        /// Where to write the flipped file
+        output: String,
+
+        /// Swaps left and right
+        #[arg(long, group = "axis")]
+        horizontal: bool,
+
+        /// Swaps top and bottom
+        #[arg(long, group = "axis")]
+        vertical: bool,
+    },
+
+    /// Stamps one ciebii file onto another
+    Composite {
+        base: String,
+
+        /// The file to put on top
+        overlay: String,
+
+        /// Where to write the result
+        output: String,
+
+        /// The position of the overlay's top left corner
+        #[arg(long, value_name = "X,Y", value_parser = edit::parse_position, default_value = "0,0")]
+        at: (usize, usize),
+    },
+
+    /// Generates a test pattern
+    Generate {
+        /// Where to write the pattern
+        output: String,
+
+        #[arg(long, value_enum)]
+        pattern: Pattern,
+
+        /// The size of the image
+        #[arg(long, value_name = "WxH", value_parser = parse_size, default_value = "256x256")]
+        size: (usize, usize),
+
+        /// The color of the solid pattern
+        #[arg(long, value_name = "COLOR", default_value = "#FFFFFF")]
+        color: RGB,
+
+        /// The seed of the noise pattern
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+    },
+
+    /// Hides data in the lowest bits of a ciebii file, or reads it back out
+    #[command(subcommand)]
+    Steg(StegCommands),
+
+    /// Takes frames out of animated ciebii files or adds them
+    #[command(subcommand)]
+    Frames(FramesCommands),
+
+    /// Cuts ciebii files into tiles
+    #[command(subcommand)]
+    Tile(TileCommands),
+
+    /// Saves the colors used by an image as a .cibpal palette, for convert --palette
+    Palette {
+        file_name: String,
+
+        /// Where to write the palette
+        output: String,
+    },
+
+    /// Opens a ciebii file in an interactive prompt to inspect and edit it pixel by pixel
+    Repl { file_name: String },
+
+    /// Prints the dimensions, size and header of a ciebii file and whether its checksums pass,
+    /// without opening a window
+    Info { file_name: String },
+
+    /// Prints the header checksum and a digest of a ciebii file, to compare copies of it
+    Checksum {
+        file_name: String,
+
+        /// Also prints a digest of every row along with the offset it starts at
+        #[arg(long)]
+        rows: bool,
+    },
+
+    /// Rewrites a legacy SHITFILE (or an older ciebii file) in the current format,
+    /// keeping the pixels byte for byte
+    #[command(group = clap::ArgGroup::new("target").required(true))]
+    Upgrade {
+        file_name: String,
+
+        /// Where to write the upgraded file
+        #[arg(group = "target")]
+        output: Option<String>,
+
+        /// Replaces the file itself
+        #[arg(long, group = "target")]
+        in_place: bool,
+
+        #[command(flatten)]
+        write: WriteArgs,
+    },
+
+    /// Converts the images in a directory and keeps converting new and modified ones
+    Watch {
+        /// The directory to watch for PNG/JPG/BMP/Netpbm/QOI images
+        src_dir: String,
+
+        /// Where the ciebii files are written
+        out_dir: String,
+    },
+
+    /// Reports whether files are ciebii files, legacy SHITFILEs, regular images or unknown
+    Identify {
+        #[arg(required = true)]
+        file_names: Vec<String>,
+
+        /// Prints the MIME type of each file instead of a description
+        #[arg(long)]
+        mime: bool,
+    },
+
+    /// Streams ciebii files as frames to a viewer opened with `render tcp://HOST:PORT`,
+    /// or to browsers with `--live`
+    Serve {
+        /// The files to send, all of the same size
+        #[arg(required = true)]
+        file_names: Vec<String>,
 
-    /// Converts a PNG/JPG file into a ciebii file
-    Convert { i: String },
+        /// The address to listen on
+        #[arg(long, default_value = "127.0.0.1:7878")]
+        address: String,
+
+        /// Serves a web page showing the file, updated whenever the file changes
+        #[arg(long, conflicts_with_all = ["interval", "repeat"])]
+        live: bool,
+
+        /// Seconds between frames
+        #[arg(long, default_value_t = 1.0, value_parser = parse_interval)]
+        interval: f64,
+
+        /// Starts over after the last file instead of closing the stream
+        #[arg(long = "loop")]
+        repeat: bool,
+    },
+
+    /// Registers .cib files with the desktop so double-clicking one opens the viewer
+    InstallIntegration,
+
+    /// Writes a corpus of valid and invalid ciebii files with a manifest of expected results
+    GenVectors {
+        /// The directory to write into
+        dir: String,
+    },
+
+    /// Decodes a ciebii file into a regular image (PNG, JPG, BMP, TIFF, WebP, PPM, PGM, PAM or QOI)
+    #[command(visible_alias = "export")]
+    Decode {
+        file_name: String,
+
+        /// The image to write, the format follows its extension
+        output: String,
+    },
+
+    /// Prints a ciebii file to the terminal
+    Print {
+        file_name: String,
+
+        /// Draws the image with sixel graphics (xterm, mlterm, foot, ...)
+        #[arg(long, group = "mode")]
+        sixel: bool,
+
+        /// Draws the image with the kitty graphics protocol
+        #[arg(long, group = "mode")]
+        kitty: bool,
+
+        /// Draws the image with iTerm2's inline image protocol
+        #[arg(long, group = "mode")]
+        iterm: bool,
+
+        /// Draws the image with plain characters picked by brightness
+        #[arg(long, group = "mode")]
+        ascii: bool,
+
+        /// The characters used by --ascii, from darkest to brightest
+        #[arg(long, requires = "ascii", default_value = " .:-=+*#%@", value_parser = parse_charset)]
+        charset: String,
+
+        /// Colors the characters of --ascii
+        #[arg(long, requires = "ascii")]
+        color: bool,
+    },
 }
 
-fn main() -> anyhow::Result<()> {
+#[derive(Debug, Subcommand)]
+enum StegCommands {
+    /// Hides a file in a ciebii image
+    Embed {
+        cover: String,
+        secret: String,
+        output: String,
+    },
+
+    /// Reads a hidden file back out of a ciebii image
+    Extract { file_name: String, output: String },
+}
+
+#[derive(Debug, Subcommand)]
+enum TileCommands {
+    /// Writes one ciebii file per tile into a directory, along with a manifest.json
+    Split {
+        file_name: String,
+
+        /// The size of a tile, the ones along the right and bottom edges may be smaller
+        #[arg(long, value_name = "WxH", value_parser = parse_size, default_value = "256x256")]
+        tile: (usize, usize),
+
+        /// The directory to write the tiles into
+        dir: String,
+    },
+
+    /// Puts tiles back together into one ciebii file
+    Join {
+        /// The manifest written by split, or the directory of tiles with --grid
+        source: String,
+
+        /// Where to write the joined file
+        output: String,
+
+        /// Joins COLUMNSxROWS tiles named like split names them, without a manifest
+        #[arg(long, value_name = "CxR", value_parser = parse_size)]
+        grid: Option<(usize, usize)>,
+
+        /// The color of missing tiles
+        #[arg(long, value_name = "COLOR", default_value = "#000000")]
+        fill: RGB,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+enum FramesCommands {
+    /// Writes a single frame of an animation to its own file
+    Extract {
+        file_name: String,
+
+        /// The frame to extract, counting from 0
+        #[arg(long, default_value_t = 0)]
+        frame: usize,
+
+        output: String,
+    },
+
+    /// Adds a ciebii file of the same size as the last frame of an animation
+    Append {
+        file_name: String,
+
+        /// The file holding the new frame
+        frame: String,
+
+        /// How long the new frame is shown, in milliseconds [default: the last frame's]
+        #[arg(long, value_name = "MS")]
+        delay: Option<u32>,
+
+        /// Writes the result here instead of overwriting the animation
+        #[arg(short, long, value_name = "OUT")]
+        output: Option<String>,
+    },
+}
+
+fn main() -> ExitCode {
     let cli = Args::parse();
 
+    // Escape codes have no business inside JSON strings
+    if cli.json_errors {
+        colored::control::set_override(false);
+    }
+
+    if let Err(err) = logging::init(cli.log_level, cli.log_file.as_deref()) {
+        return errors::report(&err, cli.json_errors);
+    }
+
+    match run(&cli) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => errors::report(&err, cli.json_errors),
+    }
+}
+
+fn run(cli: &Args) -> anyhow::Result<()> {
+    let config = Config::load()?;
+
+    // Respect NO_COLOR and friends unless the config says otherwise
+    if let Some(color) = config.color.filter(|_| !cli.json_errors) {
+        colored::control::set_override(color);
+    }
+
     match &cli.command {
-        Commands::Render { file_name } => {
-            render(file_name.to_owned())?;
-        }
-        Commands::Convert { i } => match convert::convert(i) {
-            Ok(_) => {
-                println!(
-                    "✨ {} {}{}",
-                    "Successfully converted".green().bold(),
-                    format!("'{}'", i).white().bold(),
-                    "!".green().bold()
-                );
+        Commands::Render {
+            file_names,
+            slideshow,
+            interval,
+            fullscreen,
+            screenshot,
+            grid_color,
+            background,
+            scale,
+            images,
+            fps,
+            no_vsync,
+            smooth,
+            compare,
+            output,
+        } => {
+            if let Some(output) = output {
+                render::render_to_file(file_names, Path::new(output), scale.or(config.scale))?;
+
+                return Ok(());
             }
-            Err(err) => {
-                println!(
-                    "{} {}{}",
-                    "Failed to convert".red().bold(),
-                    format!("'{}'", i).white().bold(),
-                    ".".red().bold()
-                );
 
-                eprintln!("{err}");
+            let options = RenderOptions {
+                fullscreen: *fullscreen,
+                screenshot: *screenshot,
+                grid_color: grid_color
+                    .or(config.grid_color)
+                    .unwrap_or(RGB::new(0x40, 0x40, 0x40)),
+                background: background
+                    .or(config.background)
+                    .unwrap_or(RGB::new(0x22, 0x22, 0x22)),
+                scale: scale.or(config.scale),
+                images: *images,
+                fps: fps.or(config.fps),
+                vsync: !no_vsync,
+                smooth: *smooth || config.smooth.unwrap_or(false),
+                icon: config.icon.clone(),
+                compare: *compare,
+            };
+
+            match slideshow {
+                Some(dir) => render::slideshow(Path::new(dir), *interval, options)?,
+                None => render(file_names.to_owned(), options)?,
+            }
+        }
+        Commands::Convert {
+            i,
+            out_dir,
+            from_clipboard,
+            raw,
+            size,
+            format,
+            keep_metadata,
+            background,
+            premultiply,
+            keep_alpha,
+            dry_run,
+            thumbnail,
+            to,
+            jobs,
+            cache,
+            palette,
+            write,
+        } => {
+            if let Some(jobs) = jobs.map(usize::from).or(config.jobs) {
+                rayon::ThreadPoolBuilder::new()
+                    .num_threads(jobs)
+                    .build_global()?;
+            }
+
+            let alpha = match (background, premultiply, keep_alpha) {
+                (Some(background), _, _) => Alpha::Background(*background),
+                (None, true, _) => Alpha::Premultiply,
+                (None, false, true) => Alpha::Keep,
+                (None, false, false) => Alpha::Drop,
+            };
+            let palette = palette.as_deref().map(convert::load_palette).transpose()?;
+            let options = ConvertOptions {
+                keep_metadata: *keep_metadata,
+                alpha,
+                dry_run: *dry_run,
+                thumbnail: *thumbnail,
+                target: *to,
+                palette: palette.as_ref(),
+                write: write.options(),
+            };
+
+            match (from_clipboard, raw) {
+                (Some(o), _) => {
+                    let result = convert::convert_clipboard(o, options);
+
+                    // Nothing was converted in a dry run, so there's no success to report
+                    match dry_run {
+                        true => result?,
+                        false => report_conversion("the clipboard image", result)?,
+                    }
+                }
+                (None, Some(raw)) => {
+                    let o = i.as_deref().unwrap();
+                    let result = convert::convert_raw(raw, size.unwrap(), *format, o, options);
 
-                std::fs::remove_file(Path::new(i).file_stem().unwrap().to_str().unwrap())?;
+                    match dry_run {
+                        true => result?,
+                        false => report_conversion(&format!("'{}'", raw), result)?,
+                    }
+                }
+                (None, None) => {
+                    let i = i.as_deref().unwrap();
+                    let cache = match cache.as_ref().or(config.cache_dir.as_ref()) {
+                        Some(dir) => Some(Cache::open(dir)?),
+                        None => None,
+                    };
+
+                    // A batch reports every image on its own
+                    if Path::new(i).is_dir() {
+                        let out = out_dir.as_deref().unwrap_or(Path::new("."));
+                        batch::convert_dir(Path::new(i), out, options, cache.as_ref())?;
+                    } else {
+                        let out_dir = out_dir.as_deref();
+                        let result = convert::convert(i, out_dir, options, cache.as_ref());
+
+                        if *dry_run {
+                            result?;
+                        } else {
+                            report_conversion(&format!("'{}'", i), result)?;
+                        }
+                    }
+                }
             }
+        }
+        Commands::Set {
+            file_name,
+            pixel,
+            output,
+        } => {
+            let patches = pixel
+                .chunks(2)
+                .map(|pair| Patch::parse(&pair[0], &pair[1]))
+                .collect::<anyhow::Result<Vec<_>>>()?;
+
+            edit::set(file_name, &patches, output.as_deref())?;
+        }
+        Commands::Draw {
+            file_name,
+            output,
+            rect,
+            text,
+            text_scale,
+        } => {
+            // Labels go on top of the rectangles
+            let annotations: Vec<Annotation> = rect.iter().chain(text).cloned().collect();
+
+            edit::draw(file_name, &annotations, *text_scale, output)?;
+        }
+        Commands::Crop {
+            file_name,
+            region,
+            output,
+        } => edit::crop(file_name, *region, output)?,
+        Commands::Resize {
+            file_name,
+            size,
+            output,
+            filter,
+            fit,
+        } => edit::resize(file_name, *size, *filter, *fit, output)?,
+        Commands::Composite {
+            base,
+            overlay,
+            output,
+            at,
+        } => edit::composite(base, overlay, *at, output)?,
+        Commands::Rotate {
+            file_name,
+            rotation,
+            output,
+        } => edit::rotate(file_name, *rotation, output)?,
+        Commands::Flip {
+            file_name,
+            output,
+            horizontal,
+            ..
+        } => {
+            let axis = match horizontal {
+                true => Axis::Horizontal,
+                false => Axis::Vertical,
+            };
+
+            edit::flip(file_name, axis, output)?
+        }
+        Commands::Generate {
+            output,
+            pattern,
+            size,
+            color,
+            seed,
+        } => generate::generate(*pattern, *size, *color, *seed, output)?,
+        Commands::Palette { file_name, output } => palette::extract(file_name, output)?,
+        Commands::Repl { file_name } => repl::repl(file_name)?,
+        Commands::Info { file_name } => info::info(file_name)?,
+        Commands::Checksum { file_name, rows } => checksum::checksum(file_name, *rows)?,
+        Commands::Steg(StegCommands::Embed {
+            cover,
+            secret,
+            output,
+        }) => steg::embed(cover, secret, output)?,
+        Commands::Steg(StegCommands::Extract { file_name, output }) => {
+            steg::extract(file_name, output)?
+        }
+        Commands::Frames(FramesCommands::Extract {
+            file_name,
+            frame,
+            output,
+        }) => frames::extract(file_name, *frame, output)?,
+        Commands::Frames(FramesCommands::Append {
+            file_name,
+            frame,
+            delay,
+            output,
+        }) => frames::append(file_name, frame, *delay, output.as_deref())?,
+        Commands::Tile(TileCommands::Split {
+            file_name,
+            tile,
+            dir,
+        }) => tile::split(file_name, *tile, Path::new(dir))?,
+        Commands::Tile(TileCommands::Join {
+            source,
+            output,
+            grid,
+            fill,
+        }) => match grid {
+            Some(grid) => tile::join_grid(Path::new(source), *grid, *fill, output)?,
+            None => tile::join(Path::new(source), *fill, output)?,
+        },
+        Commands::Decode { file_name, output } => decode::decode(file_name, output)?,
+        Commands::Serve {
+            file_names,
+            address,
+            live: true,
+            ..
+        } => match file_names.as_slice() {
+            [file_name] => live::live(address, file_name)?,
+            _ => anyhow::bail!("--live shows a single file"),
         },
+        Commands::Serve {
+            file_names,
+            address,
+            interval,
+            repeat,
+            ..
+        } => serve::serve(address, file_names, *interval, *repeat)?,
+        Commands::InstallIntegration => integration::install()?,
+        Commands::GenVectors { dir } => vectors::generate(Path::new(dir))?,
+        Commands::Upgrade {
+            file_name,
+            output,
+            write,
+            ..
+        } => {
+            let output = Path::new(output.as_deref().unwrap_or(file_name));
+            upgrade::upgrade(file_name, output, &write.options())?
+        }
+        Commands::Watch { src_dir, out_dir } => watch::watch(Path::new(src_dir), Path::new(out_dir))?,
+        Commands::Identify { file_names, mime } => identify::identify(file_names, *mime),
+        Commands::Print {
+            file_name,
+            sixel,
+            kitty,
+            iterm,
+            ascii,
+            charset,
+            color,
+        } => {
+            let mode = if *sixel {
+                PrintMode::Sixel
+            } else if *kitty {
+                PrintMode::Kitty
+            } else if *iterm {
+                PrintMode::ITerm
+            } else if *ascii {
+                PrintMode::Ascii {
+                    charset: charset.chars().collect(),
+                    color: *color,
+                }
+            } else {
+                PrintMode::Ansi
+            };
+
+            print::print(file_name, mode)?;
+        }
     }
 
     Ok(())
 }
+
+/// Prints a successful conversion, failures are passed on to `main` to be reported
+fn report_conversion(source: &str, result: anyhow::Result<()>) -> anyhow::Result<()> {
+    result.with_context(|| {
+        format!(
+            "{} {}{}",
+            "Failed to convert".red().bold(),
+            source.white().bold(),
+            ".".red().bold()
+        )
+    })?;
+
+    println!(
+        "✨ {} {}{}",
+        "Successfully converted".green().bold(),
+        source.white().bold(),
+        "!".green().bold()
+    );
+
+    Ok(())
+}
+
+/// Parses the characters used for ASCII art
+fn parse_charset(s: &str) -> Result<String, String> {
+    match s.is_empty() {
+        true => Err("the charset needs at least one character".to_owned()),
+        false => Ok(s.to_owned()),
+    }
+}
+
+/// Parses a strictly positive number, like a scale factor or frame rate
+fn parse_scale(s: &str) -> Result<f32, String> {
+    match s.parse::<f32>() {
+        Ok(scale) if scale > 0.0 && scale.is_finite() => Ok(scale),
+        _ => Err(format!("'{s}' is not a positive number")),
+    }
+}
+
+/// Parses a positive number of seconds
+fn parse_interval(s: &str) -> Result<f64, String> {
+    match s.parse::<f64>() {
+        Ok(interval) if interval > 0.0 && interval.is_finite() => Ok(interval),
+        _ => Err(format!("'{s}' is not a positive number of seconds")),
+    }
+}
+
+/// Parses a non-empty image size like `256x256`
+fn parse_size(s: &str) -> Result<(usize, usize), String> {
+    let size = s
+        .split_once('x')
+        .and_then(|(width, height)| Some((width.parse().ok()?, height.parse().ok()?)));
+
+    match size {
+        Some((width, height)) if width > 0 && height > 0 => Ok((width, height)),
+        _ => Err(format!("'{s}' is not a valid size, expected WxH")),
+    }
+}
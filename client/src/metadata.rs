@@ -0,0 +1,145 @@
+use std::{
+    fs::{self, File},
+    io::BufReader,
+    path::Path,
+};
+
+use ciebii_lib::{file::CIEBIIFILE, section::Section};
+use colored::*;
+use exif::{In, Tag};
+use image::{DynamicImage, ImageFormat};
+
+/// Identifies an XMP packet in a JPEG APP1 segment
+const JPEG_XMP_HEADER: &[u8] = b"http://ns.adobe.com/xap/1.0/\0";
+
+/// Identifies an XMP packet in a PNG iTXt chunk
+const PNG_XMP_KEYWORD: &[u8] = b"XML:com.adobe.xmp\0";
+
+const PNG_SIGNATURE: &[u8] = &[137, 80, 78, 71, 13, 10, 26, 10];
+
+/// Copies the EXIF and XMP metadata of the JPEG/PNG at `source` into `file`.
+pub fn copy(source: &Path, file: &mut CIEBIIFILE) -> anyhow::Result<()> {
+    for section in read(source)? {
+        file.set_metadata(section.tag(), section.payload().to_vec());
+    }
+
+    Ok(())
+}
+
+/// Reads the EXIF and XMP metadata of the JPEG/PNG at `source` as sections.
+pub fn read(source: &Path) -> anyhow::Result<Vec<Section>> {
+    let bytes = fs::read(source)?;
+
+    let mut sections = Vec::new();
+    let mut kept = Vec::new();
+
+    if let Some(exif) = read_exif(source) {
+        sections.push(Section::new(Section::EXIF, exif));
+        kept.push("EXIF");
+    }
+
+    if let Some(xmp) = read_xmp(&bytes) {
+        sections.push(Section::new(Section::XMP, xmp));
+        kept.push("XMP");
+    }
+
+    match kept.is_empty() {
+        true => println!("🏷️ {}", "no metadata found".yellow().bold()),
+        false => println!("🏷️ {} {}", "keeping".bold(), kept.join(", ").bold()),
+    }
+
+    Ok(sections)
+}
+
+/// Returns the raw EXIF data (a TIFF structure) of an image, if it has any.
+fn read_exif(source: &Path) -> Option<Vec<u8>> {
+    let mut reader = BufReader::new(File::open(source).ok()?);
+
+    exif::Reader::new()
+        .read_from_container(&mut reader)
+        .ok()
+        .map(|exif| exif.buf().to_vec())
+}
+
+/// Returns the thumbnail stored in the EXIF data of an image, if it has a JPEG one.
+pub fn read_thumbnail(source: &Path) -> Option<DynamicImage> {
+    let mut reader = BufReader::new(File::open(source).ok()?);
+    let exif = exif::Reader::new().read_from_container(&mut reader).ok()?;
+
+    // Both are offsets into the TIFF structure of the thumbnail IFD
+    let field = |tag| exif.get_field(tag, In::THUMBNAIL)?.value.get_uint(0);
+    let offset = field(Tag::JPEGInterchangeFormat)? as usize;
+    let len = field(Tag::JPEGInterchangeFormatLength)? as usize;
+
+    let jpeg = exif.buf().get(offset..offset.checked_add(len)?)?;
+
+    image::load_from_memory_with_format(jpeg, ImageFormat::Jpeg).ok()
+}
+
+/// Returns the XMP packet of a JPEG or PNG, if it has one.
+fn read_xmp(bytes: &[u8]) -> Option<Vec<u8>> {
+    if bytes.starts_with(&[0xFF, 0xD8]) {
+        return read_jpeg_xmp(bytes);
+    }
+
+    if bytes.starts_with(PNG_SIGNATURE) {
+        return read_png_xmp(bytes);
+    }
+
+    None
+}
+
+/// Looks for an APP1 segment holding XMP before the image data starts.
+fn read_jpeg_xmp(bytes: &[u8]) -> Option<Vec<u8>> {
+    let mut offset = 2;
+
+    while offset + 4 <= bytes.len() && bytes[offset] == 0xFF {
+        let marker = bytes[offset + 1];
+
+        // Start of scan, the metadata segments are over
+        if marker == 0xDA {
+            break;
+        }
+
+        let len = u16::from_be_bytes([bytes[offset + 2], bytes[offset + 3]]) as usize;
+        let segment = bytes.get(offset + 4..offset + 2 + len)?;
+
+        if marker == 0xE1 && segment.starts_with(JPEG_XMP_HEADER) {
+            return Some(segment[JPEG_XMP_HEADER.len()..].to_vec());
+        }
+
+        offset += 2 + len;
+    }
+
+    None
+}
+
+/// Looks for an uncompressed iTXt chunk holding XMP.
+fn read_png_xmp(bytes: &[u8]) -> Option<Vec<u8>> {
+    let mut offset = PNG_SIGNATURE.len();
+
+    while offset + 8 <= bytes.len() {
+        let len = u32::from_be_bytes(bytes[offset..offset + 4].try_into().ok()?) as usize;
+        let kind = &bytes[offset + 4..offset + 8];
+        let data = bytes.get(offset + 8..offset + 8 + len)?;
+
+        if kind == b"iTXt" && data.starts_with(PNG_XMP_KEYWORD) {
+
+            // Compression flag and method, then a language tag and translated keyword
+            let rest = &data[PNG_XMP_KEYWORD.len()..];
+            if rest.first() != Some(&0) {
+                return None;
+            }
+
+            let mut fields = rest.get(2..)?.splitn(3, |byte| *byte == 0);
+            let (_, _, text) = (fields.next()?, fields.next()?, fields.next()?);
+
+            return Some(text.to_vec());
+        }
+
+        // Length, type, data and CRC
+        offset += 12 + len;
+    }
+
+    None
+}
@@ -0,0 +1,23 @@
+use std::path::Path;
+
+use ciebii_lib::{io::write_palette, palette::Palette};
+use colored::*;
+
+use crate::gallery;
+
+/// Saves the colors used by the image at `file_name` to `output` as a `.cibpal` palette,
+/// in the order they first appear.
+pub fn extract(file_name: &str, output: &str) -> anyhow::Result<()> {
+    let palette = Palette::from_file(&gallery::load(Path::new(file_name))?);
+
+    write_palette(Path::new(output), &palette)?;
+
+    println!(
+        "🎨 {} {} {}",
+        "Saved".green().bold(),
+        format!("{} colors", palette.len()).white().bold(),
+        format!("to '{output}'").bold()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,129 @@
+use ciebii_lib::file::CIEBIIFILE;
+use macroquad::{
+    color::{Color, WHITE},
+    input::{is_key_pressed, KeyCode},
+    shapes::draw_rectangle,
+    text::{draw_text, measure_text},
+    time::get_time,
+    window::screen_height,
+};
+
+/// Delay used for frames that don't specify one, in seconds. Matches what browsers do for GIFs.
+const DEFAULT_DELAY: f64 = 0.1;
+
+const FONT_SIZE: f32 = 18.0;
+const PADDING: f32 = 6.0;
+
+/// Plays back the frames of an animated file at their recorded delays.
+/// Space pauses, the arrow keys step while paused and L toggles looping.
+#[derive(Debug)]
+pub struct Player {
+    delays: Vec<f64>,
+    current: usize,
+    paused: bool,
+    looping: bool,
+    shown_at: f64,
+}
+
+impl Player {
+    pub fn new(file: &CIEBIIFILE) -> Self {
+        let delays = (0..file.frame_count())
+            .map(|i| match file.frame_delay(i).unwrap_or(0) {
+                0 => DEFAULT_DELAY,
+                delay => delay as f64 / 1000.0,
+            })
+            .collect();
+
+        Self {
+            delays,
+            current: 0,
+            paused: false,
+            looping: true,
+            shown_at: get_time(),
+        }
+    }
+
+    /// Returns the index of the frame that should be displayed
+    pub fn current(&self) -> usize {
+        self.current
+    }
+
+    /// Returns whether the arrow keys are used for stepping through frames
+    pub fn is_stepping(&self) -> bool {
+        self.is_animated() && self.paused
+    }
+
+    fn is_animated(&self) -> bool {
+        self.delays.len() > 1
+    }
+
+    /// Handles the playback shortcuts.
+    pub fn handle_input(&mut self) {
+        if !self.is_animated() {
+            return;
+        }
+
+        if is_key_pressed(KeyCode::Space) {
+            self.paused = !self.paused;
+            self.shown_at = get_time();
+        }
+
+        if is_key_pressed(KeyCode::L) {
+            self.looping = !self.looping;
+        }
+
+        if self.paused {
+            let frames = self.delays.len();
+
+            if is_key_pressed(KeyCode::Right) {
+                self.current = (self.current + 1) % frames;
+            } else if is_key_pressed(KeyCode::Left) {
+                self.current = (self.current + frames - 1) % frames;
+            }
+        }
+    }
+
+    /// Advances to the next frame once the current one has been shown long enough.
+    pub fn update(&mut self) {
+        if !self.is_animated() || self.paused {
+            return;
+        }
+
+        // Catch up on every frame whose delay has passed, so slow frames don't slow down playback
+        while get_time() - self.shown_at >= self.delays[self.current] {
+            if self.current + 1 == self.delays.len() && !self.looping {
+                return;
+            }
+
+            self.shown_at += self.delays[self.current];
+            self.current = (self.current + 1) % self.delays.len();
+        }
+    }
+
+    /// Draws the frame counter in the bottom left corner.
+    pub fn draw_counter(&self) {
+        if !self.is_animated() {
+            return;
+        }
+
+        let mut text = format!("frame {}/{}", self.current + 1, self.delays.len());
+        if self.paused {
+            text.push_str(" (paused)");
+        }
+        if !self.looping {
+            text.push_str(" (no loop)");
+        }
+
+        let size = measure_text(&text, None, FONT_SIZE as u16, 1.0);
+        let y = screen_height() - size.height - PADDING * 3.0;
+
+        draw_rectangle(
+            PADDING,
+            y,
+            size.width + PADDING * 2.0,
+            size.height + PADDING * 2.0,
+            Color::new(0.0, 0.0, 0.0, 0.75),
+        );
+        draw_text(&text, PADDING * 2.0, y + PADDING + size.offset_y, FONT_SIZE, WHITE);
+    }
+}
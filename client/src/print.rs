@@ -0,0 +1,272 @@
+use std::{
+    fmt::Write as _,
+    io::{self, Write},
+    path::Path,
+};
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use ciebii_lib::io::read_file;
+use color_quant::NeuQuant;
+use image::{imageops::FilterType, ImageOutputFormat, RgbImage};
+use terminal_size::{terminal_size, Height, Width};
+
+use crate::decode;
+
+/// The terminal size assumed when stdout isn't a terminal
+const FALLBACK_SIZE: (u16, u16) = (80, 24);
+
+/// The size of a character cell in pixels assumed for graphics protocols.
+/// Most terminal fonts are at least this large.
+const CELL_SIZE: (u32, u32) = (8, 16);
+
+/// The amount of colors registers used for sixel output
+const SIXEL_COLORS: usize = 256;
+
+/// The largest payload kitty accepts per escape sequence
+const KITTY_CHUNK: usize = 4096;
+
+/// How an image is drawn in the terminal
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PrintMode {
+    /// Upper half blocks colored with 24-bit ANSI escapes
+    Ansi,
+
+    /// DEC sixel graphics
+    Sixel,
+
+    /// The kitty terminal graphics protocol
+    Kitty,
+
+    /// iTerm2's inline image protocol
+    ITerm,
+
+    /// Characters picked by luminance from `charset`, darkest first, optionally colored
+    Ascii { charset: Vec<char>, color: bool },
+}
+
+/// Prints a ciebii file to the terminal.
+pub fn print(file_name: &str, mode: PrintMode) -> anyhow::Result<()> {
+    let file = read_file(Path::new(file_name))?;
+    let image = decode::to_image(&file, 0);
+
+    // Leave a line for the prompt
+    let (columns, rows) = terminal_cells();
+    let (columns, rows) = (columns as u32, (rows as u32).saturating_sub(1));
+
+    let out = match mode {
+
+        // Each cell holds two rows of pixels
+        PrintMode::Ansi => half_blocks(&fit(image, columns, rows * 2)),
+        PrintMode::Sixel => sixel(&fit(image, columns * CELL_SIZE.0, rows * CELL_SIZE.1)),
+        PrintMode::Kitty => kitty(&fit(image, columns * CELL_SIZE.0, rows * CELL_SIZE.1))?,
+        PrintMode::ITerm => iterm(&fit(image, columns * CELL_SIZE.0, rows * CELL_SIZE.1))?,
+        PrintMode::Ascii { charset, color } => {
+
+            // Character cells are about twice as tall as they are wide
+            let image = fit(image, columns, rows * 2);
+            let height = (image.height() / 2).max(1);
+            let image = image::imageops::resize(&image, image.width(), height, FilterType::Triangle);
+
+            ascii(&image, &charset, color)
+        }
+    };
+
+    io::stdout().write_all(out.as_bytes())?;
+
+    Ok(())
+}
+
+/// Returns the size of the terminal in character cells
+pub fn terminal_cells() -> (u16, u16) {
+    match terminal_size() {
+        Some((Width(columns), Height(rows))) => (columns, rows),
+        None => FALLBACK_SIZE,
+    }
+}
+
+/// Downscales `image` to fit in `width` by `height` pixels, keeping its aspect ratio.
+/// Images that already fit are returned untouched.
+pub fn fit(image: RgbImage, width: u32, height: u32) -> RgbImage {
+    let (width, height) = (width.max(1), height.max(1));
+
+    if image.width() <= width && image.height() <= height {
+        return image;
+    }
+
+    let scale = (width as f64 / image.width() as f64).min(height as f64 / image.height() as f64);
+    let new_width = ((image.width() as f64 * scale) as u32).max(1);
+    let new_height = ((image.height() as f64 * scale) as u32).max(1);
+
+    image::imageops::resize(&image, new_width, new_height, FilterType::Triangle)
+}
+
+/// Encodes `image` as lines of upper half blocks, the foreground color being the top
+/// pixel and the background color the bottom one.
+fn half_blocks(image: &RgbImage) -> String {
+    let mut out = String::new();
+
+    for y in (0..image.height()).step_by(2) {
+        for x in 0..image.width() {
+            let [r, g, b] = image.get_pixel(x, y).0;
+            let _ = write!(out, "\x1b[38;2;{r};{g};{b}m");
+
+            // The last row of an odd height image has nothing underneath it
+            if y + 1 < image.height() {
+                let [r, g, b] = image.get_pixel(x, y + 1).0;
+                let _ = write!(out, "\x1b[48;2;{r};{g};{b}m");
+            } else {
+                out.push_str("\x1b[49m");
+            }
+
+            out.push('▀');
+        }
+
+        out.push_str("\x1b[0m\n");
+    }
+
+    out
+}
+
+/// Encodes `image` as DEC sixel graphics, quantized to a 256 color palette.
+fn sixel(image: &RgbImage) -> String {
+    let rgba: Vec<u8> = image
+        .pixels()
+        .flat_map(|pixel| [pixel[0], pixel[1], pixel[2], 255])
+        .collect();
+    let quantizer = NeuQuant::new(10, SIXEL_COLORS, &rgba);
+
+    let indices: Vec<usize> = rgba.chunks(4).map(|pixel| quantizer.index_of(pixel)).collect();
+    let (width, height) = (image.width() as usize, image.height() as usize);
+
+    // Enter sixel mode, then declare the size and the palette with components in percent
+    let mut out = format!("\x1bP0;1;0q\"1;1;{width};{height}");
+    for (i, color) in quantizer.color_map_rgb().chunks(3).enumerate() {
+        let [r, g, b] = [color[0], color[1], color[2]].map(|c| c as u32 * 100 / 255);
+        let _ = write!(out, "#{i};2;{r};{g};{b}");
+    }
+
+    // Every band covers six rows, drawn one color at a time
+    for band in (0..height).step_by(6) {
+        let rows = band..(band + 6).min(height);
+
+        let mut colors: Vec<usize> = rows
+            .clone()
+            .flat_map(|y| indices[y * width..(y + 1) * width].iter().copied())
+            .collect();
+        colors.sort_unstable();
+        colors.dedup();
+
+        for (n, color) in colors.iter().enumerate() {
+
+            // Go back to the start of the band to overlay the next color
+            if n > 0 {
+                out.push('$');
+            }
+            let _ = write!(out, "#{color}");
+
+            let sixels = (0..width).map(|x| {
+                let bits = rows
+                    .clone()
+                    .enumerate()
+                    .filter(|(_, y)| indices[y * width + x] == *color)
+                    .fold(0, |bits, (bit, _)| bits | 1 << bit);
+
+                (63 + bits) as u8 as char
+            });
+
+            push_run_length_encoded(&mut out, sixels);
+        }
+
+        out.push('-');
+    }
+
+    out.push_str("\x1b\\");
+    out
+}
+
+/// Pushes sixel characters, collapsing repeats into `!<count><char>`
+fn push_run_length_encoded(out: &mut String, sixels: impl Iterator<Item = char>) {
+    let mut sixels = sixels.peekable();
+
+    while let Some(sixel) = sixels.next() {
+        let mut count = 1;
+        while sixels.peek() == Some(&sixel) {
+            sixels.next();
+            count += 1;
+        }
+
+        match count {
+            1..=3 => out.extend(std::iter::repeat_n(sixel, count)),
+            _ => {
+                let _ = write!(out, "!{count}{sixel}");
+            }
+        }
+    }
+}
+
+/// Encodes `image` as a PNG transmitted with the kitty graphics protocol.
+fn kitty(image: &RgbImage) -> anyhow::Result<String> {
+    let data = STANDARD.encode(png(image)?);
+    let chunks: Vec<&[u8]> = data.as_bytes().chunks(KITTY_CHUNK).collect();
+
+    // The first chunk carries the control data, m=1 marks that more chunks follow
+    let mut out = String::new();
+    for (i, chunk) in chunks.iter().enumerate() {
+        let more = (i + 1 < chunks.len()) as u8;
+        let control = match i {
+            0 => format!("a=T,f=100,m={more}"),
+            _ => format!("m={more}"),
+        };
+
+        let _ = write!(out, "\x1b_G{control};{}\x1b\\", String::from_utf8_lossy(chunk));
+    }
+
+    out.push('\n');
+    Ok(out)
+}
+
+/// Encodes `image` as a PNG shown inline with iTerm2's proprietary escape sequence.
+fn iterm(image: &RgbImage) -> anyhow::Result<String> {
+    let png = png(image)?;
+
+    Ok(format!(
+        "\x1b]1337;File=inline=1;size={};preserveAspectRatio=1:{}\x07\n",
+        png.len(),
+        STANDARD.encode(&png)
+    ))
+}
+
+/// Encodes `image` as PNG in memory
+fn png(image: &RgbImage) -> anyhow::Result<Vec<u8>> {
+    let mut png = io::Cursor::new(Vec::new());
+    image.write_to(&mut png, ImageOutputFormat::Png)?;
+
+    Ok(png.into_inner())
+}
+
+/// Maps every pixel of `image` to a character of `charset` by its luminance.
+fn ascii(image: &RgbImage, charset: &[char], color: bool) -> String {
+    let mut out = String::new();
+
+    for row in image.rows() {
+        for pixel in row {
+            let [r, g, b] = pixel.0;
+
+            // Rec. 709 luma
+            let luminance = 0.2126 * r as f32 + 0.7152 * g as f32 + 0.0722 * b as f32;
+            let index = (luminance / 255.0 * (charset.len() - 1) as f32).round() as usize;
+
+            if color {
+                let _ = write!(out, "\x1b[38;2;{r};{g};{b}m");
+            }
+            out.push(charset[index.min(charset.len() - 1)]);
+        }
+
+        if color {
+            out.push_str("\x1b[0m");
+        }
+        out.push('\n');
+    }
+
+    out
+}
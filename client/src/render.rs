@@ -1,71 +1,900 @@
 
-use std::{path::Path, thread, time::Duration};
+use std::{
+    net::TcpStream,
+    path::{Path, PathBuf},
+    sync::mpsc::{self, Receiver},
+    thread,
+    time::{Duration, Instant},
+};
 
 use macroquad::{
-    shapes::draw_rectangle,
-    window::{next_frame, Conf}, miniquad::conf::Icon,
+    color::Color,
+    input::{is_key_down, is_key_pressed, is_quit_requested, prevent_quit, KeyCode},
+    math::{vec2, Rect},
+    texture::{draw_texture_ex, DrawTextureParams, FilterMode, Texture2D},
+    time::get_time,
+    window::{clear_background, get_internal_gl, next_frame, screen_height, screen_width, Conf},
+    miniquad::conf::{Icon, Platform},
+};
+use anyhow::Context;
+use ciebii_lib::{
+    chunk::Chunk, file::CIEBIIFILE, histogram::Histogram, net::FrameReceiver, rgb::RGB,
 };
-use ciebii_lib::io::read_file;
 
+use arboard::Clipboard;
 use colored::*;
 
-use crate::icons;
+use crate::{
+    adjust::Adjustment,
+    backdrop::Backdrop,
+    clipboard,
+    compare::Compare,
+    config::WindowState,
+    decode,
+    gallery::{self, Gallery},
+    grid::Grid,
+    help::Help,
+    histogram::Histograms,
+    icons,
+    info::{Details, Info},
+    inspector::Inspector,
+    player::Player,
+    strip::Strip,
+    tabs,
+    view::View,
+    vision::Vision,
+};
+
+/// The largest window the viewer opens by default.
+/// miniquad can't query the monitor before the window exists, so this is a
+/// conservative size that fits on practically every display.
+const MAX_WINDOW: (f32, f32) = (1280.0, 800.0);
+
+/// How often a frame that is still coming in is redrawn
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Options shared by every render mode
+#[derive(Debug, Clone)]
+pub struct RenderOptions {
+    /// Start in fullscreen mode
+    pub fullscreen: bool,
+
+    /// Save the image as a PNG as soon as it is opened
+    pub screenshot: bool,
+
+    /// The color of the pixel grid overlay
+    pub grid_color: RGB,
+
+    /// The window clear color, which also fills the letterbox bars
+    pub background: RGB,
+
+    /// A fixed scale factor. When `None` the largest integer scale that fits on screen is used.
+    pub scale: Option<f32>,
+
+    /// Also browse PNG/JPG/BMP files next to the ciebii files
+    pub images: bool,
+
+    /// Caps the frame rate, `None` leaves it to vsync
+    pub fps: Option<f32>,
+
+    /// Waits for the display's refresh before showing a frame
+    pub vsync: bool,
+
+    /// Scales the image with linear filtering instead of nearest-neighbor
+    pub smooth: bool,
+
+    /// A ciebii file to use as the window icon instead of the bundled one
+    pub icon: Option<PathBuf>,
 
-pub fn render(file_name: String) -> anyhow::Result<()> {
-    let shf = read_file(Path::new(&file_name));
-    let shf = match shf {
+    /// Opens two files of the same size for comparison, with a difference view on D
+    pub compare: bool,
+}
+
+/// Opens the viewer on the given files. A single file can be browsed alongside the
+/// rest of its directory, several files are opened as tabs.
+pub fn render(file_names: Vec<String>, options: RenderOptions) -> anyhow::Result<()> {
+    if let [address] = &file_names[..] {
+        if let Some(address) = address.strip_prefix("tcp://") {
+            return stream(address, options);
+        }
+    }
+
+    let paths: Vec<PathBuf> = file_names.iter().map(PathBuf::from).collect();
+
+    if let [dir] = &paths[..] {
+        if dir.is_dir() {
+            return browse(dir, options);
+        }
+    }
+
+    let shf = match gallery::load(&paths[0]) {
         Ok(shf) => shf,
         Err(err) => {
             println!("{}", "An unexpected error has occured!".red().bold());
 
-            panic!("{}", err);
+            return Err(err);
         }
     };
 
-    let (width, height) = shf.dimensions();
+    if options.screenshot {
+        save_screenshot(&shf, 0, &paths[0]);
 
-    macroquad::Window::from_config(
-        Conf {
-            window_title: "ciebii file viewer".to_owned(),
-            window_width: width as i32,
-            window_height: height as i32,
-            icon: Some(Icon {
-                small: icons::SMALL_ICON,
-                medium: icons::MEDIUM_ICON,
-                big: icons::LARGE_ICON,
-            }),
-            ..Default::default()
-        },
-        async move {
-            let px_width = 1.0;
-            let px_height = 1.0;
+        for path in &paths[1..] {
+            match gallery::load(path) {
+                Ok(file) => save_screenshot(&file, 0, path),
+                Err(err) => eprintln!("{} {}", "Failed to save screenshot:".red().bold(), err),
+            }
+        }
+    }
 
-            let mut x = 0.0;
-            let mut y = 0.0;
+    texture_size(shf.dimensions())
+        .with_context(|| format!("Can't show '{}'", paths[0].display()))?;
 
-            shf.chunks().iter().for_each(|chunk| {
-                let color = chunk.rgb().color();
-                let color = macroquad::color::Color::from_rgba(color.0, color.1, color.2, 255);
+    // Both files are opened as tabs to flip between, the difference is worked out once
+    let compare = match &paths[..] {
+        [_, other] if options.compare => Some(Compare::new(&shf, &gallery::load(other)?)?),
+        _ if options.compare => anyhow::bail!("--compare takes two files, got {}", paths.len()),
+        _ => None,
+    };
 
-                // ctx.dr
+    // The other files in the directory can be browsed with the arrow keys
+    let gallery = match paths.len() {
+        1 => Gallery::around(&paths[0], options.images)?,
+        _ => Gallery::tabs(paths),
+    };
+
+    run("ciebii file viewer", gallery, shf, None, None, compare, options);
+
+    Ok(())
+}
+
+/// Shows the frames sent by a `FrameSender` listening on `address` as they arrive.
+/// Frames are drawn row by row while they come in, on top of the previous frame.
+fn stream(address: &str, options: RenderOptions) -> anyhow::Result<()> {
+    let connection = TcpStream::connect(address)
+        .with_context(|| format!("Failed to connect to '{address}'"))?;
+    let mut receiver = FrameReceiver::new(connection)?;
+    let (width, height) = receiver.dimensions();
+    texture_size((width, height)).context("Can't show the stream")?;
+
+    let (frames, received) = mpsc::channel();
+    thread::spawn(move || {
+        // Only grows as the rows of the first frame arrive, the header isn't trusted with more
+        let mut chunks = Vec::new();
+        let mut rows = 0;
+        let mut shown_at: Option<Instant> = None;
 
-                draw_rectangle(x, y, px_width, px_height, color);
+        loop {
+            // Showing a partial frame means copying it, so that's done a few times a second at most
+            let next = receiver.next_frame_by_rows(|index, row| {
+                let start = index * width;
+                match chunks.len() > start {
+                    true => chunks[start..start + width].copy_from_slice(row),
+                    false => chunks.extend_from_slice(row),
+                }
+                rows = rows.max(index + 1);
 
-                x += px_width;
+                if shown_at.is_none_or(|shown_at| shown_at.elapsed() >= PROGRESS_INTERVAL) {
+                    shown_at = Some(Instant::now());
 
-                if x >= width as f32 {
-                    x = 0.0;
-                    y += px_height;
+                    // The first frame is as tall as the rows that are in, later ones are drawn
+                    // over the previous frame
+                    let partial = chunks.clone();
+                    if let Ok(partial) = CIEBIIFILE::try_from_chunks(width, rows, partial) {
+                        let _ = frames.send(partial);
+                    }
                 }
             });
 
-            next_frame().await;
+            match next {
+                Ok(Some(file)) => {
+                    shown_at = Some(Instant::now());
+
+                    if frames.send(file).is_err() {
+                        break;
+                    }
+                }
+                Ok(None) => {
+                    println!("📡 {}", "The stream has ended".bold());
+                    break;
+                }
+                Err(err) => {
+                    eprintln!("{} {err:#}", "The stream broke off:".red().bold());
+                    break;
+                }
+            }
+        }
+    });
+
+    // The window opens once the first rows are in
+    let file = received
+        .recv()
+        .context("The stream ended before any rows arrived")?;
+
+    let gallery = Gallery::single(PathBuf::from(format!("tcp://{address}")));
+    run("ciebii stream", gallery, file, None, Some(received), None, options);
+
+    Ok(())
+}
+
+/// Renders a file straight to an image file without opening a window.
+pub fn render_to_file(file_names: &[String], output: &Path, scale: Option<f32>) -> anyhow::Result<()> {
+    if file_names.len() > 1 {
+        anyhow::bail!("--output takes a single file, got {}", file_names.len());
+    }
+
+    let shf = gallery::load(Path::new(&file_names[0]))?;
+    decode::render_to_file(&shf, output, scale)?;
+
+    println!(
+        "💾 {} {}",
+        "Saved".green().bold(),
+        format!("'{}'", output.display()).white().bold()
+    );
+
+    Ok(())
+}
+
+/// Opens the viewer on the files in `dir`, with a strip of their thumbnails along the bottom
+/// to pick one by clicking it.
+fn browse(dir: &Path, options: RenderOptions) -> anyhow::Result<()> {
+    let mut gallery = Gallery::browse(dir, options.images)?;
+
+    let file = gallery.load_current().ok_or_else(|| {
+        anyhow::anyhow!("None of the files in '{}' could be loaded", dir.display())
+    })?;
+
+    run("ciebii file viewer", gallery, file, None, None, None, options);
+
+    Ok(())
+}
+
+/// Cycles through every `.cib` file in `dir`, advancing every `interval` seconds.
+/// The left and right arrow keys step backwards and forwards manually.
+pub fn slideshow(dir: &Path, interval: f64, options: RenderOptions) -> anyhow::Result<()> {
+    let mut gallery = Gallery::in_dir(dir, options.images)?;
+
+    let file = gallery
+        .load_current()
+        .ok_or_else(|| {
+            anyhow::anyhow!("None of the files in '{}' could be loaded", dir.display())
+        })?;
+
+    run("ciebii slideshow", gallery, file, Some(interval), None, None, options);
+
+    Ok(())
+}
+
+/// Opens the viewer window showing `file`, the current file of `gallery`.
+/// With an `interval` the viewer moves on to the next file every `interval` seconds.
+/// Files arriving on `frames` replace the one on screen, keeping the view.
+/// Returns once the window is closed or Esc/Q is pressed.
+fn run(
+    title: &str,
+    mut gallery: Gallery,
+    file: CIEBIIFILE,
+    interval: Option<f64>,
+    frames: Option<Receiver<CIEBIIFILE>>,
+    compare: Option<Compare>,
+    options: RenderOptions,
+) {
+    macroquad::Window::from_config(
+        window_conf(title, file.dimensions(), &options),
+        async move {
+            let mut viewer = Viewer::new(&options);
+            viewer.compare = compare;
+            let mut slide = Slide::new(file, gallery.path(), viewer.filters());
+            let mut shown_at = get_time();
+            let mut pacer = Pacer::new(options.fps);
+            let mut window = None;
+            let mut strip = gallery
+                .is_browsing()
+                .then(|| Strip::new(gallery.paths().to_vec()));
+
+            // Closing the window ends the loop like Esc does, so the window size still gets saved
+            prevent_quit();
+
             loop {
-                thread::sleep(Duration::from_secs(5));
+                if viewer.quit_requested() {
+                    break;
+                }
+
+                // A fullscreen window says nothing about the size to reopen at
+                if !viewer.fullscreen {
+                    window = Some(WindowState {
+                        width: screen_width(),
+                        height: screen_height(),
+                    });
+                }
+
+                if viewer.handle_input() {
+                    slide.retexture(viewer.filters());
+                }
+                slide.player.handle_input();
+                slide.player.update();
+
+                // Dragging the sensitivity slider shouldn't pan the image along with it, and
+                // scrolling the thumbnails shouldn't zoom it
+                let on_strip = strip.is_some() && Strip::contains_mouse();
+                if !viewer.compare.as_ref().is_some_and(Compare::is_dragging) && !on_strip {
+                    slide.view.handle_input(fit_area(slide.textures[0]));
+                }
+
+                // Manual navigation restarts the timer so the slide gets its full interval.
+                // The arrow keys step through frames instead while a paused animation is shown.
+                let stepping = slide.player.is_stepping();
+                let step = if is_key_pressed(KeyCode::Right) && !stepping {
+                    Some(true)
+                } else if is_key_pressed(KeyCode::Left) && !stepping {
+                    Some(false)
+                } else if interval.is_some_and(|interval| get_time() - shown_at >= interval) {
+                    Some(true)
+                } else {
+                    None
+                };
+
+                if let Some(forward) = step {
+                    if let Some(file) = gallery.advance(forward) {
+                        slide = Slide::new(file, gallery.path(), viewer.filters());
+                    }
+                    shown_at = get_time();
+                }
+
+                // Only the newest frame is worth showing when several arrived at once
+                if let Some(file) = frames.as_ref().and_then(|frames| frames.try_iter().last()) {
+                    slide.show(file, gallery.path(), viewer.filters());
+                }
+
+                // Number keys jump straight to a tab
+                if gallery.is_tabbed() {
+                    if let Some(file) = tabs::selected().and_then(|index| gallery.select(index)) {
+                        slide = Slide::new(file, gallery.path(), viewer.filters());
+                    }
+                }
+
+                // Clicking a thumbnail opens its file
+                let clicked = strip.as_mut().and_then(|strip| strip.handle_input(gallery.index()));
+                if let Some(file) = clicked.and_then(|index| gallery.select(index)) {
+                    slide = Slide::new(file, gallery.path(), viewer.filters());
+                }
+
+                let frame = slide.player.current();
+
+                if viewer.histograms.is_open() {
+                    slide.update_histogram(frame);
+                }
+
+                if is_key_pressed(KeyCode::S) {
+                    save_screenshot(&slide.file, frame, gallery.path());
+                }
+
+                if is_copy_pressed() {
+                    viewer.copy(&slide.file, frame);
+                }
+
+                viewer.draw(&slide, frame);
+                slide.player.draw_counter();
+                if gallery.is_tabbed() {
+                    tabs::draw(&gallery.names(), gallery.index());
+                }
+                if let Some(strip) = &strip {
+                    strip.draw(gallery.index());
+                }
+                viewer.draw_help();
+
+                pacer.wait();
+                next_frame().await;
+            }
+
+            if let Some(Err(err)) = window.map(|window| window.save()) {
+                eprintln!("{} {err}", "Failed to save the window size:".red().bold());
             }
         },
     );
+}
 
-    Ok(())
+/// Caps the frame rate by sleeping off whatever is left of each frame.
+struct Pacer {
+    /// The shortest time a frame may take, in seconds
+    frame: Option<f64>,
+    last: f64,
+}
+
+impl Pacer {
+    fn new(fps: Option<f32>) -> Self {
+        Self {
+            frame: fps.map(|fps| 1.0 / fps as f64),
+            last: get_time(),
+        }
+    }
+
+    /// Blocks until the current frame has taken up its share of a second.
+    fn wait(&mut self) {
+        if let Some(frame) = self.frame {
+            let left = frame - (get_time() - self.last);
+
+            if left > 0.0 {
+                thread::sleep(Duration::from_secs_f64(left));
+            }
+        }
+
+        self.last = get_time();
+    }
+}
+
+/// The file currently on screen along with its textures and playback state.
+struct Slide {
+    file: CIEBIIFILE,
+    details: Details,
+    textures: Vec<Texture2D>,
+    player: Player,
+    view: View,
+
+    // The histogram of a frame, only worked out while the panel is open
+    histogram: Option<(usize, Histogram)>,
+}
+
+impl Slide {
+    fn new(file: CIEBIIFILE, path: &Path, filters: Filters) -> Self {
+        Self {
+            details: Details::new(path, &file),
+            textures: to_textures(&file, filters),
+            player: Player::new(&file),
+            view: View::default(),
+            histogram: None,
+            file,
+        }
+    }
+
+    /// Swaps in another file, like the next frame of a stream, keeping the zoom and pan.
+    fn show(&mut self, file: CIEBIIFILE, path: &Path, filters: Filters) {
+        self.details = Details::new(path, &file);
+        self.player = Player::new(&file);
+        self.histogram = None;
+        let previous = std::mem::replace(&mut self.file, file);
+
+        // Streams tend to change a few rows at a time, re-uploading all of an 8K image for
+        // that would stall every frame
+        if !self.update_dirty(&previous, filters) {
+            self.retexture(filters);
+        }
+    }
+
+    /// Uploads only the part of each frame that differs from `previous`, leaving the rest of
+    /// the textures alone. Returns `false` without uploading anything if the frames don't
+    /// line up, like after a change in size.
+    fn update_dirty(&self, previous: &CIEBIIFILE, filters: Filters) -> bool {
+        let (width, height) = self.file.dimensions();
+        let frames = self.file.frame_count();
+
+        if previous.dimensions() != (width, height)
+            || previous.frame_count() != frames
+            || self.textures.len() != frames
+        {
+            return false;
+        }
+
+        let _span = tracing::debug_span!("render_dirty", width, height, frames).entered();
+        let lut = filters.adjustment.lut();
+        let gl = unsafe { get_internal_gl() };
+
+        for (i, texture) in self.textures.iter().enumerate() {
+            let (Some(old), Some(new)) = (previous.frame(i), self.file.frame(i)) else {
+                continue;
+            };
+
+            let Some(dirty) = dirty_rect(old, new, width) else {
+                continue;
+            };
+
+            let bytes: Vec<u8> = (dirty.y..dirty.y + dirty.height)
+                .flat_map(|y| {
+                    let start = y * width + dirty.x;
+                    to_rgba(&new[start..start + dirty.width], filters, &lut)
+                })
+                .collect();
+
+            texture.raw_miniquad_texture_handle().update_texture_part(
+                gl.quad_context,
+                dirty.x as i32,
+                dirty.y as i32,
+                dirty.width as i32,
+                dirty.height as i32,
+                &bytes,
+            );
+        }
+
+        true
+    }
+
+    /// Works out the histogram of `frame` unless it's known already.
+    fn update_histogram(&mut self, frame: usize) {
+        if self.histogram.as_ref().is_some_and(|(index, _)| *index == frame) {
+            return;
+        }
+
+        if let Some(chunks) = self.file.frame(frame) {
+            self.histogram = Some((frame, Histogram::new(chunks)));
+        }
+    }
+
+    /// Uploads the frames again with different filters.
+    fn retexture(&mut self, filters: Filters) {
+        delete_textures(&self.textures);
+        self.textures = to_textures(&self.file, filters);
+    }
+}
+
+impl Drop for Slide {
+    fn drop(&mut self) {
+        delete_textures(&self.textures);
+    }
+}
+
+/// The view-only filters applied to the pixels before they are uploaded.
+#[derive(Debug, Clone, Copy)]
+struct Filters {
+    adjustment: Adjustment,
+    vision: Vision,
+
+    // Linear filtering when scaling, nearest-neighbor otherwise
+    smooth: bool,
+}
+
+/// Frees the GPU memory of the given textures.
+/// Textures are plain handles, so this has to be done by hand.
+fn delete_textures(textures: &[Texture2D]) {
+    textures.iter().for_each(|texture| texture.delete());
+}
+
+/// The state shared by every render mode: display settings and overlays.
+struct Viewer {
+    fullscreen: bool,
+    inspector: Inspector,
+    grid: Grid,
+    backdrop: Backdrop,
+    help: Help,
+    info: Info,
+    histograms: Histograms,
+    background: Color,
+    adjustment: Adjustment,
+    vision: Vision,
+    smooth: bool,
+
+    // Only set when two files are compared
+    compare: Option<Compare>,
+
+    // Kept alive for the whole session, on X11 copied data disappears along with it
+    clipboard: Option<Clipboard>,
+}
+
+impl Viewer {
+    fn new(options: &RenderOptions) -> Self {
+        Self {
+            fullscreen: options.fullscreen,
+            inspector: Inspector::default(),
+            grid: Grid::new(options.grid_color),
+            backdrop: Backdrop::default(),
+            help: Help::default(),
+            info: Info::default(),
+            histograms: Histograms::default(),
+            adjustment: Adjustment::default(),
+            vision: Vision::default(),
+            smooth: options.smooth,
+            background: to_color(options.background),
+            compare: None,
+            clipboard: None,
+        }
+    }
+
+    /// Returns whether the window was closed, or Esc or Q pressed to close the viewer.
+    /// Esc closes the help overlay instead while it's open.
+    fn quit_requested(&self) -> bool {
+        is_quit_requested()
+            || is_key_pressed(KeyCode::Q)
+            || (is_key_pressed(KeyCode::Escape) && !self.help.is_open())
+    }
+
+    /// Handles the shortcuts that work in every mode.
+    /// Returns whether the image has to be uploaded again because the filters changed.
+    fn handle_input(&mut self) -> bool {
+
+        // Flip between fullscreen and windowed mode
+        if is_key_pressed(KeyCode::F11) || is_key_pressed(KeyCode::F) {
+            self.fullscreen = !self.fullscreen;
+
+            // macroquad doesn't expose this yet, so go through miniquad directly.
+            unsafe { get_internal_gl().quad_context.set_fullscreen(self.fullscreen) };
+        }
+
+        self.inspector.handle_input();
+        self.grid.handle_input();
+        self.backdrop.handle_input();
+        self.help.handle_input();
+        self.info.handle_input();
+        self.histograms.handle_input();
+
+        if let Some(compare) = &mut self.compare {
+            compare.handle_input();
+        }
+
+        // Crisp pixels suit pixel art, smooth scaling suits photos
+        let toggled = is_key_pressed(KeyCode::N);
+        if toggled {
+            self.smooth = !self.smooth;
+        }
+
+        // Both have to run, so don't short-circuit
+        self.adjustment.handle_input() | self.vision.handle_input() | toggled
+    }
+
+    /// Returns the filters the image is currently displayed with
+    fn filters(&self) -> Filters {
+        Filters {
+            adjustment: self.adjustment,
+            vision: self.vision,
+            smooth: self.smooth,
+        }
+    }
+
+    /// Copies a frame of `file` to the clipboard as an image.
+    fn copy(&mut self, file: &CIEBIIFILE, frame: usize) {
+
+        // Connect to the clipboard the first time it's needed
+        if self.clipboard.is_none() {
+            match Clipboard::new() {
+                Ok(clipboard) => self.clipboard = Some(clipboard),
+                Err(err) => {
+                    eprintln!("{} {}", "Failed to open the clipboard:".red().bold(), err);
+                    return;
+                }
+            }
+        }
+
+        let clipboard = self.clipboard.as_mut().unwrap();
+
+        match clipboard::copy_image(clipboard, file, frame) {
+            Ok(()) => println!("📋 {}", "Copied the image to the clipboard".green().bold()),
+            Err(err) => eprintln!("{} {}", "Failed to copy the image:".red().bold(), err),
+        }
+    }
+
+    /// Draws a frame showing the given frame of `slide`, or the difference heatmap when comparing.
+    fn draw(&mut self, slide: &Slide, frame: usize) {
+        let heatmap = self.compare.as_mut().and_then(Compare::texture);
+        let (file, texture) = (&slide.file, heatmap.unwrap_or(slide.textures[frame]));
+
+        clear_background(self.background);
+
+        // Recomputed every frame so the image follows the window as it's resized
+        let area = slide.view.area(fit_area(texture));
+
+        self.backdrop.draw(area);
+        draw_image(texture, area);
+        self.grid.draw(file.dimensions(), area);
+        self.inspector.draw(file, frame, area);
+        self.info.draw(file, &slide.details, area);
+        self.histograms.draw(slide.histogram.as_ref().map(|(_, histogram)| histogram));
+        self.adjustment.draw();
+        self.vision.draw();
+
+        if let Some(compare) = &self.compare {
+            compare.draw();
+        }
+    }
+
+
+    /// Draws the shortcut overlay on top of everything else.
+    fn draw_help(&self) {
+        self.help.draw();
+    }
+}
+
+/// Returns whether Ctrl+C was pressed this frame.
+fn is_copy_pressed() -> bool {
+    let ctrl = is_key_down(KeyCode::LeftControl) || is_key_down(KeyCode::RightControl);
+
+    ctrl && is_key_pressed(KeyCode::C)
+}
+
+/// Builds the window configuration for an image of the given dimensions.
+/// Unless a scale is given the window reopens at the size it had last time.
+fn window_conf(title: &str, dimensions: (usize, usize), options: &RenderOptions) -> Conf {
+    let (width, height) = match WindowState::load().filter(|_| options.scale.is_none()) {
+        Some(state) => (state.width as i32, state.height as i32),
+        None => window_size(dimensions, options.scale),
+    };
+
+    Conf {
+        window_title: title.to_owned(),
+        window_width: width,
+        window_height: height,
+        window_resizable: true,
+        fullscreen: options.fullscreen,
+        icon: Some(window_icon(options.icon.as_deref())),
+        platform: Platform {
+            swap_interval: Some(options.vsync as i32),
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+/// Loads the custom window icon, falling back to the bundled one if it can't be read.
+fn window_icon(path: Option<&Path>) -> Icon {
+    match path.map(icons::load) {
+        Some(Ok(icon)) => icon,
+        Some(Err(err)) => {
+            eprintln!("{} {err:#}", "Using the default icon:".yellow().bold());
+            icons::bundled()
+        }
+        None => icons::bundled(),
+    }
+}
+
+/// Picks the initial window size for an image.
+/// Without an explicit scale the largest integer scale that fits on screen is used,
+/// and the window is always clamped to `MAX_WINDOW` while keeping the aspect ratio.
+fn window_size((width, height): (usize, usize), scale: Option<f32>) -> (i32, i32) {
+    let (width, height) = (width.max(1) as f32, height.max(1) as f32);
+
+    let scale = scale.unwrap_or_else(|| {
+        (MAX_WINDOW.0 / width)
+            .min(MAX_WINDOW.1 / height)
+            .floor()
+            .max(1.0)
+    });
+
+    // Shrink the window if the scaled image would still exceed the display
+    let clamp = (MAX_WINDOW.0 / (width * scale))
+        .min(MAX_WINDOW.1 / (height * scale))
+        .min(1.0);
+
+    (
+        (width * scale * clamp).round().max(1.0) as i32,
+        (height * scale * clamp).round().max(1.0) as i32,
+    )
+}
+
+/// Converts a color into its macroquad equivalent.
+fn to_color(rgb: RGB) -> Color {
+    let (r, g, b) = rgb.color();
+
+    Color::from_rgba(r, g, b, 255)
+}
+
+/// Exports a frame of `file` as a PNG next to `source`, reporting the outcome on the terminal.
+fn save_screenshot(file: &CIEBIIFILE, frame: usize, source: &Path) {
+    match decode::export_png(file, frame, source) {
+        Ok(path) => println!(
+            "💾 {} {}",
+            "Saved".green().bold(),
+            format!("'{}'", path.display()).white().bold()
+        ),
+        Err(err) => eprintln!("{} {}", "Failed to save screenshot:".red().bold(), err),
+    }
+}
+
+/// Returns the size of a texture showing an image of `width` by `height` pixels. Textures are
+/// at most 65535 pixels a side, so larger images can't be shown.
+pub fn texture_size((width, height): (usize, usize)) -> anyhow::Result<(u16, u16)> {
+    match (u16::try_from(width), u16::try_from(height)) {
+        (Ok(width), Ok(height)) => Ok((width, height)),
+        _ => anyhow::bail!("{width} x {height} is too large to show, the limit is 65535 a side"),
+    }
+}
+
+/// Uploads every frame of a file into its own GPU texture, with `filters` applied.
+/// Files are checked with `texture_size` before they get this far.
+fn to_textures(file: &CIEBIIFILE, filters: Filters) -> Vec<Texture2D> {
+    let (width, height) = file.dimensions();
+    let (texture_width, texture_height) =
+        texture_size((width, height)).expect("the file was checked to fit a texture");
+    let _span = tracing::info_span!("render", width, height, frames = file.frame_count()).entered();
+    let lut = filters.adjustment.lut();
+
+    (0..file.frame_count())
+        .filter_map(|i| file.frame(i))
+        .map(|chunks| {
+            let bytes = to_rgba(chunks, filters, &lut);
+
+            let texture = Texture2D::from_rgba8(texture_width, texture_height, &bytes);
+            texture.set_filter(match filters.smooth {
+                true => FilterMode::Linear,
+                false => FilterMode::Nearest,
+            });
+            texture
+        })
+        .collect()
+}
+
+/// Turns chunks into RGBA pixels with `filters` applied, `lut` being the lookup table of
+/// their adjustment. The alpha is kept, so the backdrop shows through transparent pixels.
+fn to_rgba(chunks: &[Chunk], filters: Filters, lut: &[u8; 256]) -> Vec<u8> {
+    chunks
+        .iter()
+        .flat_map(|chunk| {
+            let (r, g, b) = chunk.rgb().color();
+            let adjusted = (lut[r as usize], lut[g as usize], lut[b as usize]);
+            let (r, g, b) = filters.vision.simulate(adjusted);
+
+            [r, g, b, chunk.alpha()]
+        })
+        .collect()
+}
+
+/// A rectangle of pixels that changed between two frames
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct DirtyRect {
+    x: usize,
+    y: usize,
+    width: usize,
+    height: usize,
+}
+
+/// Returns the smallest rectangle covering every pixel that differs between two frames
+/// `width` pixels wide, or `None` if they are the same.
+fn dirty_rect(old: &[Chunk], new: &[Chunk], width: usize) -> Option<DirtyRect> {
+    if width == 0 {
+        return None;
+    }
+
+    let mut bounds: Option<(usize, usize, usize, usize)> = None;
+
+    for (y, (old, new)) in old.chunks(width).zip(new.chunks(width)).enumerate() {
+        let Some(left) = old.iter().zip(new).position(|(old, new)| old != new) else {
+            continue;
+        };
+
+        // Searching from the right finds a difference too, at the latest the same one
+        let from_right = old
+            .iter()
+            .rev()
+            .zip(new.iter().rev())
+            .position(|(old, new)| old != new)?;
+        let right = old.len() - 1 - from_right;
+
+        bounds = Some(match bounds {
+            None => (left, y, right, y),
+            Some((l, top, r, _)) => (l.min(left), top, r.max(right), y),
+        });
+    }
+
+    bounds.map(|(left, top, right, bottom)| DirtyRect {
+        x: left,
+        y: top,
+        width: right - left + 1,
+        height: bottom - top + 1,
+    })
+}
+
+/// Returns the screen area a texture occupies when centered in the window and
+/// scaled to fit while keeping its aspect ratio, leaving letterbox bars on the other axis.
+fn fit_area(texture: Texture2D) -> Rect {
+    let scale = (screen_width() / texture.width()).min(screen_height() / texture.height());
+
+    // Snap to whole screen pixels so the edges stay sharp at odd window sizes
+    let width = (texture.width() * scale).round().max(1.0);
+    let height = (texture.height() * scale).round().max(1.0);
+
+    Rect::new(
+        ((screen_width() - width) / 2.0).floor(),
+        ((screen_height() - height) / 2.0).floor(),
+        width,
+        height,
+    )
+}
+
+/// Draws a texture stretched over `area`.
+fn draw_image(texture: Texture2D, area: Rect) {
+    draw_texture_ex(
+        texture,
+        area.x,
+        area.y,
+        macroquad::color::WHITE,
+        DrawTextureParams {
+            dest_size: Some(vec2(area.w, area.h)),
+            ..Default::default()
+        },
+    );
 }
-// 
\ No newline at end of file
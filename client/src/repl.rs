@@ -0,0 +1,255 @@
+use std::{fs, path::Path, path::PathBuf};
+
+use anyhow::Context as _;
+use ciebii_lib::{
+    file::CIEBIIFILE,
+    io::{read_file, write_file_atomic},
+};
+use colored::*;
+use rustyline::{
+    completion::{Completer, FilenameCompleter, Pair},
+    error::ReadlineError,
+    highlight::Highlighter,
+    hint::Hinter,
+    history::DefaultHistory,
+    validate::Validator,
+    Context, Editor, Helper,
+};
+
+use crate::{
+    config,
+    edit::{self, Patch, Region},
+};
+
+/// Every command the REPL understands, along with its arguments and what it does
+const COMMANDS: [(&str, &str, &str); 7] = [
+    ("info", "", "Shows the size of the image and whether it has unsaved changes"),
+    ("get", "X Y", "Prints the color and checksum of a pixel"),
+    ("set", "X Y COLOR", "Overwrites a pixel, e.g. set 4 5 #ff0000"),
+    ("crop", "X,Y,WxH", "Cuts a rectangle out of every frame"),
+    ("save", "[PATH]", "Writes the image back, or to PATH"),
+    ("help", "", "Lists the commands"),
+    ("quit", "", "Leaves the REPL, twice if there are unsaved changes"),
+];
+
+/// The name of the history file in the config directory
+const HISTORY_FILE: &str = "repl_history";
+
+/// Completes command names, then paths for `save`.
+struct ReplHelper {
+    paths: FilenameCompleter,
+}
+
+impl Completer for ReplHelper {
+    type Candidate = Pair;
+
+    fn complete(
+        &self,
+        line: &str,
+        pos: usize,
+        ctx: &Context<'_>,
+    ) -> rustyline::Result<(usize, Vec<Pair>)> {
+        let before = &line[..pos];
+
+        match before.split_once(' ') {
+            None => Ok((
+                0,
+                COMMANDS
+                    .iter()
+                    .filter(|(name, _, _)| name.starts_with(before))
+                    .map(|(name, _, _)| Pair {
+                        display: name.to_string(),
+                        replacement: format!("{name} "),
+                    })
+                    .collect(),
+            )),
+            Some(("save", _)) => self.paths.complete(line, pos, ctx),
+            Some(_) => Ok((pos, Vec::new())),
+        }
+    }
+}
+
+impl Hinter for ReplHelper {
+    type Hint = String;
+}
+
+impl Highlighter for ReplHelper {}
+
+impl Validator for ReplHelper {}
+
+impl Helper for ReplHelper {}
+
+/// The file being worked on
+struct Session {
+    path: PathBuf,
+    file: CIEBIIFILE,
+
+    // Whether there are changes that haven't been saved
+    dirty: bool,
+
+    // Whether quitting was already refused once because of unsaved changes
+    warned: bool,
+}
+
+/// Opens the ciebii file at `file_name` in an interactive prompt for inspecting and editing
+/// it one command at a time. Lines are kept in a history shared between sessions, and
+/// commands and paths complete with tab.
+pub fn repl(file_name: &str) -> anyhow::Result<()> {
+    let path = PathBuf::from(file_name);
+    let mut session = Session {
+        file: read_file(&path)?,
+        path,
+        dirty: false,
+        warned: false,
+    };
+
+    let mut editor: Editor<ReplHelper, DefaultHistory> =
+        Editor::new().context("Failed to start the REPL")?;
+    editor.set_helper(Some(ReplHelper {
+        paths: FilenameCompleter::new(),
+    }));
+
+    let history = config::config_dir().map(|dir| dir.join(HISTORY_FILE));
+    if let Some(history) = &history {
+        // There's no history before the first session
+        let _ = editor.load_history(history);
+    }
+
+    println!(
+        "🔎 {} {} {}",
+        "Opened".bold(),
+        format!("'{file_name}'").white().bold(),
+        "— type help to list the commands".bold()
+    );
+
+    loop {
+        let line = match editor.readline("ciebii> ") {
+            Ok(line) => line,
+            Err(ReadlineError::Interrupted) => continue,
+            Err(ReadlineError::Eof) => break,
+            Err(err) => return Err(err).context("Failed to read the command"),
+        };
+
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        editor.add_history_entry(line)?;
+
+        match session.run(line) {
+            Ok(true) => {}
+            Ok(false) => break,
+            Err(err) => eprintln!("{} {err:#}", "error:".red().bold()),
+        }
+    }
+
+    if let Some(history) = &history {
+        if let Some(dir) = history.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        editor
+            .save_history(history)
+            .context("Failed to save the REPL history")?;
+    }
+
+    Ok(())
+}
+
+impl Session {
+    /// Runs one line of input. Returns whether to keep going.
+    fn run(&mut self, line: &str) -> anyhow::Result<bool> {
+        let words: Vec<&str> = line.split_whitespace().collect();
+
+        let quitting = matches!(words[..], ["quit" | "exit"]);
+        if !quitting {
+            self.warned = false;
+        }
+
+        match words[..] {
+            ["info"] => self.info(),
+            ["get", x, y] => self.get(x, y)?,
+            ["set", x, y, color] => {
+                Patch::parse(&format!("{x},{y}"), color)?.apply(&mut self.file)?;
+                self.dirty = true;
+            }
+            ["crop", region] => {
+                let region = Region::parse(region).map_err(anyhow::Error::msg)?;
+                self.file = edit::crop_file(&self.file, region)?;
+                self.dirty = true;
+                self.info();
+            }
+            ["save"] => self.save(None)?,
+            ["save", path] => self.save(Some(Path::new(path)))?,
+            ["help"] => help(),
+            ["quit" | "exit"] if self.dirty && !self.warned => {
+                self.warned = true;
+                println!(
+                    "{}",
+                    "⚠️ There are unsaved changes, quit again to discard them".yellow().bold()
+                );
+            }
+            ["quit" | "exit"] => return Ok(false),
+            [command, ..] => match COMMANDS.iter().find(|(name, _, _)| *name == command) {
+                Some((name, args, _)) => anyhow::bail!("usage: {name} {args}"),
+                None => anyhow::bail!("unknown command '{command}', type help to list them"),
+            },
+            [] => {}
+        }
+
+        Ok(true)
+    }
+
+    fn info(&self) {
+        let (width, height) = self.file.dimensions();
+
+        println!(
+            "{} {} x {}, {} frame(s){}",
+            format!("'{}'", self.path.display()).white().bold(),
+            width,
+            height,
+            self.file.frame_count(),
+            if self.dirty { ", unsaved changes" } else { "" }
+        );
+    }
+
+    fn get(&self, x: &str, y: &str) -> anyhow::Result<()> {
+        let (width, height) = self.file.dimensions();
+        let (x, y) = edit::parse_position(&format!("{x},{y}")).map_err(anyhow::Error::msg)?;
+
+        let Some(&chunk) = self.file.get_pixel(x, y) else {
+            anyhow::bail!("({x}, {y}) is outside of the {width} x {height} image");
+        };
+        let color = match self.file.has_alpha() {
+            true => chunk.rgba().to_string(),
+            false => chunk.rgb().to_string(),
+        };
+
+        println!(
+            "{} checksum {:#06x}{}",
+            color.white().bold(),
+            self.file.stored_checksum(&chunk),
+            if chunk.is_valid() { "" } else { ", corrupt" }
+        );
+
+        Ok(())
+    }
+
+    /// Writes the file to `path`, or back to where it came from
+    fn save(&mut self, path: Option<&Path>) -> anyhow::Result<()> {
+        let path = path.unwrap_or(&self.path);
+        write_file_atomic(path, &self.file)?;
+
+        println!("💾 {} {}", "Saved".bold(), format!("'{}'", path.display()).white().bold());
+        if path == self.path {
+            self.dirty = false;
+        }
+
+        Ok(())
+    }
+}
+
+fn help() {
+    for (name, args, about) in COMMANDS {
+        println!("  {:<22} {about}", format!("{name} {args}").bold());
+    }
+}
@@ -0,0 +1,57 @@
+use std::{net::TcpListener, path::Path, thread, time::Duration};
+
+use anyhow::Context;
+use ciebii_lib::net::FrameSender;
+use colored::*;
+
+use crate::gallery;
+
+/// Waits for a viewer to connect to `address` and streams `file_names` to it as frames,
+/// one every `interval` seconds. Every frame of an animated file is sent in turn.
+pub fn serve(address: &str, file_names: &[String], interval: f64, repeat: bool) -> anyhow::Result<()> {
+    let files = file_names
+        .iter()
+        .map(|file_name| gallery::load(Path::new(file_name)))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    // Every frame of a stream has the size announced in its header
+    let (width, height) = files[0].dimensions();
+    if let Some(index) = files.iter().position(|file| file.dimensions() != (width, height)) {
+        anyhow::bail!(
+            "'{}' is not {width} x {height} like '{}'",
+            file_names[index],
+            file_names[0]
+        );
+    }
+
+    let listener =
+        TcpListener::bind(address).with_context(|| format!("Failed to listen on '{address}'"))?;
+    println!(
+        "📡 {} {}",
+        "Waiting for a viewer on".bold(),
+        format!("tcp://{}", listener.local_addr()?).white().bold()
+    );
+
+    let (connection, peer) = listener.accept()?;
+    println!("🔌 {} {}", "Streaming to".bold(), peer.to_string().white().bold());
+
+    // The alpha of a stream is announced once, so one transparent file makes all of them carry it
+    let alpha = files.iter().any(|file| file.has_alpha());
+    let mut sender = FrameSender::with_alpha(connection, width, height, alpha)?;
+
+    loop {
+        for file in &files {
+            for frame in (0..file.frame_count()).filter_map(|i| file.frame(i)) {
+                sender
+                    .send_chunks(frame)
+                    .context("The viewer disconnected")?;
+
+                thread::sleep(Duration::from_secs_f64(interval));
+            }
+        }
+
+        if !repeat {
+            return Ok(());
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use std::{fs, path::Path};
+
+use anyhow::Context;
+use ciebii_lib::{io::read_file, steg};
+use colored::*;
+
+use crate::edit;
+
+/// Hides the contents of `secret` in `cover` and writes the result to `output`.
+pub fn embed(cover: &str, secret: &str, output: &str) -> anyhow::Result<()> {
+    let mut file = read_file(Path::new(cover))?;
+    let data = fs::read(secret).with_context(|| format!("Failed to read '{secret}'"))?;
+
+    // Report the capacity up front so it's clear how close the fit is
+    println!(
+        "📦 {} {} {}",
+        format!("{} bytes", data.len()).white().bold(),
+        "of".bold(),
+        format!("{} bytes available", steg::capacity(&file)).white().bold()
+    );
+
+    steg::embed(&mut file, &data)?;
+    println!("🕵️ {}", "hiding data...".bold());
+
+    edit::save(&file, Path::new(output))
+}
+
+/// Writes the data hidden in `file_name` to `output`.
+pub fn extract(file_name: &str, output: &str) -> anyhow::Result<()> {
+    let file = read_file(Path::new(file_name))?;
+
+    let data = steg::extract(&file).context("No hidden data found")?;
+    fs::write(output, &data).with_context(|| format!("Failed to write '{output}'"))?;
+
+    println!(
+        "🔓 {} {}",
+        "Extracted".green().bold(),
+        format!("{} bytes", data.len()).white().bold()
+    );
+
+    Ok(())
+}
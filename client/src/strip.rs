@@ -0,0 +1,193 @@
+use std::{
+    path::PathBuf,
+    sync::mpsc::{self, Receiver},
+    thread,
+};
+
+use ciebii_lib::{file::CIEBIIFILE, io::read_thumbnail, thumbnail};
+use macroquad::{
+    color::{Color, WHITE},
+    input::{is_mouse_button_pressed, mouse_position, mouse_wheel, MouseButton},
+    math::{vec2, Rect},
+    shapes::{draw_rectangle, draw_rectangle_lines},
+    texture::{draw_texture_ex, DrawTextureParams, FilterMode, Texture2D},
+    window::{screen_height, screen_width},
+};
+
+use crate::{gallery, render};
+
+/// The size of the box each thumbnail is fitted into
+const THUMBNAIL_SIZE: f32 = 64.0;
+const PADDING: f32 = 6.0;
+
+/// How far one wheel notch scrolls the strip
+const SCROLL_STEP: f32 = THUMBNAIL_SIZE + PADDING;
+
+/// A scrollable row of thumbnails along the bottom of the window, one per file of a
+/// directory. Thumbnails are read on a background thread so the viewer opens right away.
+pub struct Strip {
+    // `None` until the thumbnail has been read, or for good if it couldn't be
+    thumbnails: Vec<Option<Texture2D>>,
+    loaded: Receiver<(usize, CIEBIIFILE)>,
+
+    // How far the strip is scrolled to the right, in screen pixels
+    scroll: f32,
+
+    // The file the strip last scrolled to, so it only follows when the file changes
+    followed: Option<usize>,
+}
+
+impl Strip {
+    /// Starts reading the thumbnails of `paths`. The embedded thumbnail is used where there
+    /// is one, other files are loaded whole and scaled down.
+    pub fn new(paths: Vec<PathBuf>) -> Self {
+        let (sender, loaded) = mpsc::channel();
+        let thumbnails = vec![None; paths.len()];
+
+        thread::spawn(move || {
+            for (i, path) in paths.iter().enumerate() {
+                let thumbnail = match read_thumbnail(path) {
+                    Ok(Some(thumbnail)) => Some(thumbnail),
+                    _ => gallery::load(path).ok().map(|file| thumbnail::generate(&file)),
+                };
+
+                // The viewer was closed
+                if thumbnail.is_some_and(|thumbnail| sender.send((i, thumbnail)).is_err()) {
+                    break;
+                }
+            }
+        });
+
+        Self {
+            thumbnails,
+            loaded,
+            scroll: 0.0,
+            followed: None,
+        }
+    }
+
+    /// Returns the area the strip covers
+    fn area() -> Rect {
+        let height = THUMBNAIL_SIZE + PADDING * 2.0;
+
+        Rect::new(0.0, screen_height() - height, screen_width(), height)
+    }
+
+    /// Returns whether the cursor is over the strip, where the wheel and clicks belong to it
+    pub fn contains_mouse() -> bool {
+        let (x, y) = mouse_position();
+
+        Self::area().contains(vec2(x, y))
+    }
+
+    /// Uploads the thumbnails read since last frame, scrolls with the wheel and keeps
+    /// `current` in sight. Returns the index of the thumbnail clicked this frame, if any.
+    pub fn handle_input(&mut self, current: usize) -> Option<usize> {
+        for (i, file) in self.loaded.try_iter() {
+            // An embedded thumbnail may claim any size
+            let Ok((width, height)) = render::texture_size(file.dimensions()) else {
+                continue;
+            };
+            let bytes: Vec<u8> = file
+                .chunks()
+                .iter()
+                .flat_map(|chunk| {
+                    let (r, g, b, a) = chunk.rgba().color();
+                    [r, g, b, a]
+                })
+                .collect();
+
+            let texture = Texture2D::from_rgba8(width, height, &bytes);
+            texture.set_filter(FilterMode::Nearest);
+            self.thumbnails[i] = Some(texture);
+        }
+
+        let max_scroll = (self.thumbnails.len() as f32 * SCROLL_STEP + PADDING
+            - screen_width())
+        .max(0.0);
+
+        if self.followed != Some(current) {
+            self.followed = Some(current);
+
+            let left = current as f32 * SCROLL_STEP;
+            let right = left + SCROLL_STEP + PADDING;
+            if left < self.scroll {
+                self.scroll = left;
+            } else if right > self.scroll + screen_width() {
+                self.scroll = right - screen_width();
+            }
+        }
+
+        if !Self::contains_mouse() {
+            self.scroll = self.scroll.clamp(0.0, max_scroll);
+            return None;
+        }
+
+        let (_, wheel) = mouse_wheel();
+        self.scroll = (self.scroll - wheel.signum() * SCROLL_STEP).clamp(0.0, max_scroll);
+
+        if !is_mouse_button_pressed(MouseButton::Left) {
+            return None;
+        }
+
+        let (x, _) = mouse_position();
+        let index = ((x + self.scroll - PADDING) / SCROLL_STEP).floor();
+
+        (index >= 0.0 && (index as usize) < self.thumbnails.len()).then_some(index as usize)
+    }
+
+    /// Draws the strip, outlining the thumbnail of `current`.
+    pub fn draw(&self, current: usize) {
+        let area = Self::area();
+        draw_rectangle(area.x, area.y, area.w, area.h, Color::new(0.0, 0.0, 0.0, 0.75));
+
+        for (i, thumbnail) in self.thumbnails.iter().enumerate() {
+            let x = PADDING + i as f32 * SCROLL_STEP - self.scroll;
+            let y = area.y + PADDING;
+
+            if x + THUMBNAIL_SIZE < 0.0 {
+                continue;
+            }
+            if x > area.w {
+                break;
+            }
+
+            match thumbnail {
+                Some(texture) => {
+                    let scale = (THUMBNAIL_SIZE / texture.width())
+                        .min(THUMBNAIL_SIZE / texture.height());
+                    let (width, height) = (texture.width() * scale, texture.height() * scale);
+
+                    draw_texture_ex(
+                        *texture,
+                        x + (THUMBNAIL_SIZE - width) / 2.0,
+                        y + (THUMBNAIL_SIZE - height) / 2.0,
+                        WHITE,
+                        DrawTextureParams {
+                            dest_size: Some(vec2(width, height)),
+                            ..Default::default()
+                        },
+                    );
+                }
+                None => draw_rectangle(
+                    x,
+                    y,
+                    THUMBNAIL_SIZE,
+                    THUMBNAIL_SIZE,
+                    Color::new(1.0, 1.0, 1.0, 0.1),
+                ),
+            }
+
+            if i == current {
+                let size = THUMBNAIL_SIZE + 4.0;
+                draw_rectangle_lines(x - 2.0, y - 2.0, size, size, 2.0, WHITE);
+            }
+        }
+    }
+}
+
+impl Drop for Strip {
+    fn drop(&mut self) {
+        self.thumbnails.iter().flatten().for_each(|texture| texture.delete());
+    }
+}
@@ -0,0 +1,61 @@
+use macroquad::{
+    color::{Color, GRAY, WHITE},
+    input::{is_key_pressed, KeyCode},
+    shapes::draw_rectangle,
+    text::{draw_text, measure_text},
+    window::screen_width,
+};
+
+const FONT_SIZE: f32 = 18.0;
+const PADDING: f32 = 6.0;
+
+/// The number keys that jump straight to the first nine tabs
+const TAB_KEYS: [KeyCode; 9] = [
+    KeyCode::Key1,
+    KeyCode::Key2,
+    KeyCode::Key3,
+    KeyCode::Key4,
+    KeyCode::Key5,
+    KeyCode::Key6,
+    KeyCode::Key7,
+    KeyCode::Key8,
+    KeyCode::Key9,
+];
+
+/// Returns the index of the tab picked with the number keys this frame, if any.
+pub fn selected() -> Option<usize> {
+    TAB_KEYS.iter().position(|key| is_key_pressed(*key))
+}
+
+/// Draws a tab bar along the top of the window, highlighting the current tab.
+/// Tabs that don't fit on screen are cut off.
+pub fn draw(names: &[String], current: usize) {
+    let height = FONT_SIZE + PADDING * 2.0;
+
+    draw_rectangle(0.0, 0.0, screen_width(), height, Color::new(0.0, 0.0, 0.0, 0.75));
+
+    let mut x = 0.0;
+    for (i, name) in names.iter().enumerate() {
+        if x >= screen_width() {
+            break;
+        }
+
+        // Number the tabs that can be reached with the number keys
+        let label = match i < TAB_KEYS.len() {
+            true => format!("{} {}", i + 1, name),
+            false => name.to_owned(),
+        };
+
+        let width = measure_text(&label, None, FONT_SIZE as u16, 1.0).width + PADDING * 2.0;
+
+        let color = if i == current {
+            draw_rectangle(x, 0.0, width, height, Color::new(1.0, 1.0, 1.0, 0.2));
+            WHITE
+        } else {
+            GRAY
+        };
+
+        draw_text(&label, x + PADDING, PADDING + FONT_SIZE * 0.8, FONT_SIZE, color);
+        x += width;
+    }
+}
@@ -0,0 +1,234 @@
+use std::{fs, path::Path};
+
+use anyhow::Context;
+use ciebii_lib::{
+    chunk::Chunk, crop::crop, draw::stamp, file::CIEBIIFILE, format, io::read_file, rgb::RGB,
+};
+use colored::*;
+use serde::{Deserialize, Serialize};
+
+/// The file describing how a set of tiles fits together, written next to the tiles.
+const MANIFEST: &str = "manifest.json";
+
+/// The layout of a split image, as stored in the manifest.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Manifest {
+    /// The size of the whole image
+    pub width: usize,
+    pub height: usize,
+
+    /// The size of a full tile, tiles along the right and bottom edges may be smaller
+    pub tile_width: usize,
+    pub tile_height: usize,
+
+    /// The number of tiles across and down
+    pub columns: usize,
+    pub rows: usize,
+
+    pub tiles: Vec<Tile>,
+}
+
+/// A single tile in the manifest.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Tile {
+    /// The file name of the tile, relative to the manifest
+    pub file: String,
+
+    pub column: usize,
+    pub row: usize,
+
+    /// Where the tile's top left corner sits in the whole image
+    pub x: usize,
+    pub y: usize,
+
+    pub width: usize,
+    pub height: usize,
+}
+
+/// Returns the file name of the tile in `column` and `row`
+pub fn tile_name(column: usize, row: usize) -> String {
+    format!("tile-{row}-{column}.{}", format::EXTENSION)
+}
+
+/// Cuts the ciebii file at `file_name` into `tile_size` tiles, written to `dir` along with a
+/// manifest. Tiles along the right and bottom edges are cut to what's left of the image.
+pub fn split(file_name: &str, tile_size: (usize, usize), dir: &Path) -> anyhow::Result<()> {
+    let file = read_file(Path::new(file_name))?;
+    let (width, height) = file.dimensions();
+    let (tile_width, tile_height) = tile_size;
+
+    fs::create_dir_all(dir).with_context(|| format!("Failed to create '{}'", dir.display()))?;
+
+    let (columns, rows) = (width.div_ceil(tile_width), height.div_ceil(tile_height));
+    let mut tiles = Vec::with_capacity(columns * rows);
+
+    for row in 0..rows {
+        for column in 0..columns {
+            let (x, y) = (column * tile_width, row * tile_height);
+            let (tile_width, tile_height) = (tile_width.min(width - x), tile_height.min(height - y));
+
+            let tile = crop(&file, x, y, tile_width, tile_height)?;
+            let name = tile_name(column, row);
+            fs::write(dir.join(&name), tile.as_bytes())
+                .with_context(|| format!("Failed to write '{name}'"))?;
+
+            tiles.push(Tile {
+                file: name,
+                column,
+                row,
+                x,
+                y,
+                width: tile_width,
+                height: tile_height,
+            });
+        }
+    }
+
+    let manifest = Manifest {
+        width,
+        height,
+        tile_width,
+        tile_height,
+        columns,
+        rows,
+        tiles,
+    };
+    fs::write(dir.join(MANIFEST), serde_json::to_string_pretty(&manifest)?)?;
+
+    println!(
+        "🧩 {} {} {}",
+        "Wrote".green().bold(),
+        format!("{columns} x {rows} tiles").white().bold(),
+        format!("to '{}'", dir.display()).bold()
+    );
+
+    Ok(())
+}
+
+/// Puts the tiles listed in the manifest at `manifest_path` back together and writes the result
+/// to `output`. Missing tiles are filled with `fill`, tiles of the wrong size are an error.
+pub fn join(manifest_path: &Path, fill: RGB, output: &str) -> anyhow::Result<()> {
+    let manifest: Manifest = serde_json::from_slice(
+        &fs::read(manifest_path)
+            .with_context(|| format!("Failed to read '{}'", manifest_path.display()))?,
+    )
+    .with_context(|| format!("'{}' is not a valid tile manifest", manifest_path.display()))?;
+
+    let dir = manifest_path.parent().unwrap_or(Path::new("."));
+    let mut file = blank(manifest.width, manifest.height, fill)?;
+    let mut missing = 0;
+
+    for tile in &manifest.tiles {
+        let path = dir.join(&tile.file);
+        if !path.exists() {
+            missing += 1;
+            continue;
+        }
+
+        let tile_file = read_file(&path)?;
+        if tile_file.dimensions() != (tile.width, tile.height) {
+            let (width, height) = tile_file.dimensions();
+            anyhow::bail!(
+                "'{}' is {width} x {height}, the manifest expects {} x {}",
+                tile.file,
+                tile.width,
+                tile.height
+            );
+        }
+
+        stamp(&mut file, &tile_file, tile.x, tile.y);
+    }
+
+    report_join(manifest.tiles.len(), missing);
+    crate::edit::save(&file, Path::new(output))
+}
+
+/// Puts a `columns` by `rows` grid of tiles named like `tile split` names them back together,
+/// without a manifest. Tiles in the same column have to be equally wide and tiles in the same
+/// row equally high. Missing tiles are filled with `fill`.
+pub fn join_grid(dir: &Path, grid: (usize, usize), fill: RGB, output: &str) -> anyhow::Result<()> {
+    let (columns, rows) = grid;
+
+    let mut tiles = Vec::new();
+    for row in 0..rows {
+        for column in 0..columns {
+            let path = dir.join(tile_name(column, row));
+
+            if path.exists() {
+                tiles.push((column, row, read_file(&path)?));
+            }
+        }
+    }
+
+    // Every column and row takes its size from the tiles in it, which all have to agree
+    let mut widths = vec![None; columns];
+    let mut heights = vec![None; rows];
+
+    for (column, row, tile) in &tiles {
+        let (width, height) = tile.dimensions();
+
+        for (size, expected, side) in [
+            (width, &mut widths[*column], "wide"),
+            (height, &mut heights[*row], "high"),
+        ] {
+            match expected {
+                Some(expected) if *expected != size => anyhow::bail!(
+                    "'{}' is {size} pixels {side}, the other tiles next to it are {expected}",
+                    tile_name(*column, *row)
+                ),
+                _ => *expected = Some(size),
+            }
+        }
+    }
+
+    // Without any tile to go by, a column or row is as large as the first one
+    let width_of = |column: usize| widths[column].or(widths[0]);
+    let height_of = |row: usize| heights[row].or(heights[0]);
+
+    let offsets = |sizes: Vec<Option<usize>>| -> anyhow::Result<Vec<usize>> {
+        let mut offset = 0;
+        let mut offsets = Vec::with_capacity(sizes.len() + 1);
+
+        for size in sizes {
+            offsets.push(offset);
+            offset += size.context("Can't work out the size of the grid without tile-0-0")?;
+        }
+        offsets.push(offset);
+
+        Ok(offsets)
+    };
+    let x_offsets = offsets((0..columns).map(width_of).collect())?;
+    let y_offsets = offsets((0..rows).map(height_of).collect())?;
+
+    let mut file = blank(x_offsets[columns], y_offsets[rows], fill)?;
+    for (column, row, tile) in &tiles {
+        stamp(&mut file, tile, x_offsets[*column], y_offsets[*row]);
+    }
+
+    report_join(columns * rows, columns * rows - tiles.len());
+    crate::edit::save(&file, Path::new(output))
+}
+
+/// Returns a `width` by `height` file filled with `color`.
+fn blank(width: usize, height: usize, color: RGB) -> anyhow::Result<CIEBIIFILE> {
+    let (r, g, b) = color.color();
+
+    Ok(CIEBIIFILE::try_from_chunks(width, height, vec![Chunk::new(r, g, b); width * height])?)
+}
+
+/// Prints how many tiles were joined and how many were missing.
+fn report_join(total: usize, missing: usize) {
+    println!(
+        "🧩 {} {}",
+        "Joined".green().bold(),
+        format!("{} tiles", total - missing).white().bold()
+    );
+
+    if missing > 0 {
+        println!(
+            "{} {}",
+            "Filled in".yellow().bold(),
+            format!("{missing} missing tiles").white().bold()
+        );
+    }
+}
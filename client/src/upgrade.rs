@@ -0,0 +1,83 @@
+use std::{fs, path::Path};
+
+use anyhow::Context;
+use ciebii_lib::{
+    compat,
+    file::CIEBIIFILE,
+    header::Header,
+    io::write_file_with,
+    writer::{Codec, WriteOptions},
+    FormatKind,
+};
+use colored::*;
+
+/// Rewrites the legacy or ciebii file at `file_name` in the current format at `output`, in the
+/// flavor `options` ask for. The pixel chunks, checksums included, are carried over byte for
+/// byte, or decode to the same chunks if either side stores them as runs or they're
+/// checksummed differently. Files that are already current are left alone when upgrading in
+/// place with the default options.
+pub fn upgrade(file_name: &str, output: &Path, options: &WriteOptions) -> anyhow::Result<()> {
+    let bytes = fs::read(file_name).with_context(|| format!("Failed to read '{file_name}'"))?;
+
+    let (file, pixels, from) = match ciebii_lib::sniff(&bytes) {
+        Some(FormatKind::Legacy) => (
+            compat::decode(&bytes)?,
+            Some(&bytes[compat::LEGACY_HEADER_LEN..]),
+            "the legacy SHITFILE format".to_owned(),
+        ),
+        Some(FormatKind::Ciebii) => {
+            let file = CIEBIIFILE::try_from(bytes.clone())?;
+            let (width, height) = file.dimensions();
+
+            // Every ciebii file so far uses the current layout, so there's nothing to migrate
+            if Path::new(file_name) == output && *options == WriteOptions::default() {
+                println!(
+                    "✅ {} {}",
+                    format!("'{file_name}'").white().bold(),
+                    "is already up to date".green().bold()
+                );
+
+                return Ok(());
+            }
+
+            let from = format!("format version {}", file.version());
+
+            // Runs aren't stored chunk by chunk
+            let end = Header::LEN + width * height * file.chunk_len();
+            let pixels = bytes.get(Header::LEN..end).filter(|_| !file.is_compressed());
+
+            (file, pixels, from)
+        }
+        None => anyhow::bail!("'{file_name}' is neither a ciebii file nor a legacy SHITFILE"),
+    };
+
+    // Nothing about the pixels may change, only what surrounds them
+    let upgraded = file.as_bytes_with(options)?;
+    let compressed = options
+        .encodes_with()
+        .map_or(file.is_compressed(), |codec| codec == Codec::Rle);
+    let rechecksummed = options
+        .checksums_with()
+        .is_some_and(|checksum| checksum != file.checksum_algorithm());
+    let unchanged = match pixels {
+        Some(pixels) if !compressed && !rechecksummed => {
+            upgraded.get(Header::LEN..Header::LEN + pixels.len()) == Some(pixels)
+        }
+        _ => CIEBIIFILE::try_from(&upgraded[..])?.chunks() == file.chunks(),
+    };
+    anyhow::ensure!(
+        unchanged,
+        "The pixels of '{file_name}' changed while upgrading, leaving it as it is"
+    );
+
+    write_file_with(output, &file, options)?;
+
+    println!(
+        "⬆️ {} {} {}",
+        "Upgraded".green().bold(),
+        format!("'{file_name}'").white().bold(),
+        format!("from {from} to format version {}", file.version()).bold()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,50 @@
+use std::{fs, path::Path};
+
+use anyhow::Context;
+use ciebii_lib::{format, vectors};
+use colored::*;
+use serde_json::json;
+
+/// Writes the test-vector corpus into `dir` along with a `manifest.json` of the expected results.
+pub fn generate(dir: &Path) -> anyhow::Result<()> {
+    fs::create_dir_all(dir).with_context(|| format!("Failed to create '{}'", dir.display()))?;
+
+    let corpus = vectors::corpus();
+    let mut manifest = Vec::new();
+
+    for vector in &corpus {
+        let file_name = format!("{}.{}", vector.name, format::EXTENSION);
+        fs::write(dir.join(&file_name), &vector.bytes)
+            .with_context(|| format!("Failed to write '{file_name}'"))?;
+
+        manifest.push(match vector.expected {
+            Ok((width, height)) => json!({
+                "file": file_name,
+                "description": vector.description,
+                "valid": true,
+                "width": width,
+                "height": height,
+            }),
+            Err(error) => json!({
+                "file": file_name,
+                "description": vector.description,
+                "valid": false,
+                "error": error,
+            }),
+        });
+    }
+
+    fs::write(
+        dir.join("manifest.json"),
+        serde_json::to_string_pretty(&manifest)?,
+    )?;
+
+    println!(
+        "🧪 {} {} {}",
+        "Wrote".green().bold(),
+        format!("{} test vectors", corpus.len()).white().bold(),
+        format!("to '{}'", dir.display()).bold()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,100 @@
+use macroquad::{
+    input::{
+        is_key_pressed, is_mouse_button_down, mouse_position, mouse_wheel, KeyCode, MouseButton,
+    },
+    math::{vec2, Rect, Vec2},
+    window::{screen_height, screen_width},
+};
+
+/// How much one wheel notch or key press zooms in or out
+const ZOOM_STEP: f32 = 1.2;
+
+/// The zoom limits, relative to the image fitting the window
+const MIN_ZOOM: f32 = 0.1;
+const MAX_ZOOM: f32 = 256.0;
+
+/// The zoom and pan of the image, on top of fitting it into the window.
+#[derive(Debug)]
+pub struct View {
+    zoom: f32,
+
+    // How far the image center is moved from the window center, in screen pixels
+    offset: Vec2,
+
+    // Where the cursor was last frame while dragging
+    dragged_from: Option<Vec2>,
+}
+
+impl Default for View {
+    fn default() -> Self {
+        Self {
+            zoom: 1.0,
+            offset: Vec2::ZERO,
+            dragged_from: None,
+        }
+    }
+}
+
+impl View {
+    /// Zooms with the mouse wheel (centered on the cursor) or +/-, pans by dragging
+    /// with the left mouse button and resets with 0. `fit` is the area the image
+    /// occupies when fitted into the window.
+    pub fn handle_input(&mut self, fit: Rect) {
+        let (mouse_x, mouse_y) = mouse_position();
+        let mouse = vec2(mouse_x, mouse_y);
+
+        let (_, wheel) = mouse_wheel();
+        if wheel != 0.0 {
+            self.zoom_at(fit, mouse, ZOOM_STEP.powf(wheel.signum()));
+        }
+
+        // Keyboard zoom keeps the window center fixed
+        let center = vec2(screen_width() / 2.0, screen_height() / 2.0);
+        if is_key_pressed(KeyCode::Equal) || is_key_pressed(KeyCode::KpAdd) {
+            self.zoom_at(fit, center, ZOOM_STEP);
+        }
+        if is_key_pressed(KeyCode::Minus) || is_key_pressed(KeyCode::KpSubtract) {
+            self.zoom_at(fit, center, 1.0 / ZOOM_STEP);
+        }
+
+        if is_key_pressed(KeyCode::Key0) || is_key_pressed(KeyCode::Kp0) {
+            *self = Self::default();
+        }
+
+        if is_mouse_button_down(MouseButton::Left) {
+            if let Some(from) = self.dragged_from {
+                self.offset += mouse - from;
+            }
+            self.dragged_from = Some(mouse);
+        } else {
+            self.dragged_from = None;
+        }
+    }
+
+    /// Returns the screen area the image occupies, given the area it occupies when fitted.
+    pub fn area(&self, fit: Rect) -> Rect {
+        let (width, height) = (fit.w * self.zoom, fit.h * self.zoom);
+        let center = fit.center() + self.offset;
+
+        // Snap to whole screen pixels so the pixels stay evenly sized
+        Rect::new(
+            (center.x - width / 2.0).round(),
+            (center.y - height / 2.0).round(),
+            width.round().max(1.0),
+            height.round().max(1.0),
+        )
+    }
+
+    /// Multiplies the zoom by `factor` while keeping the image point under `anchor` in place.
+    fn zoom_at(&mut self, fit: Rect, anchor: Vec2, factor: f32) {
+        let zoom = (self.zoom * factor).clamp(MIN_ZOOM, MAX_ZOOM);
+        let factor = zoom / self.zoom;
+
+        // The anchor's distance to the image center scales along with the image
+        let center = fit.center() + self.offset;
+        let center = anchor + (center - anchor) * factor;
+
+        self.offset = center - fit.center();
+        self.zoom = zoom;
+    }
+}
@@ -0,0 +1,124 @@
+use macroquad::{
+    color::{Color, WHITE},
+    input::{is_key_pressed, KeyCode},
+    shapes::draw_rectangle,
+    text::{draw_text, measure_text},
+    window::screen_width,
+};
+
+const FONT_SIZE: f32 = 18.0;
+const PADDING: f32 = 6.0;
+
+/// Simulation matrices for complete dichromacy in linear RGB, from
+/// Machado, Oliveira and Fernandes (2009).
+const PROTANOPIA: [[f32; 3]; 3] = [
+    [0.152286, 1.052583, -0.204868],
+    [0.114503, 0.786281, 0.099216],
+    [-0.003882, -0.048116, 1.051998],
+];
+const DEUTERANOPIA: [[f32; 3]; 3] = [
+    [0.367322, 0.860646, -0.227968],
+    [0.280085, 0.672501, 0.047413],
+    [-0.011820, 0.042940, 0.968881],
+];
+const TRITANOPIA: [[f32; 3]; 3] = [
+    [1.255528, -0.076749, -0.178779],
+    [-0.078411, 0.930809, 0.147602],
+    [0.004733, 0.691367, 0.303900],
+];
+
+/// A color vision deficiency the displayed image is simulated for. Cycled with V.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Vision {
+    #[default]
+    Normal,
+    Protanopia,
+    Deuteranopia,
+    Tritanopia,
+}
+
+impl Vision {
+    /// Cycles to the next simulation when V is pressed.
+    /// Returns whether the simulation changed.
+    pub fn handle_input(&mut self) -> bool {
+        if !is_key_pressed(KeyCode::V) {
+            return false;
+        }
+
+        *self = match self {
+            Self::Normal => Self::Protanopia,
+            Self::Protanopia => Self::Deuteranopia,
+            Self::Deuteranopia => Self::Tritanopia,
+            Self::Tritanopia => Self::Normal,
+        };
+
+        true
+    }
+
+    /// Returns how the color `(r, g, b)` looks with this deficiency.
+    pub fn simulate(&self, (r, g, b): (u8, u8, u8)) -> (u8, u8, u8) {
+        let matrix = match self {
+            Self::Normal => return (r, g, b),
+            Self::Protanopia => &PROTANOPIA,
+            Self::Deuteranopia => &DEUTERANOPIA,
+            Self::Tritanopia => &TRITANOPIA,
+        };
+
+        // The matrices work on linear light, not on the gamma encoded values
+        let linear = [to_linear(r), to_linear(g), to_linear(b)];
+        let [r, g, b] = matrix.map(|row| {
+            to_srgb(row[0] * linear[0] + row[1] * linear[1] + row[2] * linear[2])
+        });
+
+        (r, g, b)
+    }
+
+    /// Draws the name of the active simulation in the top right corner.
+    pub fn draw(&self) {
+        let text = match self {
+            Self::Normal => return,
+            Self::Protanopia => "protanopia",
+            Self::Deuteranopia => "deuteranopia",
+            Self::Tritanopia => "tritanopia",
+        };
+
+        let size = measure_text(text, None, FONT_SIZE as u16, 1.0);
+        let x = screen_width() - size.width - PADDING * 3.0;
+
+        // Keep clear of the tab bar
+        let y = FONT_SIZE + PADDING * 3.0;
+
+        draw_rectangle(
+            x,
+            y,
+            size.width + PADDING * 2.0,
+            size.height + PADDING * 2.0,
+            Color::new(0.0, 0.0, 0.0, 0.75),
+        );
+        draw_text(text, x + PADDING, y + PADDING + size.offset_y, FONT_SIZE, WHITE);
+    }
+}
+
+/// Decodes an sRGB channel value into linear light.
+fn to_linear(value: u8) -> f32 {
+    let value = value as f32 / 255.0;
+
+    if value <= 0.04045 {
+        value / 12.92
+    } else {
+        ((value + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Encodes linear light back into an sRGB channel value.
+fn to_srgb(value: f32) -> u8 {
+    let value = value.clamp(0.0, 1.0);
+
+    let encoded = if value <= 0.0031308 {
+        value * 12.92
+    } else {
+        1.055 * value.powf(1.0 / 2.4) - 0.055
+    };
+
+    (encoded * 255.0).round() as u8
+}
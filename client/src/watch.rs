@@ -0,0 +1,130 @@
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, Instant, SystemTime},
+};
+
+use anyhow::Context;
+use ciebii_lib::format;
+use colored::*;
+
+use crate::{
+    convert::{self, ConvertOptions},
+    gallery::IMAGE_EXTENSIONS,
+};
+
+/// How often the source directory is checked for changes
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// How long a file has to stay unchanged before it's converted, so files that are still being
+/// written aren't picked up halfway
+const SETTLE_TIME: Duration = Duration::from_millis(1000);
+
+/// When a file was last modified and how large it was, which changes whenever it's written
+type Stamp = (SystemTime, u64);
+
+/// Converts every image in `src` to a ciebii file in `out`, then keeps converting new and
+/// modified images until interrupted. Images whose ciebii file is already newer are skipped.
+pub fn watch(src: &Path, out: &Path) -> anyhow::Result<()> {
+    fs::create_dir_all(out).with_context(|| format!("Failed to create '{}'", out.display()))?;
+
+    // The stamp of every image as it was last converted
+    let mut converted: HashMap<PathBuf, Stamp> = HashMap::new();
+
+    // Images that changed, along with the moment they were last seen changing
+    let mut pending: HashMap<PathBuf, (Stamp, Instant)> = HashMap::new();
+
+    for (path, stamp) in images(src)? {
+        let up_to_date = fs::metadata(output(&path, out))
+            .and_then(|metadata| metadata.modified())
+            .is_ok_and(|modified| modified >= stamp.0);
+
+        if up_to_date {
+            converted.insert(path, stamp);
+        }
+    }
+
+    println!(
+        "👀 {} {} {}",
+        "Watching".bold(),
+        format!("'{}'", src.display()).white().bold(),
+        format!("for images, converting them into '{}'", out.display()).bold()
+    );
+
+    loop {
+        for (path, stamp) in images(src)? {
+            if converted.get(&path) == Some(&stamp) {
+                continue;
+            }
+
+            // Every change restarts the wait
+            match pending.get(&path) {
+                Some((seen, _)) if *seen == stamp => {}
+                _ => {
+                    pending.insert(path, (stamp, Instant::now()));
+                }
+            }
+        }
+
+        let settled: Vec<PathBuf> = pending
+            .iter()
+            .filter(|(_, (_, since))| since.elapsed() >= SETTLE_TIME)
+            .map(|(path, _)| path.clone())
+            .collect();
+
+        for path in settled {
+            let (stamp, _) = pending.remove(&path).unwrap();
+            let o = output(&path, out);
+
+            // A broken image is reported once, and tried again when it changes
+            match convert::convert_uncached(&path, &o, ConvertOptions::default()) {
+                Ok(()) => println!(
+                    "✨ {} {} {}",
+                    "Converted".green().bold(),
+                    format!("'{}'", path.display()).white().bold(),
+                    format!("to '{}'", o.display()).bold()
+                ),
+                Err(err) => eprintln!(
+                    "{} {}: {err:#}",
+                    "Failed to convert".red().bold(),
+                    format!("'{}'", path.display()).white().bold()
+                ),
+            }
+
+            converted.insert(path, stamp);
+        }
+
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+/// Lists the convertible images directly inside `dir` along with their stamps.
+fn images(dir: &Path) -> anyhow::Result<Vec<(PathBuf, Stamp)>> {
+    let entries =
+        fs::read_dir(dir).with_context(|| format!("Failed to read directory '{}'", dir.display()))?;
+
+    Ok(entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| {
+            IMAGE_EXTENSIONS
+                .iter()
+                .any(|extension| convert::has_extension(path, extension))
+        })
+        .filter_map(|path| {
+            let metadata = fs::metadata(&path).ok().filter(|metadata| metadata.is_file())?;
+            let stamp = (metadata.modified().ok()?, metadata.len());
+
+            Some((path, stamp))
+        })
+        .collect())
+}
+
+/// Returns where the ciebii file of the image at `path` goes
+fn output(path: &Path, out: &Path) -> PathBuf {
+    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+
+    out.join(format!("{stem}.{}", format::EXTENSION))
+}